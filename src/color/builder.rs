@@ -0,0 +1,182 @@
+//! Material Design theme builder
+
+use material_colors::{
+    color::Argb,
+    dynamic_color::{DynamicScheme, Variant},
+    theme::{CustomColor, Palettes, ThemeBuilder},
+};
+
+use super::palette::MaterialPalettes;
+use super::theme::{ColorMode, MaterialTheme};
+
+/// Name of a scheme variant, as used in theme files
+pub fn variant_name(variant: &Variant) -> &'static str {
+    match variant {
+        Variant::Monochrome => "monochrome",
+        Variant::Neutral => "neutral",
+        Variant::TonalSpot => "tonal_spot",
+        Variant::Vibrant => "vibrant",
+        Variant::Expressive => "expressive",
+        Variant::Fidelity => "fidelity",
+        Variant::Content => "content",
+        Variant::Rainbow => "rainbow",
+        Variant::FruitSalad => "fruit_salad",
+    }
+}
+
+/// Parse a scheme variant from its name, ignoring case, `-` and `_`
+pub fn parse_variant(name: &str) -> Option<Variant> {
+    let name: String = name
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let variant = match name.as_str() {
+        "monochrome" => Variant::Monochrome,
+        "neutral" => Variant::Neutral,
+        "tonalspot" => Variant::TonalSpot,
+        "vibrant" => Variant::Vibrant,
+        "expressive" => Variant::Expressive,
+        "fidelity" => Variant::Fidelity,
+        "content" => Variant::Content,
+        "rainbow" => Variant::Rainbow,
+        "fruitsalad" => Variant::FruitSalad,
+        _ => return None,
+    };
+    Some(variant)
+}
+
+/// Contrast level of the generated schemes
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContrastLevel {
+    /// Lower than the spec, `-1.0`
+    Reduced,
+    /// The design as spec'd, `0.0`
+    #[default]
+    Standard,
+    /// `0.5`
+    Medium,
+    /// `1.0`
+    High,
+    /// Any level from `-1.0` to `1.0`
+    Custom(f64),
+}
+
+impl ContrastLevel {
+    /// Contrast level as expected by the dynamic color algorithm
+    pub fn value(&self) -> f64 {
+        match self {
+            ContrastLevel::Reduced => -1.0,
+            ContrastLevel::Standard => 0.0,
+            ContrastLevel::Medium => 0.5,
+            ContrastLevel::High => 1.0,
+            ContrastLevel::Custom(level) => level.clamp(-1.0, 1.0),
+        }
+    }
+}
+
+/// Builder for [`MaterialTheme`]
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// let theme = MaterialTheme::builder(0xffaae5a4)
+///     .with_variant(Variant::Vibrant)
+///     .with_contrast(ContrastLevel::High)
+///     .with_color_mode(ColorMode::System)
+///     .with_custom_color("success", 0xff2e7d32, true)
+///     .build();
+/// ```
+pub struct MaterialThemeBuilder {
+    source: Argb,
+    color_mode: ColorMode,
+    variant: Variant,
+    contrast: ContrastLevel,
+    custom_colors: Vec<CustomColor>,
+}
+
+impl MaterialThemeBuilder {
+    /// Build through argb base colors
+    pub fn new(argb: u32) -> Self {
+        Self::from_source(Argb::from_u32(argb))
+    }
+
+    /// Build through a seed color
+    pub fn from_source(source: Argb) -> Self {
+        Self {
+            source,
+            color_mode: ColorMode::default(),
+            variant: Variant::TonalSpot,
+            contrast: ContrastLevel::default(),
+            custom_colors: Vec::new(),
+        }
+    }
+
+    pub fn with_color_mode(self, color_mode: ColorMode) -> Self {
+        Self { color_mode, ..self }
+    }
+
+    /// Scheme variant, tonal spot by default
+    pub fn with_variant(self, variant: Variant) -> Self {
+        Self { variant, ..self }
+    }
+
+    pub fn with_contrast(self, contrast: ContrastLevel) -> Self {
+        Self { contrast, ..self }
+    }
+
+    /// Add a named custom color, optionally harmonized toward the source color
+    pub fn with_custom_color(
+        mut self,
+        name: impl Into<String>,
+        argb: u32,
+        harmonize: bool,
+    ) -> Self {
+        self.custom_colors.push(CustomColor {
+            value: Argb::from_u32(argb),
+            name: name.into(),
+            blend: harmonize,
+        });
+        self
+    }
+
+    pub fn build(self) -> MaterialTheme {
+        let Self {
+            source,
+            color_mode,
+            variant,
+            contrast,
+            custom_colors,
+        } = self;
+
+        let mut theme = ThemeBuilder::with_source(source)
+            .variant(variant.clone())
+            .custom_colors(custom_colors)
+            .build();
+
+        // material-colors 的 ThemeBuilder 只生成标准对比度，
+        // 其调色板也总是按 tonal spot 生成，改用方案实际使用的调色板
+        let level = Some(contrast.value());
+        let light = DynamicScheme::by_variant(source, &variant, false, level);
+        let dark = DynamicScheme::by_variant(source, &variant, true, level);
+        theme.palettes = Palettes {
+            primary: light.primary_palette,
+            secondary: light.secondary_palette,
+            tertiary: light.tertiary_palette,
+            neutral: light.neutral_palette,
+            neutral_variant: light.neutral_variant_palette,
+            error: light.error_palette,
+        };
+        theme.schemes.light = light.into();
+        theme.schemes.dark = dark.into();
+
+        MaterialTheme {
+            palettes: MaterialPalettes::from(&theme.palettes),
+            theme,
+            color_mode,
+            variant,
+            contrast,
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Export of the theme as design tokens for other platforms
+
+use std::fmt::Write;
+
+use material_colors::{color::Argb, scheme::Scheme};
+
+use super::builder::variant_name;
+use super::palette::MaterialPalette;
+use super::theme::{ColorMode, MaterialTheme};
+use crate::utils::clone_scheme;
+
+/// Tones exported for every tonal palette
+pub const PALETTE_TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+fn kebab_case(name: &str) -> String {
+    name.replace('_', "-")
+}
+
+fn rgb_hex(argb: Argb) -> String {
+    format!("#{:02x}{:02x}{:02x}", argb.red, argb.green, argb.blue)
+}
+
+impl MaterialTheme {
+    /// Schemes of the color mode, both of them for [`ColorMode::System`]
+    fn export_schemes(&self) -> Vec<(&'static str, &Scheme)> {
+        match self.color_mode {
+            ColorMode::Light => vec![("light", self.get_light_scheme())],
+            ColorMode::Dark => vec![("dark", self.get_dark_scheme())],
+            ColorMode::System => vec![
+                ("light", self.get_light_scheme()),
+                ("dark", self.get_dark_scheme()),
+            ],
+        }
+    }
+
+    fn export_palettes(&self) -> [(&'static str, MaterialPalette); 6] {
+        let palettes = self.palettes();
+        [
+            ("primary", palettes.primary),
+            ("secondary", palettes.secondary),
+            ("tertiary", palettes.tertiary),
+            ("neutral", palettes.neutral),
+            ("neutral_variant", palettes.neutral_variant),
+            ("error", palettes.error),
+        ]
+    }
+
+    /// Scheme roles and tonal palettes as CSS custom properties
+    ///
+    /// Names follow the Material Web tokens, e.g. `--md-sys-color-on-primary`
+    /// and `--md-ref-palette-primary40`. With [`ColorMode::System`] the dark
+    /// scheme is wrapped in a `prefers-color-scheme` media query.
+    ///
+    /// The palettes are the ones the schemes take their tones from:
+    ///
+    /// ```rust
+    /// # use egui_material::prelude::*;
+    /// // light primary is tone 0 of the primary palette in monochrome, tone 40 otherwise
+    /// for (variant, tone) in [
+    ///     (Variant::Monochrome, 0),
+    ///     (Variant::TonalSpot, 40),
+    ///     (Variant::Vibrant, 40),
+    ///     (Variant::FruitSalad, 40),
+    /// ] {
+    ///     let css = MaterialTheme::builder(0xff6750a4).with_variant(variant).build().to_css();
+    ///     let primary = css
+    ///         .lines()
+    ///         .find_map(|line| line.trim().strip_prefix("--md-sys-color-primary: "))
+    ///         .unwrap();
+    ///     assert!(css.contains(&format!("--md-ref-palette-primary{tone}: {primary}")));
+    /// }
+    /// ```
+    pub fn to_css(&self) -> String {
+        let mut css = String::new();
+
+        css.push_str(":root {\n");
+        for (name, palette) in self.export_palettes() {
+            for tone in PALETTE_TONES {
+                let _ = writeln!(
+                    css,
+                    "  --md-ref-palette-{}{tone}: {};",
+                    kebab_case(name),
+                    rgb_hex(palette.argb(tone))
+                );
+            }
+        }
+        css.push_str("}\n");
+
+        for (mode, scheme) in self.export_schemes() {
+            let media = self.color_mode == ColorMode::System && mode == "dark";
+            let indent = if media { "  " } else { "" };
+
+            css.push('\n');
+            if media {
+                css.push_str("@media (prefers-color-scheme: dark) {\n");
+            }
+            let _ = writeln!(css, "{indent}:root {{");
+            for (role, argb) in clone_scheme(scheme) {
+                let _ = writeln!(
+                    css,
+                    "{indent}  --md-sys-color-{}: {};",
+                    kebab_case(&role),
+                    rgb_hex(argb)
+                );
+            }
+            let _ = writeln!(css, "{indent}}}");
+            if media {
+                css.push_str("}\n");
+            }
+        }
+
+        css
+    }
+
+    /// Scheme roles and tonal palettes as a Rust module of `egui::Color32` constants
+    ///
+    /// Every scheme gets its own module (`light` / `dark`), the palettes live in
+    /// `palettes`, e.g. `palettes::PRIMARY_40`.
+    pub fn to_rust_module(&self) -> String {
+        let mut code = String::new();
+
+        let _ = writeln!(
+            code,
+            "//! Material color tokens generated from {} ({})",
+            rgb_hex(self.source()),
+            variant_name(self.variant())
+        );
+        code.push_str("#![allow(dead_code)]\n");
+
+        code.push_str("\npub mod palettes {\n    use egui::Color32;\n\n");
+        for (name, palette) in self.export_palettes() {
+            for tone in PALETTE_TONES {
+                let argb = palette.argb(tone);
+                let _ = writeln!(
+                    code,
+                    "    pub const {}_{tone}: Color32 = Color32::from_rgb({}, {}, {});",
+                    name.to_ascii_uppercase(),
+                    argb.red,
+                    argb.green,
+                    argb.blue
+                );
+            }
+        }
+        code.push_str("}\n");
+
+        for (mode, scheme) in self.export_schemes() {
+            let _ = writeln!(code, "\npub mod {mode} {{\n    use egui::Color32;\n");
+            for (role, argb) in clone_scheme(scheme) {
+                let _ = writeln!(
+                    code,
+                    "    pub const {}: Color32 = Color32::from_rgb({}, {}, {});",
+                    role.to_ascii_uppercase(),
+                    argb.red,
+                    argb.green,
+                    argb.blue
+                );
+            }
+            code.push_str("}\n");
+        }
+
+        code
+    }
+
+    /// Scheme roles and tonal palettes as JSON
+    ///
+    /// ```json
+    /// {
+    ///   "source": "#6750a4",
+    ///   "variant": "tonal_spot",
+    ///   "contrast": 0.0,
+    ///   "schemes": { "light": { "primary": "#6750a4", ... } },
+    ///   "palettes": { "primary": { "40": "#6750a4", ... } }
+    /// }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_tokens_json(&self) -> String {
+        use std::collections::BTreeMap;
+
+        /// Tones are keyed by number so they are written in numeric order
+        #[derive(serde::Serialize)]
+        struct TokensJson {
+            source: String,
+            variant: &'static str,
+            contrast: f64,
+            schemes: BTreeMap<&'static str, BTreeMap<String, String>>,
+            palettes: BTreeMap<&'static str, BTreeMap<u8, String>>,
+        }
+
+        let schemes = self
+            .export_schemes()
+            .into_iter()
+            .map(|(mode, scheme)| {
+                let roles = clone_scheme(scheme)
+                    .into_iter()
+                    .map(|(role, argb)| (role, rgb_hex(argb)))
+                    .collect();
+                (mode, roles)
+            })
+            .collect();
+
+        let palettes = self
+            .export_palettes()
+            .into_iter()
+            .map(|(name, palette)| {
+                let tones = PALETTE_TONES
+                    .iter()
+                    .map(|tone| (*tone, rgb_hex(palette.argb(*tone))))
+                    .collect();
+                (name, tones)
+            })
+            .collect();
+
+        let tokens = TokensJson {
+            source: rgb_hex(self.source()),
+            variant: variant_name(self.variant()),
+            contrast: self.contrast.value(),
+            schemes,
+            palettes,
+        };
+        serde_json::to_string_pretty(&tokens).unwrap_or_default()
+    }
+}
@@ -0,0 +1,238 @@
+//! Import of Material Theme Builder exports
+
+use std::fmt;
+
+use material_colors::{hct::Hct, palette::TonalPalette};
+use serde_json::Value;
+
+use super::builder::{ContrastLevel, MaterialThemeBuilder};
+use super::serialize::{apply_scheme_map, parse_hex};
+use super::theme::{ColorMode, MaterialTheme};
+use crate::utils::argb_to_u32;
+
+/// Error while importing a theme file
+#[derive(Debug)]
+pub enum ThemeImportError {
+    /// The file is not valid JSON
+    Json(serde_json::Error),
+    /// A required field is missing or has the wrong type
+    MissingField(&'static str),
+    /// A color or role could not be read
+    Invalid(String),
+}
+
+impl fmt::Display for ThemeImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeImportError::Json(err) => write!(f, "invalid theme json: {err}"),
+            ThemeImportError::MissingField(field) => write!(f, "missing field `{field}`"),
+            ThemeImportError::Invalid(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for ThemeImportError {}
+
+impl From<serde_json::Error> for ThemeImportError {
+    fn from(err: serde_json::Error) -> Self {
+        ThemeImportError::Json(err)
+    }
+}
+
+/// `onPrimaryContainer` -> `on_primary_container`
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn scheme_key(dark: bool, contrast: ContrastLevel) -> &'static str {
+    let medium = contrast.value() >= 0.25;
+    let high = contrast.value() >= 0.75;
+    match (dark, high, medium) {
+        (false, true, _) => "light-high-contrast",
+        (false, false, true) => "light-medium-contrast",
+        (false, false, false) => "light",
+        (true, true, _) => "dark-high-contrast",
+        (true, false, true) => "dark-medium-contrast",
+        (true, false, false) => "dark",
+    }
+}
+
+impl MaterialTheme {
+    /// Import the JSON file exported by the
+    /// [Material Theme Builder](https://material-foundation.github.io/material-theme-builder/)
+    ///
+    /// The theme is generated from the exported seed and extended colors, then
+    /// the exported schemes for the requested contrast level replace the
+    /// generated ones. The exported palette tones are used as they are, the other
+    /// tones come from the core colors, or the seed when they are missing.
+    pub fn from_theme_builder_json(
+        json: &str,
+        color_mode: ColorMode,
+        contrast: ContrastLevel,
+    ) -> Result<Self, ThemeImportError> {
+        let file: Value = serde_json::from_str(json)?;
+
+        let seed = file["seed"]
+            .as_str()
+            .ok_or(ThemeImportError::MissingField("seed"))?;
+        let seed = parse_hex(seed).map_err(ThemeImportError::Invalid)?;
+
+        let mut builder = MaterialThemeBuilder::from_source(seed)
+            .with_color_mode(color_mode)
+            .with_contrast(contrast);
+        if let Some(extended) = file["extendedColors"].as_array() {
+            for color in extended {
+                let name = color["name"]
+                    .as_str()
+                    .ok_or(ThemeImportError::MissingField("extendedColors.name"))?;
+                let value = color["color"]
+                    .as_str()
+                    .ok_or(ThemeImportError::MissingField("extendedColors.color"))?;
+                let value = parse_hex(value).map_err(ThemeImportError::Invalid)?;
+                let harmonize = color["harmonized"].as_bool().unwrap_or(false);
+                builder = builder.with_custom_color(name, argb_to_u32(value), harmonize);
+            }
+        }
+        let mut theme = builder.build();
+
+        for dark in [false, true] {
+            let Some(roles) = file["schemes"][scheme_key(dark, contrast)].as_object() else {
+                continue;
+            };
+            let roles = roles
+                .iter()
+                .map(|(role, hex)| {
+                    let hex = hex.as_str().ok_or_else(|| {
+                        ThemeImportError::Invalid(format!("invalid color `{hex}`"))
+                    })?;
+                    Ok((snake_case(role), hex))
+                })
+                .collect::<Result<Vec<_>, ThemeImportError>>()?;
+            let scheme = match dark {
+                true => &mut theme.theme.schemes.dark,
+                false => &mut theme.theme.schemes.light,
+            };
+            apply_scheme_map(
+                scheme,
+                roles.iter().map(|(role, hex)| (role.as_str(), *hex)),
+            )
+            .map_err(ThemeImportError::Invalid)?;
+        }
+
+        // 导出了各色调时直接使用，否则由核心颜色生成调色板
+        let palettes = &mut theme.palettes;
+        for (key, palette_key, palette) in [
+            ("primary", "primary", &mut palettes.primary),
+            ("secondary", "secondary", &mut palettes.secondary),
+            ("tertiary", "tertiary", &mut palettes.tertiary),
+            ("error", "error", &mut palettes.error),
+            ("neutral", "neutral", &mut palettes.neutral),
+            (
+                "neutralVariant",
+                "neutral-variant",
+                &mut palettes.neutral_variant,
+            ),
+        ] {
+            if let Some(hex) = file["coreColors"][key].as_str() {
+                let argb = parse_hex(hex).map_err(ThemeImportError::Invalid)?;
+                *palette = TonalPalette::from_hct(Hct::new(argb)).into();
+            }
+            let tones = (file["palettes"][palette_key].as_object())
+                .or_else(|| file["palettes"][key].as_object());
+            for (tone, hex) in tones.into_iter().flatten() {
+                let tone = (tone.parse::<u8>().ok())
+                    .filter(|tone| *tone <= 100)
+                    .ok_or_else(|| ThemeImportError::Invalid(format!("invalid tone `{tone}`")))?;
+                let hex = hex
+                    .as_str()
+                    .ok_or_else(|| ThemeImportError::Invalid(format!("invalid color `{hex}`")))?;
+                palette.set_tone(tone, parse_hex(hex).map_err(ThemeImportError::Invalid)?);
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Color32;
+
+    use super::*;
+
+    const THEME: &str = r##"{
+        "seed": "#6750A4",
+        "coreColors": { "primary": "#6750A4", "neutralVariant": "#79747E" },
+        "palettes": {
+            "primary": { "0": "#000000", "40": "#123456", "90": "#ABCDEF" },
+            "neutral-variant": { "50": "#777777" }
+        }
+    }"##;
+
+    #[test]
+    fn palette_tones() {
+        let theme = MaterialTheme::from_theme_builder_json(
+            THEME,
+            ColorMode::Light,
+            ContrastLevel::Standard,
+        )
+        .unwrap();
+        let palettes = theme.palettes();
+
+        // 导出的色调原样使用
+        assert_eq!(palettes.primary.tone(0), Color32::BLACK);
+        assert_eq!(
+            palettes.primary.tone(40),
+            Color32::from_rgb(0x12, 0x34, 0x56)
+        );
+        assert_eq!(
+            palettes.primary.tone(90),
+            Color32::from_rgb(0xab, 0xcd, 0xef)
+        );
+        assert_eq!(palettes.neutral_variant.tone(50), Color32::from_gray(0x77));
+
+        // 其余色调由核心颜色生成
+        let core = TonalPalette::from_hct(Hct::new(parse_hex("#6750A4").unwrap()));
+        assert_eq!(palettes.primary.argb(80), core.tone(80));
+    }
+
+    #[test]
+    fn palette_tones_round_trip() {
+        let theme = MaterialTheme::from_theme_builder_json(
+            THEME,
+            ColorMode::Light,
+            ContrastLevel::Standard,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&theme).unwrap();
+        let loaded: MaterialTheme = serde_json::from_str(&json).unwrap();
+
+        // 导入的色调与核心颜色在保存后仍然保留
+        let (saved, loaded) = (theme.palettes(), loaded.palettes());
+        for tone in [0, 40, 50, 80, 90] {
+            assert_eq!(loaded.primary.tone(tone), saved.primary.tone(tone));
+            assert_eq!(
+                loaded.neutral_variant.tone(tone),
+                saved.neutral_variant.tone(tone)
+            );
+        }
+        assert_eq!(loaded.primary.tone(40), Color32::from_rgb(0x12, 0x34, 0x56));
+    }
+
+    #[test]
+    fn invalid_tone() {
+        let json = r##"{ "seed": "#6750A4", "palettes": { "primary": { "120": "#000000" } } }"##;
+        let result =
+            MaterialTheme::from_theme_builder_json(json, ColorMode::Light, ContrastLevel::Standard);
+        assert!(matches!(result, Err(ThemeImportError::Invalid(_))));
+    }
+}
@@ -1,4 +1,13 @@
 //! Material Design Color System
 
+mod builder;
+mod export;
+#[cfg(feature = "serde")]
+mod import;
+mod palette;
 pub mod prelude;
+mod role;
+#[cfg(feature = "serde")]
+mod serialize;
 mod theme;
+mod transition;
@@ -0,0 +1,127 @@
+//! Tonal palettes and egui colors of scheme roles
+
+use std::collections::BTreeMap;
+
+use egui::Color32;
+use material_colors::{color::Argb, palette::TonalPalette, scheme::Scheme, theme::Palettes};
+
+use crate::utils::{argb_to_color32, with_scheme_roles};
+
+/// A tonal palette: one hue and chroma in every tone
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// let theme = MaterialTheme::default();
+/// let background = theme.palettes().neutral.tone(6);
+/// let highlight = theme.palettes().primary.tone(95);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaterialPalette {
+    palette: TonalPalette,
+    /// Exact colors of some tones, e.g. imported from a theme file
+    tones: BTreeMap<u8, Argb>,
+}
+
+impl MaterialPalette {
+    /// Color at a tone from 0 (black) to 100 (white)
+    pub fn tone(&self, tone: u8) -> Color32 {
+        argb_to_color32(self.argb(tone))
+    }
+
+    /// Color at a tone from 0 (black) to 100 (white)
+    pub fn argb(&self, tone: u8) -> Argb {
+        let tone = tone.min(100);
+        match self.tones.get(&tone) {
+            Some(argb) => *argb,
+            None => self.palette.tone(i32::from(tone)),
+        }
+    }
+
+    /// The color the palette was created from
+    pub fn key_color(&self) -> Color32 {
+        argb_to_color32(self.palette.key_color().into())
+    }
+
+    pub fn hue(&self) -> f64 {
+        self.palette.hue()
+    }
+
+    pub fn chroma(&self) -> f64 {
+        self.palette.chroma()
+    }
+
+    /// Use an exact color for a tone instead of the generated one
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub(super) fn set_tone(&mut self, tone: u8, argb: Argb) {
+        self.tones.insert(tone.min(100), argb);
+    }
+
+    /// Tones set with [`Self::set_tone`]
+    #[cfg(feature = "serde")]
+    pub(super) fn tone_overrides(&self) -> &BTreeMap<u8, Argb> {
+        &self.tones
+    }
+}
+
+impl From<TonalPalette> for MaterialPalette {
+    fn from(palette: TonalPalette) -> Self {
+        Self {
+            palette,
+            tones: BTreeMap::new(),
+        }
+    }
+}
+
+/// The core palettes of a theme
+#[derive(Clone, Debug)]
+pub struct MaterialPalettes {
+    pub primary: MaterialPalette,
+    pub secondary: MaterialPalette,
+    pub tertiary: MaterialPalette,
+    pub neutral: MaterialPalette,
+    pub neutral_variant: MaterialPalette,
+    pub error: MaterialPalette,
+}
+
+impl From<&Palettes> for MaterialPalettes {
+    fn from(palettes: &Palettes) -> Self {
+        Self {
+            primary: palettes.primary.into(),
+            secondary: palettes.secondary.into(),
+            tertiary: palettes.tertiary.into(),
+            neutral: palettes.neutral.into(),
+            neutral_variant: palettes.neutral_variant.into(),
+            error: palettes.error.into(),
+        }
+    }
+}
+
+macro_rules! scheme_colors {
+    ($($role:ident),*) => {
+        /// egui colors of the scheme roles
+        ///
+        /// Every role of [`Scheme`] is available as a method with the same
+        /// name, e.g. `scheme.surface_container_high()`.
+        ///
+        /// # Example
+        /// ```rust
+        /// # use egui_material::prelude::*;
+        /// let theme = MaterialTheme::default();
+        /// let scheme = theme.get();
+        /// let fill = scheme.surface_container_high();
+        /// let text = scheme.on_primary_fixed_variant();
+        /// ```
+        pub trait SchemeColors {
+            $(fn $role(&self) -> Color32;)*
+        }
+
+        impl SchemeColors for Scheme {
+            $(fn $role(&self) -> Color32 {
+                argb_to_color32(self.$role)
+            })*
+        }
+    };
+}
+
+with_scheme_roles!(scheme_colors);
@@ -1 +1,8 @@
+pub use super::builder::*;
+pub use super::export::*;
+#[cfg(feature = "serde")]
+pub use super::import::*;
+pub use super::palette::*;
+pub use super::role::*;
 pub use super::theme::*;
+pub use super::transition::*;
@@ -0,0 +1,120 @@
+//! Material Design color roles
+
+use egui::Color32;
+use material_colors::{
+    scheme::Scheme,
+    theme::{ColorGroup, CustomColorGroup},
+};
+
+use crate::utils::argb_to_color32;
+
+/// The four roles of a color group, e.g. primary / on primary /
+/// primary container / on primary container
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorRoles {
+    pub color: Color32,
+    pub on_color: Color32,
+    pub color_container: Color32,
+    pub on_color_container: Color32,
+}
+
+impl From<&ColorGroup> for ColorRoles {
+    fn from(group: &ColorGroup) -> Self {
+        Self {
+            color: argb_to_color32(group.color),
+            on_color: argb_to_color32(group.on_color),
+            color_container: argb_to_color32(group.color_container),
+            on_color_container: argb_to_color32(group.on_color_container),
+        }
+    }
+}
+
+/// Light and dark roles of a named custom (extended) color
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomColorRoles {
+    pub name: String,
+    pub light: ColorRoles,
+    pub dark: ColorRoles,
+}
+
+impl CustomColorRoles {
+    /// Roles for the given color mode
+    pub fn get(&self, dark_mode: bool) -> ColorRoles {
+        match dark_mode {
+            true => self.dark,
+            false => self.light,
+        }
+    }
+}
+
+impl From<&CustomColorGroup> for CustomColorRoles {
+    fn from(group: &CustomColorGroup) -> Self {
+        Self {
+            name: group.color.name.clone(),
+            light: ColorRoles::from(&group.light),
+            dark: ColorRoles::from(&group.dark),
+        }
+    }
+}
+
+/// Color a component is styled with
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorRole {
+    #[default]
+    Primary,
+    Secondary,
+    Tertiary,
+    Error,
+    /// A custom color added through [`MaterialThemeBuilder::with_custom_color`]
+    ///
+    /// [`MaterialThemeBuilder::with_custom_color`]: super::builder::MaterialThemeBuilder::with_custom_color
+    Custom(String),
+}
+
+impl ColorRole {
+    pub fn custom(name: impl Into<String>) -> Self {
+        Self::Custom(name.into())
+    }
+
+    /// Roles of a scheme color
+    ///
+    /// Custom colors are not part of the scheme and resolve to primary,
+    /// see [`MaterialTokens::roles`](crate::tokens::MaterialTokens::roles).
+    pub fn scheme_roles(&self, scheme: &Scheme) -> ColorRoles {
+        let (color, on_color, color_container, on_color_container) = match self {
+            ColorRole::Primary | ColorRole::Custom(_) => (
+                scheme.primary,
+                scheme.on_primary,
+                scheme.primary_container,
+                scheme.on_primary_container,
+            ),
+            ColorRole::Secondary => (
+                scheme.secondary,
+                scheme.on_secondary,
+                scheme.secondary_container,
+                scheme.on_secondary_container,
+            ),
+            ColorRole::Tertiary => (
+                scheme.tertiary,
+                scheme.on_tertiary,
+                scheme.tertiary_container,
+                scheme.on_tertiary_container,
+            ),
+            ColorRole::Error => (
+                scheme.error,
+                scheme.on_error,
+                scheme.error_container,
+                scheme.on_error_container,
+            ),
+        };
+        ColorRoles {
+            color: argb_to_color32(color),
+            on_color: argb_to_color32(on_color),
+            color_container: argb_to_color32(color_container),
+            on_color_container: argb_to_color32(on_color_container),
+        }
+    }
+}
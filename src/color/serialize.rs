@@ -0,0 +1,198 @@
+//! Serde support for [`MaterialTheme`]
+
+use std::collections::BTreeMap;
+
+use material_colors::{color::Argb, palette::TonalPalette, scheme::Scheme};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use super::builder::{ContrastLevel, MaterialThemeBuilder, parse_variant, variant_name};
+use super::palette::{MaterialPalette, MaterialPalettes};
+use super::theme::{ColorMode, MaterialTheme};
+use crate::utils::{argb_to_u32, clone_scheme, scheme_role_mut};
+
+/// A custom color as written in theme files
+#[derive(Serialize, Deserialize)]
+struct CustomColorRepr {
+    name: String,
+    value: String,
+    #[serde(default)]
+    harmonize: bool,
+}
+
+/// Light and dark scheme roles as `role name -> #rrggbb`
+#[derive(Default, Serialize, Deserialize)]
+struct SchemesRepr {
+    #[serde(default)]
+    light: BTreeMap<String, String>,
+    #[serde(default)]
+    dark: BTreeMap<String, String>,
+}
+
+/// A tonal palette with the tones that were set to exact colors
+#[derive(Serialize, Deserialize)]
+struct PaletteRepr {
+    hue: f64,
+    chroma: f64,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tones: BTreeMap<u8, String>,
+}
+
+/// The serialized form of a theme
+///
+/// The theme is rebuilt from its source color and settings, then the scheme
+/// roles and palettes are applied on top so edited or imported schemes and
+/// palette tones survive a round trip.
+#[derive(Serialize, Deserialize)]
+struct MaterialThemeRepr {
+    source: String,
+    #[serde(default)]
+    color_mode: ColorMode,
+    #[serde(default = "default_variant")]
+    variant: String,
+    #[serde(default)]
+    contrast: ContrastLevel,
+    #[serde(default)]
+    custom_colors: Vec<CustomColorRepr>,
+    #[serde(default)]
+    schemes: SchemesRepr,
+    #[serde(default)]
+    palettes: BTreeMap<String, PaletteRepr>,
+}
+
+fn default_variant() -> String {
+    "tonal_spot".to_owned()
+}
+
+fn scheme_to_map(scheme: &Scheme) -> BTreeMap<String, String> {
+    clone_scheme(scheme)
+        .into_iter()
+        .map(|(role, argb)| (role, argb.to_hex_with_pound()))
+        .collect()
+}
+
+/// Overwrite scheme roles from `role name -> hex` pairs
+pub(super) fn apply_scheme_map<'a>(
+    scheme: &mut Scheme,
+    roles: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Result<(), String> {
+    for (role, hex) in roles {
+        let argb = parse_hex(hex)?;
+        let slot = scheme_role_mut(scheme, role).ok_or_else(|| format!("unknown role `{role}`"))?;
+        *slot = argb;
+    }
+    Ok(())
+}
+
+fn palettes_mut(palettes: &mut MaterialPalettes) -> [(&'static str, &mut MaterialPalette); 6] {
+    [
+        ("primary", &mut palettes.primary),
+        ("secondary", &mut palettes.secondary),
+        ("tertiary", &mut palettes.tertiary),
+        ("neutral", &mut palettes.neutral),
+        ("neutral_variant", &mut palettes.neutral_variant),
+        ("error", &mut palettes.error),
+    ]
+}
+
+pub(super) fn parse_hex(hex: &str) -> Result<Argb, String> {
+    hex.parse().map_err(|_| format!("invalid color `{hex}`"))
+}
+
+impl From<&MaterialTheme> for MaterialThemeRepr {
+    fn from(theme: &MaterialTheme) -> Self {
+        Self {
+            source: theme.source().to_hex_with_pound(),
+            color_mode: theme.get_color_mode(),
+            variant: variant_name(theme.variant()).to_owned(),
+            contrast: theme.contrast(),
+            custom_colors: theme
+                .theme
+                .custom_colors
+                .iter()
+                .map(|group| CustomColorRepr {
+                    name: group.color.name.clone(),
+                    value: group.color.value.to_hex_with_pound(),
+                    harmonize: group.color.blend,
+                })
+                .collect(),
+            schemes: SchemesRepr {
+                light: scheme_to_map(theme.get_light_scheme()),
+                dark: scheme_to_map(theme.get_dark_scheme()),
+            },
+            palettes: palettes_mut(&mut theme.palettes())
+                .into_iter()
+                .map(|(name, palette)| {
+                    let tones = (palette.tone_overrides().iter())
+                        .map(|(tone, argb)| (*tone, argb.to_hex_with_pound()))
+                        .collect();
+                    let repr = PaletteRepr {
+                        hue: palette.hue(),
+                        chroma: palette.chroma(),
+                        tones,
+                    };
+                    (name.to_owned(), repr)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<MaterialThemeRepr> for MaterialTheme {
+    type Error = String;
+
+    fn try_from(repr: MaterialThemeRepr) -> Result<Self, Self::Error> {
+        let variant = parse_variant(&repr.variant)
+            .ok_or_else(|| format!("unknown variant `{}`", repr.variant))?;
+
+        let mut builder = MaterialThemeBuilder::from_source(parse_hex(&repr.source)?)
+            .with_color_mode(repr.color_mode)
+            .with_variant(variant)
+            .with_contrast(repr.contrast);
+        for custom in &repr.custom_colors {
+            let value = parse_hex(&custom.value)?;
+            builder = builder.with_custom_color(&custom.name, argb_to_u32(value), custom.harmonize);
+        }
+        let mut theme = builder.build();
+
+        let light = repr.schemes.light.iter();
+        apply_scheme_map(
+            &mut theme.theme.schemes.light,
+            light.map(|(role, hex)| (role.as_str(), hex.as_str())),
+        )?;
+        let dark = repr.schemes.dark.iter();
+        apply_scheme_map(
+            &mut theme.theme.schemes.dark,
+            dark.map(|(role, hex)| (role.as_str(), hex.as_str())),
+        )?;
+
+        for (name, repr) in &repr.palettes {
+            let (_, palette) = palettes_mut(&mut theme.palettes)
+                .into_iter()
+                .find(|(key, _)| key == name)
+                .ok_or_else(|| format!("unknown palette `{name}`"))?;
+            *palette = TonalPalette::of(repr.hue, repr.chroma).into();
+            for (tone, hex) in &repr.tones {
+                if *tone > 100 {
+                    return Err(format!("invalid tone `{tone}`"));
+                }
+                palette.set_tone(*tone, parse_hex(hex)?);
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+impl Serialize for MaterialTheme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MaterialThemeRepr::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MaterialTheme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MaterialThemeRepr::deserialize(deserializer)?
+            .try_into()
+            .map_err(D::Error::custom)
+    }
+}
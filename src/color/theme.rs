@@ -1,61 +1,205 @@
-use crate::utils::argb_to_color32;
-use egui::Visuals;
+use super::builder::{ContrastLevel, MaterialThemeBuilder};
+use super::palette::{MaterialPalettes, SchemeColors};
+use super::role::{ColorRoles, CustomColorRoles};
+use crate::elevation::{ElevationLevel, MaterialElevation};
+use crate::shape::MaterialShapes;
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
+use egui::{
+    Color32, CornerRadius, Margin, Stroke, Visuals,
+    style::{HandleShape, Selection, Spacing, WidgetVisuals},
+    vec2,
+};
 pub use material_colors::{
     color::Argb,
+    dynamic_color::Variant,
     scheme::Scheme,
     theme::{Theme, ThemeBuilder},
 };
+use material_colors::{
+    quantize::{Quantizer, QuantizerCelebi},
+    score::Score,
+};
+use std::sync::Arc;
 
 /// Color mode for Material Design
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorMode {
+    #[default]
     Light,
     Dark,
+    /// Follow the system theme reported by egui
+    System,
 }
 
 /// Material Color Theme
 ///
 /// # Example
 /// ```rust
+/// # use egui_material::prelude::*;
+/// # let ctx = egui::Context::default();
 /// // create a theme from argb code
 /// let theme = MaterialTheme::from_argb(0xffaae5a4, ColorMode::Light);
 /// // get scheme from theme
 /// let scheme = theme.get();
-/// ````
-pub struct MaterialTheme(Theme, ColorMode);
+/// // install fonts, visuals and widget tokens into the egui context
+/// theme.install(&ctx);
+/// ```
+pub struct MaterialTheme {
+    pub(super) theme: Theme,
+    pub(super) palettes: MaterialPalettes,
+    pub(super) color_mode: ColorMode,
+    pub(super) variant: Variant,
+    pub(super) contrast: ContrastLevel,
+}
+
+impl Default for MaterialTheme {
+    /// The Material baseline theme
+    fn default() -> Self {
+        Self::from_argb(0xff6750a4, ColorMode::Light)
+    }
+}
 
 impl MaterialTheme {
     /// Build through argb base colors
     pub fn from_argb(argb: u32, color_mode: ColorMode) -> Self {
-        Self(
-            ThemeBuilder::with_source(Argb::from_u32(argb)).build(),
-            color_mode,
+        Self::from_source(Argb::from_u32(argb), color_mode)
+    }
+
+    /// Build through a seed color
+    pub fn from_source(source: Argb, color_mode: ColorMode) -> Self {
+        MaterialThemeBuilder::from_source(source)
+            .with_color_mode(color_mode)
+            .build()
+    }
+
+    /// Start building a theme with a variant, contrast level or custom colors
+    pub fn builder(argb: u32) -> MaterialThemeBuilder {
+        MaterialThemeBuilder::new(argb)
+    }
+
+    /// Build through the most suitable seed color of an image
+    ///
+    /// `pixels` is RGBA pixel data, e.g. [`egui::ColorImage::pixels`].
+    pub fn from_image(pixels: &[Color32], color_mode: ColorMode) -> Self {
+        let seed = Self::seeds_from_image(pixels, 1)[0];
+        Self::from_source(seed, color_mode)
+    }
+
+    /// Rank the colors of an image by how suitable they are as a seed color
+    ///
+    /// Returns at most `count` colors, best first, and always at least one.
+    /// Fully or partially transparent pixels are ignored.
+    pub fn seeds_from_image(pixels: &[Color32], count: usize) -> Vec<Argb> {
+        // 采样到最多 128x128 个像素，量化开销与图片大小无关
+        let step = (pixels.len() / (128 * 128)).max(1);
+        let pixels: Vec<Argb> = pixels
+            .iter()
+            .step_by(step)
+            .filter(|pixel| pixel.a() == u8::MAX)
+            .map(|pixel| Argb::new(pixel.a(), pixel.r(), pixel.g(), pixel.b()))
+            .collect();
+
+        let result = QuantizerCelebi::quantize(&pixels, 128);
+        Score::score(
+            &result.color_to_count,
+            Some(count.max(1) as i32),
+            None,
+            None,
         )
     }
 
+    /// Source (seed) color of the theme
+    pub fn source(&self) -> Argb {
+        self.theme.source
+    }
+
+    /// Scheme variant the theme was built with
+    pub fn variant(&self) -> &Variant {
+        &self.variant
+    }
+
+    /// Contrast level the theme was built with
+    pub fn contrast(&self) -> ContrastLevel {
+        self.contrast
+    }
+
+    /// Roles of every custom color, in the order they were added
+    pub fn custom_colors(&self) -> Vec<CustomColorRoles> {
+        self.theme
+            .custom_colors
+            .iter()
+            .map(CustomColorRoles::from)
+            .collect()
+    }
+
+    /// Roles of a custom color in the current color mode, asking egui for the system theme
+    pub fn custom_color(&self, ctx: &egui::Context, name: &str) -> Option<ColorRoles> {
+        let dark = self.is_dark_in(ctx);
+        self.theme
+            .custom_colors
+            .iter()
+            .find(|group| group.color.name == name)
+            .map(|group| CustomColorRoles::from(group).get(dark))
+    }
+
+    /// The core tonal palettes of the theme, the ones its schemes take their tones from
+    pub fn palettes(&self) -> MaterialPalettes {
+        self.palettes.clone()
+    }
+
     /// Get light theme color schemes
     pub fn get_light_scheme(&self) -> &Scheme {
-        &self.0.schemes.light
+        &self.theme.schemes.light
     }
 
     /// Get dark theme color schemes
     pub fn get_dark_scheme(&self) -> &Scheme {
-        &self.0.schemes.dark
+        &self.theme.schemes.dark
     }
 
-    // Get the schemes in the current color mode
+    /// Get the schemes in the current color mode
+    ///
+    /// [`ColorMode::System`] falls back to the light scheme, use
+    /// [`Self::get_in`] to follow the system theme.
     pub fn get(&self) -> &Scheme {
-        match self.1 {
-            ColorMode::Light => self.get_light_scheme(),
-            ColorMode::Dark => self.get_dark_scheme(),
+        match self.get_dark_mode() {
+            true => self.get_dark_scheme(),
+            false => self.get_light_scheme(),
+        }
+    }
+
+    /// Get the schemes in the current color mode, asking egui for the system theme
+    pub fn get_in(&self, ctx: &egui::Context) -> &Scheme {
+        match self.is_dark_in(ctx) {
+            true => self.get_dark_scheme(),
+            false => self.get_light_scheme(),
         }
     }
 
+    /// Get Color Mode
+    pub fn get_color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Set Color Mode
+    pub fn set_color_mode(&mut self, color_mode: ColorMode) {
+        self.color_mode = color_mode;
+    }
+
     /// Get Color Mode
     pub fn get_dark_mode(&self) -> bool {
-        match self.1 {
-            ColorMode::Dark => true,
+        matches!(self.color_mode, ColorMode::Dark)
+    }
+
+    /// Whether the dark scheme is in use, asking egui for the system theme
+    pub fn is_dark_in(&self, ctx: &egui::Context) -> bool {
+        match self.color_mode {
             ColorMode::Light => false,
+            ColorMode::Dark => true,
+            ColorMode::System => ctx.system_theme() == Some(egui::Theme::Dark),
         }
     }
 
@@ -63,34 +207,180 @@ impl MaterialTheme {
     pub fn set_dark_mode(&mut self, dark: bool) {
         match dark {
             true => {
-                self.1 = ColorMode::Dark;
+                self.color_mode = ColorMode::Dark;
             }
             false => {
-                self.1 = ColorMode::Light;
+                self.color_mode = ColorMode::Light;
             }
         }
     }
 
+    /// egui visuals mapped from the current scheme
+    ///
+    /// Covers the widget states, selection, windows, menus and text cursor so
+    /// stock egui widgets look like the Material ones.
+    pub fn visuals(&self) -> Visuals {
+        Self::scheme_visuals(self.get(), self.get_dark_mode())
+    }
+
+    /// egui visuals mapped from any scheme
+    pub fn scheme_visuals(scheme: &Scheme, dark_mode: bool) -> Visuals {
+        let mut visuals = match dark_mode {
+            false => Visuals::light(),
+            true => Visuals::dark(),
+        };
+
+        visuals.window_fill = scheme.surface();
+        visuals.faint_bg_color = scheme.surface_container();
+        visuals.extreme_bg_color = scheme.surface_variant();
+        visuals.text_edit_bg_color = Some(scheme.surface_container_highest());
+        visuals.code_bg_color = scheme.surface_dim();
+        visuals.panel_fill = scheme.surface_container_high();
+        visuals.warn_fg_color = scheme.error_container();
+        visuals.error_fg_color = scheme.error();
+
+        visuals.override_text_color = Some(scheme.on_surface());
+        visuals.weak_text_color = Some(scheme.on_surface_variant());
+        visuals.hyperlink_color = scheme.primary();
+
+        // 选中文本与可选中标签
+        visuals.selection = Selection {
+            bg_fill: scheme.primary_container(),
+            stroke: Stroke::new(1.0, scheme.on_primary_container()),
+        };
+        visuals.text_cursor.stroke = Stroke::new(2.0, scheme.primary());
+
+        // 窗口（对话框）与菜单
+        visuals.window_stroke = Stroke::new(1.0, scheme.outline_variant());
+        // 对话框 3 级、菜单 2 级阴影
+        let elevation = MaterialElevation::default();
+        visuals.window_shadow = elevation.shadow(ElevationLevel::Level3, scheme.shadow());
+        visuals.popup_shadow = elevation.shadow(ElevationLevel::Level2, scheme.shadow());
+
+        // 控件状态，悬停 8%，按下 10% 的状态层
+        let container = scheme.surface_container_highest();
+        let tonal = scheme.secondary_container();
+        let on_tonal = scheme.on_secondary_container();
+        let on_surface = scheme.on_surface();
+        // 圆角由 MaterialShapes 统一设置
+        let rounding = CornerRadius::ZERO;
+
+        visuals.widgets.noninteractive = WidgetVisuals {
+            bg_fill: scheme.surface_container(),
+            weak_bg_fill: scheme.surface_container(),
+            bg_stroke: Stroke::new(1.0, scheme.outline_variant()),
+            corner_radius: rounding,
+            fg_stroke: Stroke::new(1.0, scheme.on_surface_variant()),
+            expansion: 0.0,
+        };
+        visuals.widgets.inactive = WidgetVisuals {
+            bg_fill: container,
+            weak_bg_fill: tonal,
+            bg_stroke: Stroke::NONE,
+            corner_radius: rounding,
+            fg_stroke: Stroke::new(1.0, on_tonal),
+            expansion: 0.0,
+        };
+        visuals.widgets.hovered = WidgetVisuals {
+            bg_fill: container.lerp_to_gamma(on_surface, 0.08),
+            weak_bg_fill: tonal.lerp_to_gamma(on_tonal, 0.08),
+            bg_stroke: Stroke::new(1.0, scheme.outline()),
+            corner_radius: rounding,
+            fg_stroke: Stroke::new(1.5, on_tonal),
+            expansion: 1.0,
+        };
+        visuals.widgets.active = WidgetVisuals {
+            bg_fill: container.lerp_to_gamma(on_surface, 0.1),
+            weak_bg_fill: tonal.lerp_to_gamma(on_tonal, 0.1),
+            bg_stroke: Stroke::new(1.0, scheme.primary()),
+            corner_radius: rounding,
+            fg_stroke: Stroke::new(2.0, on_tonal),
+            expansion: 1.0,
+        };
+        visuals.widgets.open = WidgetVisuals {
+            bg_fill: scheme.surface_container_high(),
+            weak_bg_fill: tonal,
+            bg_stroke: Stroke::new(1.0, scheme.outline_variant()),
+            corner_radius: rounding,
+            fg_stroke: Stroke::new(1.0, on_surface),
+            expansion: 0.0,
+        };
+
+        visuals.slider_trailing_fill = true;
+        visuals.handle_shape = HandleShape::Circle;
+        MaterialShapes::default().apply_to(&mut visuals);
+
+        visuals
+    }
+
+    /// egui spacing following the Material layout metrics
+    pub fn spacing() -> Spacing {
+        Spacing {
+            item_spacing: vec2(8.0, 8.0),
+            button_padding: vec2(16.0, 6.0),
+            window_margin: Margin::same(24),
+            menu_margin: Margin::same(8),
+            icon_width: 18.0,
+            icon_width_inner: 10.0,
+            icon_spacing: 8.0,
+            slider_rail_height: 4.0,
+            ..Spacing::default()
+        }
+    }
+
     /// Apply it to your egui application
+    ///
+    /// Also makes the scheme the one Material widgets are styled with,
+    /// call it again after changing the color mode to restyle everything.
+    /// With [`ColorMode::System`] both schemes are installed and egui picks one
+    /// following the system theme.
     pub fn apply(&self, ctx: &egui::Context) {
-        let mut visuals = match self.1 {
-            ColorMode::Light => Visuals::light(),
-            ColorMode::Dark => Visuals::dark(),
-        };
-        let scheme = self.get();
+        let light = Arc::new(clone_scheme(self.get_light_scheme()));
+        let dark = Arc::new(clone_scheme(self.get_dark_scheme()));
+        let custom_colors = Arc::new(self.custom_colors());
+        MaterialTokens::update(ctx, |tokens| tokens.custom_colors = custom_colors);
 
-        visuals.window_fill = argb_to_color32(scheme.surface);
-        visuals.faint_bg_color = argb_to_color32(scheme.surface_container);
-        visuals.extreme_bg_color = argb_to_color32(scheme.surface_variant);
-        visuals.code_bg_color = argb_to_color32(scheme.surface_dim);
-        visuals.panel_fill = argb_to_color32(scheme.surface_container_high);
-        visuals.warn_fg_color = argb_to_color32(scheme.error_container);
-        visuals.error_fg_color = argb_to_color32(scheme.error);
+        match self.color_mode {
+            ColorMode::Light | ColorMode::Dark => {
+                let dark_mode = self.get_dark_mode();
+                let (theme, scheme) = match dark_mode {
+                    true => (egui::Theme::Dark, dark),
+                    false => (egui::Theme::Light, light),
+                };
+                ctx.set_theme(theme);
+                Self::apply_style(ctx, theme, &scheme);
+                MaterialTokens::update(ctx, |tokens| {
+                    tokens.scheme = scheme;
+                    tokens.dark_mode = dark_mode;
+                    tokens.system_schemes = None;
+                });
+            }
+            ColorMode::System => {
+                ctx.set_theme(egui::ThemePreference::System);
+                Self::apply_style(ctx, egui::Theme::Light, &light);
+                Self::apply_style(ctx, egui::Theme::Dark, &dark);
+                MaterialTokens::update(ctx, |tokens| {
+                    tokens.system_schemes = Some([light, dark]);
+                });
+            }
+        }
+    }
 
-        visuals.override_text_color = Some(argb_to_color32(scheme.on_surface));
-        visuals.weak_text_color = Some(argb_to_color32(scheme.on_surface_variant));
-        visuals.hyperlink_color = argb_to_color32(scheme.primary);
+    pub(super) fn apply_style(ctx: &egui::Context, theme: egui::Theme, scheme: &Scheme) {
+        let mut visuals = Self::scheme_visuals(scheme, theme == egui::Theme::Dark);
+        // 保留已安装的圆角比例
+        MaterialTokens::load_target(ctx)
+            .shapes
+            .apply_to(&mut visuals);
+        ctx.style_mut_of(theme, |style| {
+            style.visuals = visuals;
+            style.spacing = Self::spacing();
+        });
+    }
 
-        ctx.set_visuals(visuals);
+    /// Install the bundled fonts with the default type scale and apply the theme
+    pub fn install(&self, ctx: &egui::Context) {
+        MaterialTypography::default().install(ctx);
+        self.apply(ctx);
     }
 }
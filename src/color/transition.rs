@@ -0,0 +1,234 @@
+//! Animated transitions between schemes
+
+use std::sync::Arc;
+
+use egui::{Color32, Context, Id};
+use material_colors::{color::Argb, scheme::Scheme};
+
+use super::role::{ColorRoles, CustomColorRoles};
+use super::theme::MaterialTheme;
+use crate::motion::MotionEasing;
+use crate::tokens::MaterialTokens;
+use crate::utils::{argb_to_color32, with_scheme_roles};
+
+/// A running transition from the scheme shown before [`MaterialTheme::apply_animated`]
+/// to the scheme of the applied theme
+#[derive(Clone)]
+pub struct ThemeTransition {
+    from: Arc<Scheme>,
+    /// Custom colors shown before, with the same roles in light and dark mode
+    from_custom: Arc<Vec<CustomColorRoles>>,
+    /// Blended scheme of the current pass
+    current: Arc<Scheme>,
+    /// Blended custom colors of the current pass
+    current_custom: Arc<Vec<CustomColorRoles>>,
+    start: f64,
+    duration: f32,
+}
+
+impl ThemeTransition {
+    fn id() -> Id {
+        Id::new("egui_material::transition")
+    }
+
+    /// Blended scheme and custom colors of the running transition, if any
+    pub(crate) fn current(ctx: &Context) -> Option<(Arc<Scheme>, Arc<Vec<CustomColorRoles>>)> {
+        ctx.data(|d| d.get_temp::<Self>(Self::id()))
+            .map(|transition| (transition.current, transition.current_custom))
+    }
+
+    /// Whether a theme transition is running
+    pub fn is_active(ctx: &Context) -> bool {
+        ctx.data(|d| d.get_temp::<Self>(Self::id())).is_some()
+    }
+
+    fn start(ctx: &Context, from: &MaterialTokens, duration: f32) {
+        // 检查与标记在同一次访问中完成，钩子只注册一次
+        let hook = Id::new("egui_material::transition_hook");
+        let installed = ctx.data_mut(|d| std::mem::replace(d.get_temp_mut_or_default(hook), true));
+        if !installed {
+            ctx.on_begin_pass("egui_material::transition", Arc::new(Self::step));
+        }
+
+        let from_custom: Arc<Vec<_>> = Arc::new(
+            (from.custom_colors.iter())
+                .map(|custom| {
+                    let roles = custom.get(from.dark_mode);
+                    CustomColorRoles {
+                        name: custom.name.clone(),
+                        light: roles,
+                        dark: roles,
+                    }
+                })
+                .collect(),
+        );
+        let transition = Self {
+            from: from.scheme.clone(),
+            current: from.scheme.clone(),
+            current_custom: from_custom.clone(),
+            from_custom,
+            start: ctx.input(|i| i.time),
+            duration,
+        };
+        ctx.data_mut(|d| d.insert_temp(Self::id(), transition));
+        ctx.request_repaint();
+    }
+
+    /// Blend the scheme of this pass into the egui style and the tokens
+    fn step(ctx: &Context) {
+        let Some(mut transition) = ctx.data(|d| d.get_temp::<Self>(Self::id())) else {
+            return;
+        };
+        let target = MaterialTokens::load_target(ctx);
+        let theme = ctx.theme();
+
+        let elapsed = (ctx.input(|i| i.time) - transition.start) as f32;
+        let t = elapsed / transition.duration.max(f32::EPSILON);
+        if t >= 1.0 {
+            ctx.data_mut(|d| d.remove::<Self>(Self::id()));
+            MaterialTheme::apply_style(ctx, theme, &target.scheme);
+            return;
+        }
+
+        let t = MotionEasing::Standard.apply(t);
+        let scheme = blend_scheme(&transition.from, &target.scheme, t);
+        MaterialTheme::apply_style(ctx, theme, &scheme);
+        transition.current = Arc::new(scheme);
+        // 新增的自定义颜色没有起始颜色，直接使用目标颜色
+        let custom = (target.custom_colors.iter())
+            .map(|custom| {
+                let to = custom.get(target.dark_mode);
+                let roles = match transition
+                    .from_custom
+                    .iter()
+                    .find(|c| c.name == custom.name)
+                {
+                    Some(from) => blend_roles(&from.light, &to, t),
+                    None => to,
+                };
+                CustomColorRoles {
+                    name: custom.name.clone(),
+                    light: roles,
+                    dark: roles,
+                }
+            })
+            .collect();
+        transition.current_custom = Arc::new(custom);
+        ctx.data_mut(|d| d.insert_temp(Self::id(), transition));
+        ctx.request_repaint();
+    }
+}
+
+impl MaterialTheme {
+    /// Apply the theme, animating every color role from the scheme shown now
+    ///
+    /// Colors, custom colors included, are blended in OKLab over `duration`
+    /// seconds, a transition that is still running continues from its current
+    /// colors. With reduced motion the theme is applied at once.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use egui_material::prelude::*;
+    /// # let ctx = egui::Context::default();
+    /// let mut theme = MaterialTheme::default();
+    /// theme.apply(&ctx);
+    /// // later, e.g. when a dark mode switch is toggled
+    /// theme.set_dark_mode(true);
+    /// theme.apply_animated(&ctx, 0.3);
+    /// ```
+    pub fn apply_animated(&self, ctx: &Context, duration: f32) {
+        let tokens = MaterialTokens::load(ctx);
+        self.apply(ctx);
+        if tokens.motion.reduced_motion {
+            ctx.data_mut(|d| d.remove::<ThemeTransition>(ThemeTransition::id()));
+            return;
+        }
+        ThemeTransition::start(ctx, &tokens, duration);
+    }
+}
+
+/// Blend every role of two schemes
+fn blend_scheme(from: &Scheme, to: &Scheme, t: f32) -> Scheme {
+    macro_rules! blend_roles {
+        ($($role:ident),*) => {
+            Scheme {
+                $($role: lerp_oklab(from.$role, to.$role, f64::from(t))),*
+            }
+        };
+    }
+    with_scheme_roles!(blend_roles)
+}
+
+/// Blend the roles of a custom color
+fn blend_roles(from: &ColorRoles, to: &ColorRoles, t: f32) -> ColorRoles {
+    let blend = |from: Color32, to: Color32| {
+        let [r, g, b, a] = from.to_srgba_unmultiplied();
+        let from = Argb::new(a, r, g, b);
+        let [r, g, b, a] = to.to_srgba_unmultiplied();
+        let to = Argb::new(a, r, g, b);
+        argb_to_color32(lerp_oklab(from, to, f64::from(t)))
+    };
+    ColorRoles {
+        color: blend(from.color, to.color),
+        on_color: blend(from.on_color, to.on_color),
+        color_container: blend(from.color_container, to.color_container),
+        on_color_container: blend(from.on_color_container, to.on_color_container),
+    }
+}
+
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+fn to_oklab(argb: Argb) -> [f64; 3] {
+    let r = srgb_to_linear(argb.red);
+    let g = srgb_to_linear(argb.green);
+    let b = srgb_to_linear(argb.blue);
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+fn from_oklab([lightness, a, b]: [f64; 3], alpha: u8) -> Argb {
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    Argb::new(
+        alpha,
+        linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+        linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+        linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+    )
+}
+
+/// Perceptual blend of two colors
+fn lerp_oklab(from: Argb, to: Argb, t: f64) -> Argb {
+    let from_lab = to_oklab(from);
+    let to_lab = to_oklab(to);
+    let lab = std::array::from_fn(|i| from_lab[i] + (to_lab[i] - from_lab[i]) * t);
+    let alpha = from.alpha as f64 + (to.alpha as f64 - from.alpha as f64) * t;
+    from_oklab(lab, alpha.round() as u8)
+}
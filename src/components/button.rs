@@ -1,23 +1,88 @@
 //! Material Desigin Buttons
 
-use egui::{Color32, Response, Sense, Ui, Vec2, Widget, vec2};
-use material_colors::scheme::Scheme;
+use egui::{
+    Color32, Response, Sense, Stroke, StrokeKind, Ui, Vec2, Widget, pos2, text::LayoutJob, vec2,
+};
+use std::sync::Arc;
 
-use crate::utils::argb_to_color32;
+use super::ripple::Ripple;
+use super::state_layer::*;
+use crate::color::prelude::*;
+use crate::elevation::ElevationLevel;
+use crate::icon::prelude::Icon;
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::shape::{CornerSize, MaterialShape, lerp_corner_radius};
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
+
+/// The common button variants, from the highest to the lowest emphasis
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// ui.horizontal(|ui| {
+///     ui.add(MaterialButton::new("Save"));
+///     ui.add(MaterialButton::new("Cancel").with_variant(ButtonVariant::Outlined));
+/// });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ButtonVariant {
+    /// Primary container, for the most important action
+    #[default]
+    Filled,
+    /// Secondary container, between filled and outlined
+    FilledTonal,
+    /// Low surface lifted by a shadow, to stand out from patterned backgrounds
+    Elevated,
+    /// Outline and no container, for important but not primary actions
+    Outlined,
+    /// Only the label, for the lowest priority actions
+    Text,
+}
+
+impl ButtonVariant {
+    /// Color role a variant is built from when no other is given
+    pub fn default_role(&self) -> ColorRole {
+        match self {
+            ButtonVariant::FilledTonal => ColorRole::Secondary,
+            _ => ColorRole::Primary,
+        }
+    }
+}
+
+/// Side of the label an icon is placed on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconPosition {
+    #[default]
+    Leading,
+    Trailing,
+}
 
 /// Material Button Style
 ///
 /// See: [material design document](https://m3.material.io/components/buttons/specs#08e8cb37-ac4e-49b8-82af-c77421c834ee)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaterialButtonStyle {
     pub container_color: Color32,
     pub shadow_color: Color32,
+    /// Overlaid on the container color with the opacity of the elevation level
+    pub tint_color: Color32,
+    pub elevation: ElevationLevel,
+    pub hovered_elevation: ElevationLevel,
+    pub pressed_elevation: ElevationLevel,
     pub label_color: Color32,
     pub icon_color: Color32,
-    pub font_size: f32,
-    pub line_height: f32,
+    pub label_text: TypeStyle,
     pub icon_size: f32,
-    pub rounding: f32,
-    pub pressed_rounding: f32,
+    pub shape: MaterialShape,
+    pub pressed_shape: MaterialShape,
+    pub outline_width: f32,
+    pub outline_color: Color32,
     pub padding: Vec2,
     pub between_icon_label_space: f32,
     pub disabled_container_color: Color32,
@@ -26,12 +91,14 @@ pub struct MaterialButtonStyle {
     pub disabled_label_opacity: f32,
     pub disabled_icon_color: Color32,
     pub disabled_icon_opacity: f32,
+    pub disabled_outline_color: Color32,
+    pub disabled_outline_opacity: f32,
     pub hovered_container_layer_color: Color32,
-    pub hovered_container_layer_opacity: f32,
     pub hovered_label_color: Color32,
     pub hovered_icon_color: Color32,
     pub pressed_container_layer_color: Color32,
-    pub pressed_container_layer_opacity: f32,
+    /// Opacity of the state layer when hovered, focused, pressed or dragged
+    pub state_layer_opacity: StateLayerOpacity,
     pub pressed_label_color: Color32,
     pub pressed_icon_color: Color32,
     pub button_width: Option<f32>,  // padding will not apply
@@ -40,86 +107,161 @@ pub struct MaterialButtonStyle {
 
 impl MaterialButtonStyle {
     /// default color for button
-    pub fn normal(scheme: &Scheme) -> Self {
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::colored(tokens, &ColorRole::Primary)
+    }
+
+    /// Filled button in the given color role, e.g. a custom "success" color
+    pub fn colored(tokens: &MaterialTokens, role: &ColorRole) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        let roles = tokens.roles(role);
         Self {
-            container_color: argb_to_color32(scheme.primary),
-            shadow_color: argb_to_color32(scheme.shadow),
-            label_color: argb_to_color32(scheme.on_primary),
-            icon_color: argb_to_color32(scheme.on_primary),
-            font_size: 14.0,
-            line_height: 15.0,
-            icon_size: 15.0,
-            rounding: 20.0,
-            pressed_rounding: 8.0,
+            container_color: roles.color,
+            shadow_color: scheme.shadow(),
+            tint_color: scheme.surface_tint(),
+            elevation: ElevationLevel::Level0,
+            hovered_elevation: ElevationLevel::Level1,
+            pressed_elevation: ElevationLevel::Level0,
+            label_color: roles.on_color,
+            icon_color: roles.on_color,
+            label_text: typography.label_large.clone(),
+            icon_size: 18.0,
+            shape: MaterialShape::all(CornerSize::Full),
+            pressed_shape: MaterialShape::all(CornerSize::Small),
+            outline_width: 0.0,
+            outline_color: Color32::TRANSPARENT,
             padding: vec2(15.0, 15.0),
             between_icon_label_space: 8.0,
-            disabled_container_color: argb_to_color32(scheme.on_surface),
-            disabled_container_opacity: 0.1,
-            disabled_label_color: argb_to_color32(scheme.on_surface),
-            disabled_label_opacity: 0.38,
-            disabled_icon_color: argb_to_color32(scheme.on_surface),
-            disabled_icon_opacity: 0.38,
-            hovered_container_layer_color: argb_to_color32(scheme.on_primary),
-            hovered_container_layer_opacity: 0.08,
-            hovered_label_color: argb_to_color32(scheme.on_primary),
-            hovered_icon_color: argb_to_color32(scheme.on_primary),
-            pressed_container_layer_color: argb_to_color32(scheme.on_primary),
-            pressed_container_layer_opacity: 0.1,
-            pressed_label_color: argb_to_color32(scheme.on_primary),
-            pressed_icon_color: argb_to_color32(scheme.on_primary),
+            disabled_container_color: scheme.on_surface(),
+            disabled_container_opacity: DISABLED_CONTAINER_OPACITY,
+            disabled_label_color: scheme.on_surface(),
+            disabled_label_opacity: DISABLED_CONTENT_OPACITY,
+            disabled_icon_color: scheme.on_surface(),
+            disabled_icon_opacity: DISABLED_CONTENT_OPACITY,
+            disabled_outline_color: scheme.on_surface(),
+            disabled_outline_opacity: DISABLED_CONTAINER_OPACITY,
+            hovered_container_layer_color: roles.on_color,
+            hovered_label_color: roles.on_color,
+            hovered_icon_color: roles.on_color,
+            pressed_container_layer_color: roles.on_color,
+            state_layer_opacity: StateLayerOpacity::default(),
+            pressed_label_color: roles.on_color,
+            pressed_icon_color: roles.on_color,
             button_width: None,
             button_height: None,
         }
     }
 
-    pub fn elevated(scheme: &Scheme) -> Self {
-        Self {
-            container_color: argb_to_color32(scheme.secondary_container),
-            shadow_color: argb_to_color32(scheme.shadow),
-            label_color: argb_to_color32(scheme.on_secondary_container),
-            icon_color: argb_to_color32(scheme.on_secondary_container),
-            font_size: 12.0,
-            line_height: 15.0,
-            icon_size: 15.0,
-            rounding: 20.0,
-            pressed_rounding: 8.0,
-            padding: vec2(15.0, 15.0),
-            between_icon_label_space: 8.0,
-            disabled_container_color: argb_to_color32(scheme.on_surface),
-            disabled_container_opacity: 0.1,
-            disabled_label_color: argb_to_color32(scheme.on_surface),
-            disabled_label_opacity: 0.38,
-            disabled_icon_color: argb_to_color32(scheme.on_surface),
-            disabled_icon_opacity: 0.38,
-            hovered_container_layer_color: argb_to_color32(scheme.on_secondary_container),
-            hovered_container_layer_opacity: 0.08,
-            hovered_label_color: argb_to_color32(scheme.on_secondary_container),
-            hovered_icon_color: argb_to_color32(scheme.on_secondary_container),
-            pressed_container_layer_color: argb_to_color32(scheme.on_secondary_container),
-            pressed_container_layer_opacity: 0.1,
-            pressed_label_color: argb_to_color32(scheme.on_secondary_container),
-            pressed_icon_color: argb_to_color32(scheme.on_secondary_container),
-            button_width: None,
-            button_height: None,
+    /// Button of a variant in the given color role
+    ///
+    /// Filled tonal buttons use the container roles, the other variants
+    /// without a filled container use the color role for the label.
+    pub fn variant(tokens: &MaterialTokens, variant: ButtonVariant, role: &ColorRole) -> Self {
+        let scheme = &tokens.scheme;
+        let roles = tokens.roles(role);
+        let filled = Self::colored(tokens, role);
+        // 没有填充容器的变体：标签与交互层使用角色颜色
+        let content = |color: Color32| Self {
+            label_color: color,
+            icon_color: color,
+            hovered_container_layer_color: color,
+            hovered_label_color: color,
+            hovered_icon_color: color,
+            pressed_container_layer_color: color,
+            pressed_label_color: color,
+            pressed_icon_color: color,
+            ..Self::colored(tokens, role)
+        };
+
+        match variant {
+            ButtonVariant::Filled => filled,
+            ButtonVariant::FilledTonal => Self {
+                container_color: roles.color_container,
+                ..content(roles.on_color_container)
+            },
+            ButtonVariant::Elevated => Self {
+                container_color: scheme.surface_container_low(),
+                elevation: ElevationLevel::Level1,
+                hovered_elevation: ElevationLevel::Level2,
+                pressed_elevation: ElevationLevel::Level1,
+                ..content(roles.color)
+            },
+            ButtonVariant::Outlined => Self {
+                container_color: Color32::TRANSPARENT,
+                hovered_elevation: ElevationLevel::Level0,
+                outline_width: 1.0,
+                outline_color: scheme.outline(),
+                disabled_container_opacity: 0.0,
+                ..content(roles.color)
+            },
+            ButtonVariant::Text => Self {
+                container_color: Color32::TRANSPARENT,
+                hovered_elevation: ElevationLevel::Level0,
+                padding: vec2(12.0, 15.0),
+                disabled_container_opacity: 0.0,
+                ..content(roles.color)
+            },
         }
     }
+
+    /// Filled tonal button, a secondary container for medium emphasis
+    pub fn filled_tonal(tokens: &MaterialTokens) -> Self {
+        Self::variant(tokens, ButtonVariant::FilledTonal, &ColorRole::Secondary)
+    }
+
+    /// Elevated button, a low surface lifted by a shadow
+    pub fn elevated(tokens: &MaterialTokens) -> Self {
+        Self::variant(tokens, ButtonVariant::Elevated, &ColorRole::Primary)
+    }
+
+    /// Outlined button, an outline around a transparent container
+    pub fn outlined(tokens: &MaterialTokens) -> Self {
+        Self::variant(tokens, ButtonVariant::Outlined, &ColorRole::Primary)
+    }
+
+    /// Text button, only the label in the primary color
+    pub fn text(tokens: &MaterialTokens) -> Self {
+        Self::variant(tokens, ButtonVariant::Text, &ColorRole::Primary)
+    }
 }
 
 /// Material Design Button
 pub struct MaterialButton {
     pub text: String,
-    pub icon: Option<u32>, // todo: add icon support
-    pub style: MaterialButtonStyle,
+    pub icon: Option<Icon>,
+    pub icon_position: IconPosition,
+    /// Explicit style, built from the context tokens when `None`
+    pub style: Option<MaterialButtonStyle>,
+    /// Variant the style is built from when no explicit style is given
+    pub variant: ButtonVariant,
+    /// Color role the style is built from, the role of the variant when `None`
+    pub color: Option<ColorRole>,
+    /// Scheme used instead of the one stored in the context
+    pub scheme: Option<Arc<Scheme>>,
     pub disable: bool,
+    pub padding_x: Option<f32>,
+    pub padding_y: Option<f32>,
+    pub width: Option<f32>,
+    pub height: Option<f32>,
 }
 
 impl MaterialButton {
-    pub fn new(text: String, scheme: &Scheme) -> Self {
+    pub fn new(text: impl Into<String>) -> Self {
         Self {
-            text,
+            text: text.into(),
             icon: None,
-            style: MaterialButtonStyle::normal(scheme),
+            icon_position: IconPosition::Leading,
+            style: None,
+            variant: ButtonVariant::Filled,
+            color: None,
+            scheme: None,
             disable: false,
+            padding_x: None,
+            padding_y: None,
+            width: None,
+            height: None,
         }
     }
 
@@ -127,56 +269,83 @@ impl MaterialButton {
         Self { disable, ..self }
     }
 
+    /// Show an icon before the label
+    pub fn with_icon(self, icon: impl Into<Icon>) -> Self {
+        Self {
+            icon: Some(icon.into()),
+            icon_position: IconPosition::Leading,
+            ..self
+        }
+    }
+
+    /// Show an icon after the label
+    pub fn with_trailing_icon(self, icon: impl Into<Icon>) -> Self {
+        Self {
+            icon: Some(icon.into()),
+            icon_position: IconPosition::Trailing,
+            ..self
+        }
+    }
+
     pub fn with_padding(self, padding: f32) -> Self {
         Self {
-            style: MaterialButtonStyle {
-                padding: vec2(padding, padding),
-                ..self.style
-            },
+            padding_x: Some(padding),
+            padding_y: Some(padding),
             ..self
         }
     }
 
     pub fn with_padding_x(self, padding_x: f32) -> Self {
         Self {
-            style: MaterialButtonStyle {
-                padding: vec2(padding_x, self.style.padding.y),
-                ..self.style
-            },
+            padding_x: Some(padding_x),
             ..self
         }
     }
 
     pub fn with_padding_y(self, padding_y: f32) -> Self {
         Self {
-            style: MaterialButtonStyle {
-                padding: vec2(self.style.padding.x, padding_y),
-                ..self.style
-            },
+            padding_y: Some(padding_y),
             ..self
         }
     }
 
     pub fn with_style(self, style: MaterialButtonStyle) -> Self {
-        Self { style, ..self }
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    pub fn with_variant(self, variant: ButtonVariant) -> Self {
+        Self { variant, ..self }
+    }
+
+    /// Fill the button with a color role, e.g. `ColorRole::custom("success")`
+    pub fn with_color(self, color: ColorRole) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    /// Style this button with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
     }
 
     pub fn with_width(self, width: f32) -> Self {
         Self {
-            style: MaterialButtonStyle {
-                button_width: Some(width),
-                ..self.style
-            },
+            width: Some(width),
             ..self
         }
     }
 
     pub fn with_height(self, height: f32) -> Self {
         Self {
-            style: MaterialButtonStyle {
-                button_height: Some(height),
-                ..self.style
-            },
+            height: Some(height),
             ..self
         }
     }
@@ -186,24 +355,53 @@ impl Widget for MaterialButton {
     fn ui(self, ui: &mut Ui) -> Response {
         let Self {
             text,
-            icon: _icon,
+            icon,
+            icon_position,
             style,
+            variant,
+            color,
+            scheme,
             disable,
+            padding_x,
+            padding_y,
+            width,
+            height,
         } = self;
 
-        let text_size = ui.fonts(|f| {
-            f.layout_no_wrap(
-                text.clone(),
-                egui::FontId::monospace(style.font_size),
-                style.label_color,
-            )
-            .size()
+        // 未指定样式时从上下文中的主题生成
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let mut style = style.unwrap_or_else(|| {
+            let color = color.unwrap_or_else(|| variant.default_role());
+            MaterialButtonStyle::variant(&tokens, variant, &color)
         });
+        if let Some(padding_x) = padding_x {
+            style.padding.x = padding_x;
+        }
+        if let Some(padding_y) = padding_y {
+            style.padding.y = padding_y;
+        }
+        if width.is_some() {
+            style.button_width = width;
+        }
+        if height.is_some() {
+            style.button_height = height;
+        }
+
+        // 按字体层级排版，颜色在绘制时再确定
+        let text_format = style.label_text.text_format(ui.ctx(), Color32::PLACEHOLDER);
+        let galley = ui.fonts(|f| f.layout_job(LayoutJob::single_section(text, text_format)));
+        let text_size = galley.size();
+        // 图标与标签之间留出间距
+        let icon_width = match icon {
+            Some(_) => style.icon_size + style.between_icon_label_space,
+            None => 0.0,
+        };
+        let content_size = vec2(text_size.x + icon_width, text_size.y.max(style.icon_size));
 
         // 创建一个“按钮区域”
         let button_width = style
             .button_width
-            .unwrap_or(text_size.x + 2.0 * style.padding.x);
+            .unwrap_or(content_size.x + 2.0 * style.padding.x);
         let button_height = style
             .button_height
             .unwrap_or(text_size.y + 2.0 * style.padding.y);
@@ -217,21 +415,12 @@ impl Widget for MaterialButton {
         let (rect, response) = ui.allocate_exact_size(desired_size, sense);
 
         // 获取当前交互状态
-        let is_pressed = response.is_pointer_button_down_on();
-        let is_hovering = response.hovered() && !disable;
+        let state = InteractionState::from_response(&response, disable);
+        let is_pressed = matches!(state, InteractionState::Pressed | InteractionState::Dragged);
+        let is_hovering = matches!(state, InteractionState::Hovered | InteractionState::Focused);
 
         // 计算颜色
-        let bg_color = if is_pressed {
-            style.container_color.lerp_to_gamma(
-                style.pressed_container_layer_color,
-                style.pressed_container_layer_opacity,
-            )
-        } else if is_hovering {
-            style.container_color.lerp_to_gamma(
-                style.hovered_container_layer_color,
-                style.hovered_container_layer_opacity,
-            )
-        } else if disable {
+        let bg_color = if disable {
             style
                 .disabled_container_color
                 .linear_multiply(style.disabled_container_opacity)
@@ -249,31 +438,108 @@ impl Widget for MaterialButton {
         } else {
             style.label_color
         };
+        let icon_color = if is_pressed {
+            style.pressed_icon_color
+        } else if is_hovering {
+            style.hovered_icon_color
+        } else if disable {
+            style
+                .disabled_icon_color
+                .linear_multiply(style.disabled_icon_opacity)
+        } else {
+            style.icon_color
+        };
+
+        let outline_color = if disable {
+            style
+                .disabled_outline_color
+                .linear_multiply(style.disabled_outline_opacity)
+        } else {
+            style.outline_color
+        };
 
-        // 默认圆角
-        let rounding = if is_pressed {
-            style.pressed_rounding
+        // 默认圆角，按下时平滑过渡到按下形状
+        let pressed = tokens.motion.animate_bool(
+            ui.ctx(),
+            response.id.with("shape"),
+            is_pressed,
+            MotionSpec::new(MotionDuration::Short3, MotionEasing::Standard),
+        );
+        let rounding = lerp_corner_radius(
+            style.shape.corner_radius(&tokens.shapes, rect.size()),
+            style
+                .pressed_shape
+                .corner_radius(&tokens.shapes, rect.size()),
+            pressed,
+        );
+
+        // 阴影高度随状态平滑变化
+        let elevation = if disable {
+            ElevationLevel::Level0
+        } else if is_pressed {
+            style.pressed_elevation
+        } else if is_hovering {
+            style.hovered_elevation
         } else {
-            style.rounding
+            style.elevation
         };
+        let elevation = tokens.motion.animate_value(
+            ui.ctx(),
+            response.id.with("elevation"),
+            elevation.index(),
+            MotionSpec::new(MotionDuration::Short3, MotionEasing::Standard),
+        );
 
         // 绘制按钮外观
         if ui.is_rect_visible(rect) {
             let painter = ui.painter();
 
-            // 绘制带圆角的背景
+            // 绘制阴影与带圆角的背景，背景叠加当前高度的色调
+            tokens
+                .elevation
+                .paint(painter, rect, rounding, elevation, style.shadow_color);
+            let bg_color = (tokens.elevation).tinted(elevation, bg_color, style.tint_color);
             painter.rect_filled(rect, rounding, bg_color);
+            if style.outline_width > 0.0 {
+                painter.rect_stroke(
+                    rect,
+                    rounding,
+                    Stroke::new(style.outline_width, outline_color),
+                    StrokeKind::Inside,
+                );
+            }
+
+            // 绘制 state layer（悬停/聚焦反馈），按下由水波纹表现
+            let layer_state = match state {
+                InteractionState::Pressed => InteractionState::Hovered,
+                state => state,
+            };
+            StateLayer::new(&response, style.hovered_container_layer_color)
+                .with_state(layer_state)
+                .with_opacity(style.state_layer_opacity)
+                .paint(painter, rect, rounding);
+
+            // 绘制水波纹（按下反馈）
+            Ripple::new(&response, style.pressed_container_layer_color)
+                .with_opacity(style.state_layer_opacity.pressed)
+                .paint(painter, rect, rounding);
 
-            // 绘制文字（居中）
-            let text_color = font_color;
+            // 绘制图标与文字（整体居中）
+            let content_left = rect.center().x - content_size.x / 2.0;
+            let (icon_x, text_x) = match icon_position {
+                IconPosition::Leading => (content_left, content_left + icon_width),
+                IconPosition::Trailing => (
+                    content_left + text_size.x + style.between_icon_label_space,
+                    content_left,
+                ),
+            };
+            if let Some(icon) = icon {
+                let icon_center = pos2(icon_x + style.icon_size / 2.0, rect.center().y);
+                icon.paint(painter, icon_center, style.icon_size, icon_color);
+            }
 
-            painter.text(
-                rect.center(),
-                egui::Align2::CENTER_CENTER,
-                text,
-                egui::FontId::monospace(style.font_size),
-                text_color,
-            );
+            let text_pos = pos2(text_x, rect.center().y - text_size.y / 2.0);
+            painter.galley(text_pos, galley, font_color);
         }
 
         response
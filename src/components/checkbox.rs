@@ -1,16 +1,28 @@
 //! Material Design checkbox
 
-use egui::{Color32, CornerRadius, Rect, Sense, Stroke, Widget, pos2, text::LayoutJob, vec2};
-use material_colors::scheme::Scheme;
+use egui::{
+    Color32, Rect, Sense, Stroke, Vec2, Widget, WidgetInfo, WidgetType, text::LayoutJob, vec2,
+};
+use std::sync::Arc;
 
-use crate::utils::argb_to_color32;
+use super::ripple::Ripple;
+use super::state_layer::*;
+use crate::color::prelude::*;
+use crate::icon::prelude::{Icon, VectorIcon};
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::shape::{CornerSize, MaterialShape};
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
 
 /// Style for Material Design checkbox
 ///
 /// See: [material design document](https://m3.material.io/components/checkbox/specs#fd29f662-6e61-4c1f-9b97-1145c3b33075)
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaterialCheckboxStyle {
     pub container_size: f32,
-    pub container_rounding: f32,
+    pub container_shape: MaterialShape,
     pub unselected_outline_color: Color32,
     pub unselected_outline_width: f32,
     pub selected_container_color: Color32,
@@ -21,7 +33,7 @@ pub struct MaterialCheckboxStyle {
     pub icon_selected_color: Color32,
     pub icon_selected_error_color: Color32,
     pub layer_size: f32,
-    pub layer_rounding: f32,
+    pub layer_shape: MaterialShape,
     pub disabled_unselected_outline_color: Color32,
     pub disabled_unselected_outline_width: f32,
     pub disabled_container_opacity: f32,
@@ -36,11 +48,8 @@ pub struct MaterialCheckboxStyle {
     pub hovered_unselected_error_outline_color: Color32,
     pub hovered_selected_error_container_color: Color32,
     pub hovered_selected_layer_color: Color32,
-    pub hovered_selected_layer_opacity: f32,
     pub hovered_unselected_layer_color: Color32,
-    pub hovered_unselected_layer_opacity: f32,
     pub hovered_error_layer_color: Color32,
-    pub hovered_error_layer_opacity: f32,
     pub hovered_selected_icon_color: Color32,
     pub hovered_error_icon_color: Color32,
     pub pressed_unselected_outline_color: Color32,
@@ -50,236 +59,563 @@ pub struct MaterialCheckboxStyle {
     pub pressed_unselected_error_outline_color: Color32,
     pub pressed_selected_error_container_color: Color32,
     pub pressed_unselected_layer_color: Color32,
-    pub pressed_unselected_layer_opacity: f32,
     pub pressed_selected_layer_color: Color32,
-    pub pressed_selected_layer_opacity: f32,
     pub pressed_error_layer_color: Color32,
-    pub pressed_error_layer_opacity: f32,
+    /// Opacity of the state layer when hovered, focused or pressed
+    pub state_layer_opacity: StateLayerOpacity,
     pub pressed_selected_icon_color: Color32,
     pub pressed_error_icon_color: Color32,
+    /// Size of the clickable area around the box
+    pub target_size: f32,
+    /// Space between the touch target and the label
+    pub between_target_label_space: f32,
+    pub label_text: TypeStyle,
+    pub label_color: Color32,
+    pub supporting_text: TypeStyle,
+    pub supporting_text_color: Color32,
+    pub error_supporting_text_color: Color32,
+    pub disabled_label_color: Color32,
+    pub disabled_label_opacity: f32,
 }
 
 impl MaterialCheckboxStyle {
-    fn normal(scheme: &Scheme) -> Self {
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::colored(tokens, &ColorRole::Primary)
+    }
+
+    /// Checkbox selected in the given color role, e.g. a custom "success" color
+    pub fn colored(tokens: &MaterialTokens, role: &ColorRole) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        let roles = tokens.roles(role);
         Self {
             container_size: 16.0,
-            container_rounding: 2.0,
-            unselected_outline_color: argb_to_color32(scheme.on_surface_variant),
+            container_shape: MaterialShape::all(CornerSize::Fixed(2.0)),
+            unselected_outline_color: scheme.on_surface_variant(),
             unselected_outline_width: 2.0,
-            selected_container_color: argb_to_color32(scheme.primary),
+            selected_container_color: roles.color,
             selected_outline_width: 0.0,
-            unselected_error_outline_color: argb_to_color32(scheme.error),
-            selected_error_container_color: argb_to_color32(scheme.error),
-            icon_size: 12.0,
-            icon_selected_color: argb_to_color32(scheme.on_primary),
-            icon_selected_error_color: argb_to_color32(scheme.on_error),
+            unselected_error_outline_color: scheme.error(),
+            selected_error_container_color: scheme.error(),
+            icon_size: 18.0,
+            icon_selected_color: roles.on_color,
+            icon_selected_error_color: scheme.on_error(),
             layer_size: 18.0,
-            layer_rounding: 2.0,
-            disabled_unselected_outline_color: argb_to_color32(scheme.on_surface),
+            layer_shape: MaterialShape::all(CornerSize::Fixed(2.0)),
+            disabled_unselected_outline_color: scheme.on_surface(),
             disabled_unselected_outline_width: 2.0,
-            disabled_container_opacity: 0.38,
-            disabled_selected_container_color: argb_to_color32(scheme.on_surface),
-            disabled_selected_container_opacity: 0.38,
+            disabled_container_opacity: DISABLED_CONTENT_OPACITY,
+            disabled_selected_container_color: scheme.on_surface(),
+            disabled_selected_container_opacity: DISABLED_CONTENT_OPACITY,
             disabled_selected_outline_width: 0.0,
-            disabled_selected_icon_color: argb_to_color32(scheme.on_surface),
-            hovered_unselected_outline_color: argb_to_color32(scheme.on_surface),
+            disabled_selected_icon_color: scheme.on_surface(),
+            hovered_unselected_outline_color: scheme.on_surface(),
             hovered_unselected_outline_width: 2.0,
-            hovered_selected_container_color: argb_to_color32(scheme.primary),
+            hovered_selected_container_color: roles.color,
             hovered_selected_outline_width: 0.0,
-            hovered_unselected_error_outline_color: argb_to_color32(scheme.error),
-            hovered_selected_error_container_color: argb_to_color32(scheme.error),
-            hovered_selected_layer_color: argb_to_color32(scheme.primary),
-            hovered_selected_layer_opacity: 0.08,
-            hovered_unselected_layer_color: argb_to_color32(scheme.on_surface),
-            hovered_unselected_layer_opacity: 0.08,
-            hovered_error_layer_color: argb_to_color32(scheme.error),
-            hovered_error_layer_opacity: 0.08,
-            hovered_selected_icon_color: argb_to_color32(scheme.on_primary),
-            hovered_error_icon_color: argb_to_color32(scheme.on_error),
-            pressed_unselected_outline_color: argb_to_color32(scheme.on_surface),
+            hovered_unselected_error_outline_color: scheme.error(),
+            hovered_selected_error_container_color: scheme.error(),
+            hovered_selected_layer_color: roles.color,
+            hovered_unselected_layer_color: scheme.on_surface(),
+            hovered_error_layer_color: scheme.error(),
+            hovered_selected_icon_color: roles.on_color,
+            hovered_error_icon_color: scheme.on_error(),
+            pressed_unselected_outline_color: scheme.on_surface(),
             pressed_unselected_outline_width: 2.0,
-            pressed_selected_container_color: argb_to_color32(scheme.primary),
+            pressed_selected_container_color: roles.color,
             pressed_selected_outline_width: 0.0,
-            pressed_unselected_error_outline_color: argb_to_color32(scheme.error),
-            pressed_selected_error_container_color: argb_to_color32(scheme.error),
-            pressed_unselected_layer_color: argb_to_color32(scheme.primary),
-            pressed_unselected_layer_opacity: 0.1,
-            pressed_selected_layer_color: argb_to_color32(scheme.on_surface),
-            pressed_selected_layer_opacity: 0.1,
-            pressed_error_layer_color: argb_to_color32(scheme.error),
-            pressed_error_layer_opacity: 0.1,
-            pressed_selected_icon_color: argb_to_color32(scheme.on_primary),
-            pressed_error_icon_color: argb_to_color32(scheme.on_error),
+            pressed_unselected_error_outline_color: scheme.error(),
+            pressed_selected_error_container_color: scheme.error(),
+            pressed_unselected_layer_color: roles.color,
+            pressed_selected_layer_color: scheme.on_surface(),
+            pressed_error_layer_color: scheme.error(),
+            state_layer_opacity: StateLayerOpacity::default(),
+            pressed_selected_icon_color: roles.on_color,
+            pressed_error_icon_color: scheme.on_error(),
+            target_size: 48.0,
+            between_target_label_space: 4.0,
+            label_text: typography.body_large.clone(),
+            label_color: scheme.on_surface(),
+            supporting_text: typography.body_medium.clone(),
+            supporting_text_color: scheme.on_surface_variant(),
+            error_supporting_text_color: scheme.error(),
+            disabled_label_color: scheme.on_surface(),
+            disabled_label_opacity: DISABLED_CONTENT_OPACITY,
+        }
+    }
+
+    pub fn with_container_size(self, container_size: f32) -> Self {
+        Self {
+            container_size,
+            ..self
+        }
+    }
+
+    pub fn with_container_shape(self, container_shape: MaterialShape) -> Self {
+        Self {
+            container_shape,
+            ..self
+        }
+    }
+
+    pub fn with_icon_size(self, icon_size: f32) -> Self {
+        Self { icon_size, ..self }
+    }
+
+    /// Size of the clickable area around the box, 48 by default
+    pub fn with_target_size(self, target_size: f32) -> Self {
+        Self {
+            target_size,
+            ..self
         }
     }
+
+    pub fn with_label_text(self, label_text: TypeStyle) -> Self {
+        Self { label_text, ..self }
+    }
+
+    pub fn with_state_layer_opacity(self, state_layer_opacity: StateLayerOpacity) -> Self {
+        Self {
+            state_layer_opacity,
+            ..self
+        }
+    }
+    /// Container fill, outline, mark and state layer colors of a checkbox
+    fn colors(
+        &self,
+        selected: bool,
+        disable: bool,
+        error: bool,
+        state: InteractionState,
+    ) -> (Color32, Stroke, Color32, Color32) {
+        let is_pressed = matches!(state, InteractionState::Pressed | InteractionState::Dragged);
+        let is_hovering = matches!(state, InteractionState::Hovered | InteractionState::Focused);
+
+        // 禁用状态
+        if disable {
+            return match selected {
+                true => {
+                    let fill = self
+                        .disabled_selected_container_color
+                        .linear_multiply(self.disabled_selected_container_opacity);
+                    let stroke = Stroke::new(self.disabled_selected_outline_width, fill);
+                    (
+                        fill,
+                        stroke,
+                        self.disabled_selected_icon_color,
+                        Color32::TRANSPARENT,
+                    )
+                }
+                false => {
+                    let stroke = Stroke::new(
+                        self.disabled_unselected_outline_width,
+                        self.disabled_unselected_outline_color,
+                    );
+                    (
+                        Color32::TRANSPARENT,
+                        stroke,
+                        Color32::TRANSPARENT,
+                        Color32::TRANSPARENT,
+                    )
+                }
+            };
+        }
+
+        // 交互层颜色（hover / pressed），离开时淡出也使用该颜色
+        let layer = match (is_pressed, error, selected) {
+            (true, true, _) => self.pressed_error_layer_color,
+            (true, false, true) => self.pressed_selected_layer_color,
+            (true, false, false) => self.pressed_unselected_layer_color,
+            (false, true, _) => self.hovered_error_layer_color,
+            (false, false, true) => self.hovered_selected_layer_color,
+            (false, false, false) => self.hovered_unselected_layer_color,
+        };
+
+        // 出错时各交互状态都保持错误颜色
+        if selected {
+            let (fill, width, icon) = match (is_pressed, is_hovering, error) {
+                (true, _, true) => (
+                    self.pressed_selected_error_container_color,
+                    self.pressed_selected_outline_width,
+                    self.pressed_error_icon_color,
+                ),
+                (true, _, false) => (
+                    self.pressed_selected_container_color,
+                    self.pressed_selected_outline_width,
+                    self.pressed_selected_icon_color,
+                ),
+                (false, true, true) => (
+                    self.hovered_selected_error_container_color,
+                    self.hovered_selected_outline_width,
+                    self.hovered_error_icon_color,
+                ),
+                (false, true, false) => (
+                    self.hovered_selected_container_color,
+                    self.hovered_selected_outline_width,
+                    self.hovered_selected_icon_color,
+                ),
+                (false, false, true) => (
+                    self.selected_error_container_color,
+                    self.selected_outline_width,
+                    self.icon_selected_error_color,
+                ),
+                (false, false, false) => (
+                    self.selected_container_color,
+                    self.selected_outline_width,
+                    self.icon_selected_color,
+                ),
+            };
+            (fill, Stroke::new(width, fill), icon, layer)
+        } else {
+            let (color, width) = match (is_pressed, is_hovering, error) {
+                (true, _, true) => (
+                    self.pressed_unselected_error_outline_color,
+                    self.pressed_unselected_outline_width,
+                ),
+                (true, _, false) => (
+                    self.pressed_unselected_outline_color,
+                    self.pressed_unselected_outline_width,
+                ),
+                (false, true, true) => (
+                    self.hovered_unselected_error_outline_color,
+                    self.hovered_unselected_outline_width,
+                ),
+                (false, true, false) => (
+                    self.hovered_unselected_outline_color,
+                    self.hovered_unselected_outline_width,
+                ),
+                (false, false, true) => (
+                    self.unselected_error_outline_color,
+                    self.unselected_outline_width,
+                ),
+                (false, false, false) => {
+                    (self.unselected_outline_color, self.unselected_outline_width)
+                }
+            };
+            let stroke = Stroke::new(width, color);
+            (Color32::TRANSPARENT, stroke, Color32::TRANSPARENT, layer)
+        }
+    }
+}
+
+/// State of a tri-state checkbox
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CheckboxState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Partly checked, e.g. a "select all" checkbox with some items selected
+    Indeterminate,
+}
+
+impl CheckboxState {
+    /// Checked and indeterminate checkboxes both use the selected colors
+    pub fn is_selected(&self) -> bool {
+        !matches!(self, CheckboxState::Unchecked)
+    }
+
+    /// The state after a click, an indeterminate checkbox becomes checked
+    pub fn toggled(&self) -> Self {
+        match self {
+            CheckboxState::Checked => CheckboxState::Unchecked,
+            CheckboxState::Unchecked | CheckboxState::Indeterminate => CheckboxState::Checked,
+        }
+    }
+}
+
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        match checked {
+            true => CheckboxState::Checked,
+            false => CheckboxState::Unchecked,
+        }
+    }
+}
+
+/// Value a checkbox is bound to
+enum CheckboxValue<'a> {
+    Bool(&'a mut bool),
+    State(&'a mut CheckboxState),
 }
 
 /// Material Design Checkbox
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let mut items = [true, false];
+/// let mut all = match items {
+///     [true, true] => CheckboxState::Checked,
+///     [false, false] => CheckboxState::Unchecked,
+///     _ => CheckboxState::Indeterminate,
+/// };
+/// if ui.add(MaterialCheckbox::tri_state(&mut all).with_label("Select all")).clicked() {
+///     items = [all == CheckboxState::Checked; 2];
+/// }
+/// for (item, label) in items.iter_mut().zip(["Email", "SMS"]) {
+///     ui.add(MaterialCheckbox::new(item).with_label(label));
+/// }
+///
+/// let mut accepted = false;
+/// let error = !accepted;
+/// ui.add(
+///     MaterialCheckbox::new(&mut accepted)
+///         .with_label("Accept the terms")
+///         .with_supporting_text("Required")
+///         .with_error(error),
+/// );
+/// # });
+/// ```
 pub struct MaterialCheckbox<'a> {
-    checked: &'a mut bool,
-    icon: String,
+    checked: CheckboxValue<'a>,
+    icon: Icon,
+    label: Option<String>,
+    supporting_text: Option<String>,
     disable: bool,
     error: bool,
-    style: MaterialCheckboxStyle,
+    color: Option<ColorRole>,
+    style: Option<MaterialCheckboxStyle>,
+    scheme: Option<Arc<Scheme>>,
 }
 
 impl<'a> MaterialCheckbox<'a> {
-    pub fn new(checked: &'a mut bool, scheme: &Scheme) -> Self {
+    pub fn new(checked: &'a mut bool) -> Self {
+        Self::bound(CheckboxValue::Bool(checked))
+    }
+
+    /// Checkbox that can also show the indeterminate state, clicking it
+    /// moves an indeterminate checkbox to checked
+    pub fn tri_state(state: &'a mut CheckboxState) -> Self {
+        Self::bound(CheckboxValue::State(state))
+    }
+
+    fn bound(checked: CheckboxValue<'a>) -> Self {
         Self {
             checked,
-            icon: String::from("✔"),
+            icon: Icon::Vector(VectorIcon::Check),
+            label: None,
+            supporting_text: None,
             disable: false,
             error: false,
-            style: MaterialCheckboxStyle::normal(scheme),
+            color: None,
+            style: None,
+            scheme: None,
         }
     }
 
     pub fn with_disable(self, disable: bool) -> Self {
         Self { disable, ..self }
     }
+
+    /// Show the checkbox in the error colors, e.g. when a required option is unchecked
+    pub fn with_error(self, error: bool) -> Self {
+        Self { error, ..self }
+    }
+
+    /// Text next to the box, clicking it toggles the checkbox too
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Secondary text below the label, shown in the error color on error
+    pub fn with_supporting_text(self, text: impl Into<String>) -> Self {
+        Self {
+            supporting_text: Some(text.into()),
+            ..self
+        }
+    }
+
+    /// Mark of the checked checkbox, a drawn check by default
+    ///
+    /// Indeterminate checkboxes always show a dash, vector icons morph into it.
+    pub fn with_icon(self, icon: impl Into<Icon>) -> Self {
+        Self {
+            icon: icon.into(),
+            ..self
+        }
+    }
+
+    /// Select the checkbox in a color role, e.g. `ColorRole::custom("success")`
+    pub fn with_color(self, color: ColorRole) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    /// Use a custom style, e.g. `MaterialCheckboxStyle::normal` with overrides
+    pub fn with_style(self, style: MaterialCheckboxStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this checkbox with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
 }
 
 impl Widget for MaterialCheckbox<'_> {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
         let Self {
-            checked,
+            mut checked,
             icon,
+            label,
+            supporting_text,
             disable,
             error,
+            color,
             style,
+            scheme,
         } = self;
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| match color {
+            Some(color) => MaterialCheckboxStyle::colored(&tokens, &color),
+            None => MaterialCheckboxStyle::normal(&tokens),
+        });
 
         let sense = if disable {
             Sense::hover()
         } else {
             Sense::click()
         };
-        let layer_size = vec2(style.layer_size, style.layer_size);
-        let (layer_rect, response) = ui.allocate_exact_size(layer_size, sense);
 
-        let is_pressed = response.is_pointer_button_down_on();
-        let is_hovering = response.hovered();
+        // 排版标签与辅助文字
+        let layout = |text: Option<String>, type_style: &TypeStyle| {
+            text.map(|text| {
+                let text_format = type_style.text_format(ui.ctx(), Color32::PLACEHOLDER);
+                ui.fonts(|f| f.layout_job(LayoutJob::single_section(text, text_format)))
+            })
+        };
+        let accessible_label = label.clone().unwrap_or_default();
+        let label_galley = layout(label, &style.label_text);
+        let supporting_galley = layout(supporting_text, &style.supporting_text);
+        let text_size = [&label_galley, &supporting_galley]
+            .into_iter()
+            .flatten()
+            .fold(Vec2::ZERO, |size, galley| {
+                vec2(size.x.max(galley.size().x), size.y + galley.size().y)
+            });
+
+        // 整行都可点击，复选框周围保留触控区域
+        let target_size = style.target_size.max(style.layer_size);
+        let row_size = match text_size.x > 0.0 {
+            true => vec2(
+                target_size + style.between_target_label_space + text_size.x,
+                target_size.max(text_size.y),
+            ),
+            false => Vec2::splat(target_size),
+        };
+        let (row_rect, mut response) = ui.allocate_exact_size(row_size, sense);
+        let target_rect = Rect::from_min_size(
+            row_rect.left_center() - vec2(0.0, target_size / 2.0),
+            Vec2::splat(target_size),
+        );
+        let layer_rect = Rect::from_center_size(
+            target_rect.center(),
+            vec2(style.layer_size, style.layer_size),
+        );
 
-        // 状态切换
+        let state = InteractionState::from_response(&response, disable);
+
+        // 状态切换，不确定状态点击后变为选中
+        let mut checkbox_state = match &checked {
+            CheckboxValue::Bool(checked) => CheckboxState::from(**checked),
+            CheckboxValue::State(state) => **state,
+        };
         if !disable && response.clicked() {
-            *checked = !*checked;
+            checkbox_state = checkbox_state.toggled();
+            match &mut checked {
+                CheckboxValue::Bool(checked) => **checked = checkbox_state.is_selected(),
+                CheckboxValue::State(state) => **state = checkbox_state,
+            }
+            response.mark_changed();
         }
+        let selected = checkbox_state.is_selected();
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Checkbox,
+                !disable,
+                selected,
+                accessible_label.as_str(),
+            )
+        });
+
+        // 标记逐渐绘制出来，取消选中时反向收回
+        let mark_progress = tokens.motion.animate_bool(
+            ui.ctx(),
+            response.id.with("check"),
+            selected,
+            MotionSpec::new(MotionDuration::Short4, MotionEasing::EmphasizedDecelerate),
+        );
+        // 对勾与横线之间变形，取消选中时保持最后显示的标记
+        let dash_id = response.id.with("indeterminate");
+        let indeterminate = match checkbox_state {
+            CheckboxState::Unchecked => ui.data(|d| d.get_temp(dash_id)).unwrap_or(false),
+            state => {
+                let indeterminate = state == CheckboxState::Indeterminate;
+                ui.data_mut(|d| d.insert_temp(dash_id, indeterminate));
+                indeterminate
+            }
+        };
+        let dash_progress = tokens.motion.animate_bool(
+            ui.ctx(),
+            dash_id,
+            indeterminate,
+            MotionSpec::new(MotionDuration::Short4, MotionEasing::EmphasizedDecelerate),
+        );
 
         let center = layer_rect.center();
         // 容器矩形（实际 checkbox 框）
         let container_rect =
             Rect::from_center_size(center, vec2(style.container_size, style.container_size));
-        let rounding = CornerRadius::from(style.container_rounding);
-        let layer_rounding = CornerRadius::from(style.layer_rounding);
+        let rounding = style
+            .container_shape
+            .corner_radius(&tokens.shapes, container_rect.size());
+        let layer_rounding = style
+            .layer_shape
+            .corner_radius(&tokens.shapes, layer_rect.size());
         // 计算颜色
-        let (container_fill, outline_stroke, icon_color, layer_color, layer_opacity) = {
-            let mut fill = Color32::TRANSPARENT;
-            let mut stroke;
-            let mut icon = Color32::TRANSPARENT;
-            let mut layer = Color32::TRANSPARENT;
-            let mut opacity = 0.0;
-
-            if disable {
-                // 禁用状态
-                if *checked {
-                    fill = style
-                        .disabled_selected_container_color
-                        .linear_multiply(style.disabled_selected_container_opacity);
-                    stroke = Stroke::new(style.disabled_selected_outline_width, fill);
-                    icon = style.disabled_selected_icon_color;
-                } else {
-                    stroke = Stroke::new(
-                        style.disabled_unselected_outline_width,
-                        style.disabled_unselected_outline_color,
-                    );
-                }
-            } else {
-                // 正常启用状态
-                if *checked {
-                    fill = if error {
-                        style.selected_error_container_color
-                    } else {
-                        style.selected_container_color
-                    };
-                    stroke = Stroke::new(style.selected_outline_width, fill);
-                    icon = if error {
-                        style.icon_selected_error_color
-                    } else {
-                        style.icon_selected_color
-                    };
-                } else {
-                    stroke = Stroke::new(
-                        style.unselected_outline_width,
-                        if error {
-                            style.unselected_error_outline_color
-                        } else {
-                            style.unselected_outline_color
-                        },
-                    );
-                }
-
-                // 交互层颜色（hover / pressed）
-                if is_pressed {
-                    layer = if *checked {
-                        style.pressed_selected_layer_color
-                    } else {
-                        style.pressed_unselected_layer_color
-                    };
-                    opacity = style
-                        .pressed_selected_layer_opacity
-                        .max(style.pressed_unselected_layer_opacity);
-                    if !*checked {
-                        stroke.color = style.pressed_unselected_outline_color;
-                        stroke.width = style.pressed_unselected_outline_width;
-                    } else {
-                        stroke.width = style.pressed_selected_outline_width;
-                    }
-                    icon = if *checked {
-                        style.pressed_selected_icon_color
-                    } else if error {
-                        style.pressed_error_icon_color
-                    } else {
-                        icon
-                    };
-                } else if is_hovering {
-                    layer = if *checked {
-                        style.hovered_selected_layer_color
-                    } else {
-                        style.hovered_unselected_layer_color
-                    };
-                    opacity = if *checked {
-                        style.hovered_selected_layer_opacity
-                    } else {
-                        style.hovered_unselected_layer_opacity
-                    };
-                    if !*checked {
-                        stroke.color = style.hovered_unselected_outline_color;
-                        stroke.width = style.hovered_unselected_outline_width;
-                    } else {
-                        stroke.width = style.hovered_selected_outline_width;
-                    }
-                    icon = if *checked {
-                        style.hovered_selected_icon_color
-                    } else if error {
-                        style.hovered_error_icon_color
-                    } else {
-                        icon
-                    };
-                }
+        let colors = |selected| style.colors(selected, disable, error, state);
+        let (container_fill, outline_stroke, icon_color, layer_color) = colors(selected);
+        // 取消选中时，填充与标记随动画淡出
+        let (container_fill, icon_color) = match selected {
+            true => (container_fill, icon_color),
+            false => {
+                let (fill, _, icon, _) = colors(true);
+                (fill.gamma_multiply(mark_progress), icon)
             }
-
-            (fill, stroke, icon, layer, opacity)
         };
 
         // 绘制
-        if ui.is_rect_visible(layer_rect) {
+        if ui.is_rect_visible(row_rect) {
             let painter = ui.painter();
 
-            // 绘制 state layer（悬停/按下反馈）
-            if layer_opacity > 0.0 && layer_color != Color32::TRANSPARENT {
-                let layer_color = layer_color.linear_multiply(layer_opacity);
-                painter.rect_filled(layer_rect, layer_rounding, layer_color);
-            }
+            // 绘制 state layer（悬停/聚焦反馈），按下由水波纹表现
+            let layer_state = match state {
+                InteractionState::Pressed => InteractionState::Hovered,
+                state => state,
+            };
+            StateLayer::new(&response, layer_color)
+                .with_state(layer_state)
+                .with_opacity(style.state_layer_opacity)
+                .paint(painter, layer_rect, layer_rounding);
+
+            // 绘制水波纹（按下反馈）
+            Ripple::new(&response, layer_color)
+                .with_opacity(style.state_layer_opacity.pressed)
+                .paint(painter, layer_rect, layer_rounding);
 
             // 绘制容器（边框或填充）
             painter.rect(
@@ -290,26 +626,89 @@ impl Widget for MaterialCheckbox<'_> {
                 egui::StrokeKind::Inside,
             );
 
-            // 绘制选中图标
-            if *checked {
-                let font_id = egui::FontId::monospace(style.icon_size); // 使用等宽字体避免偏移
-                let layout_job = LayoutJob::simple(icon, font_id, icon_color, 0.0);
-                // let layout_job = egui::TextFormat::simple(font_id, icon_color)(
-                //     ui.ctx(),
-                //     icon,
-                //     Vec2::splat(f32::INFINITY),
-                // );
-                let galley = ui.fonts(|f| f.layout_job(layout_job));
-
-                let icon_pos = pos2(
-                    container_rect.center().x - galley.size().x / 2.0,
-                    container_rect.center().y - galley.size().y / 2.0,
-                );
-
-                painter.galley(icon_pos, galley, icon_color);
+            // 绘制选中标记，矢量图标可与横线互相变形
+            let dash = VectorIcon::Remove;
+            match icon {
+                Icon::Vector(check) => check.paint_morph(
+                    dash,
+                    painter,
+                    Rect::from_center_size(container_rect.center(), Vec2::splat(style.icon_size)),
+                    icon_color,
+                    dash_progress,
+                    mark_progress,
+                ),
+                icon => {
+                    let icon = match dash_progress < 0.5 {
+                        true => icon,
+                        false => Icon::Vector(dash),
+                    };
+                    icon.paint_progress(
+                        painter,
+                        container_rect.center(),
+                        style.icon_size,
+                        icon_color,
+                        mark_progress,
+                    );
+                }
+            }
+
+            // 绘制标签与辅助文字，整体与复选框垂直居中
+            let label_color = match disable {
+                true => style
+                    .disabled_label_color
+                    .linear_multiply(style.disabled_label_opacity),
+                false => style.label_color,
+            };
+            let supporting_color = match (disable, error) {
+                (true, _) => label_color,
+                (false, true) => style.error_supporting_text_color,
+                (false, false) => style.supporting_text_color,
+            };
+            let mut text_pos = egui::pos2(
+                target_rect.right() + style.between_target_label_space,
+                row_rect.center().y - text_size.y / 2.0,
+            );
+            for (galley, color) in [
+                (label_galley, label_color),
+                (supporting_galley, supporting_color),
+            ] {
+                if let Some(galley) = galley {
+                    let height = galley.size().y;
+                    painter.galley(text_pos, galley, color);
+                    text_pos.y += height;
+                }
             }
         }
 
         response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_outline_kept_while_interacting() {
+        let tokens = MaterialTokens::default();
+        let style = MaterialCheckboxStyle::normal(&tokens);
+        for state in [
+            InteractionState::Hovered,
+            InteractionState::Focused,
+            InteractionState::Pressed,
+        ] {
+            let (_, stroke, _, layer) = style.colors(false, false, true, state);
+            assert_eq!(stroke.color, tokens.scheme.error());
+            assert_eq!(layer, tokens.scheme.error());
+        }
+    }
+
+    #[test]
+    fn error_container_kept_while_interacting() {
+        let tokens = MaterialTokens::default();
+        let style = MaterialCheckboxStyle::normal(&tokens);
+        let (fill, _, icon, _) = style.colors(true, false, true, InteractionState::Pressed);
+        assert_eq!(fill, tokens.scheme.error());
+        assert_eq!(icon, tokens.scheme.on_error());
+    }
+}
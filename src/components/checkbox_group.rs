@@ -0,0 +1,367 @@
+//! Material Design checkbox groups
+
+use egui::{Color32, Label, Response, Ui, Widget, text::LayoutJob};
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use super::checkbox::*;
+use super::state_layer::DISABLED_CONTENT_OPACITY;
+use crate::color::prelude::*;
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::{arrow_key_focus, clone_scheme};
+
+/// Selected items of a checkbox group
+pub enum CheckboxSelection<'a, T> {
+    /// The selected items
+    Set(&'a mut HashSet<T>),
+    /// Whether each item is selected, in the order of the items
+    Flags(&'a mut [bool]),
+}
+
+impl<T: Eq + Hash + Clone> CheckboxSelection<'_, T> {
+    fn is_selected(&self, index: usize, item: &T) -> bool {
+        match self {
+            CheckboxSelection::Set(set) => set.contains(item),
+            CheckboxSelection::Flags(flags) => flags.get(index).copied().unwrap_or(false),
+        }
+    }
+
+    fn set_selected(&mut self, index: usize, item: &T, selected: bool) {
+        match self {
+            CheckboxSelection::Set(set) => {
+                if selected {
+                    set.insert(item.clone());
+                } else {
+                    set.remove(item);
+                }
+            }
+            CheckboxSelection::Flags(flags) => {
+                if let Some(flag) = flags.get_mut(index) {
+                    *flag = selected;
+                }
+            }
+        }
+    }
+}
+
+/// Style for Material Design checkbox group
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialCheckboxGroupStyle {
+    /// Style of every checkbox row
+    pub checkbox: MaterialCheckboxStyle,
+    pub label_text: TypeStyle,
+    pub label_color: Color32,
+    pub disabled_label_color: Color32,
+    pub disabled_label_opacity: f32,
+    /// Indent of the item rows below a "select all" checkbox
+    pub nested_indent: f32,
+}
+
+impl MaterialCheckboxGroupStyle {
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::with_checkbox(tokens, MaterialCheckboxStyle::normal(tokens))
+    }
+
+    /// Group of checkboxes with the given style
+    pub fn with_checkbox(tokens: &MaterialTokens, checkbox: MaterialCheckboxStyle) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        Self {
+            checkbox,
+            label_text: typography.title_small.clone(),
+            label_color: scheme.on_surface_variant(),
+            disabled_label_color: scheme.on_surface(),
+            disabled_label_opacity: DISABLED_CONTENT_OPACITY,
+            nested_indent: 32.0,
+        }
+    }
+}
+
+/// A labeled list of checkboxes bound to a collection
+///
+/// Up and down arrows move the focus between the rows, a selection outside
+/// the min / max limits shows the group in the error colors.
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # use std::collections::HashSet;
+/// # egui::__run_test_ui(|ui| {
+/// let toppings = ["Cheese", "Mushrooms", "Olives"];
+/// let mut selected = HashSet::from(["Cheese"]);
+/// ui.add(
+///     MaterialCheckboxGroup::new(&toppings, &mut selected)
+///         .with_label("Toppings")
+///         .with_select_all("All toppings")
+///         .with_max_selected(2)
+///         .with_supporting_text("Pick up to two"),
+/// );
+///
+/// let mut flags = vec![false; 2];
+/// ui.add(MaterialCheckboxGroup::flags(&["Email", "SMS"], &mut flags).with_min_selected(1));
+/// # });
+/// ```
+pub struct MaterialCheckboxGroup<'a, T> {
+    items: &'a [T],
+    selection: CheckboxSelection<'a, T>,
+    label: Option<String>,
+    select_all: Option<String>,
+    supporting_text: Option<String>,
+    min_selected: usize,
+    max_selected: Option<usize>,
+    disable: bool,
+    error: bool,
+    color: Option<ColorRole>,
+    style: Option<MaterialCheckboxGroupStyle>,
+    scheme: Option<Arc<Scheme>>,
+}
+
+impl<'a, T: ToString + Eq + Hash + Clone> MaterialCheckboxGroup<'a, T> {
+    /// Group bound to the set of selected items
+    pub fn new(items: &'a [T], selected: &'a mut HashSet<T>) -> Self {
+        Self::bound(items, CheckboxSelection::Set(selected))
+    }
+
+    /// Group bound to a flag for each item
+    ///
+    /// When the lengths differ only the items that have a flag are shown, and
+    /// flags past the last item are left untouched.
+    pub fn flags(items: &'a [T], selected: &'a mut [bool]) -> Self {
+        let len = items.len().min(selected.len());
+        Self::bound(
+            &items[..len],
+            CheckboxSelection::Flags(&mut selected[..len]),
+        )
+    }
+
+    fn bound(items: &'a [T], selection: CheckboxSelection<'a, T>) -> Self {
+        Self {
+            items,
+            selection,
+            label: None,
+            select_all: None,
+            supporting_text: None,
+            min_selected: 0,
+            max_selected: None,
+            disable: false,
+            error: false,
+            color: None,
+            style: None,
+            scheme: None,
+        }
+    }
+
+    /// Heading above the checkboxes
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Add a tri-state parent checkbox that selects or clears all items
+    ///
+    /// It is disabled when fewer items than all of them may be selected.
+    pub fn with_select_all(self, label: impl Into<String>) -> Self {
+        Self {
+            select_all: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Text below the checkboxes, e.g. the selection rules
+    pub fn with_supporting_text(self, text: impl Into<String>) -> Self {
+        Self {
+            supporting_text: Some(text.into()),
+            ..self
+        }
+    }
+
+    /// Fewest items that must be selected
+    pub fn with_min_selected(self, min_selected: usize) -> Self {
+        Self {
+            min_selected,
+            ..self
+        }
+    }
+
+    /// Most items that may be selected
+    ///
+    /// Like the minimum, it only drives the error state: more items can still
+    /// be selected, the group is then shown in the error colors.
+    pub fn with_max_selected(self, max_selected: usize) -> Self {
+        Self {
+            max_selected: Some(max_selected),
+            ..self
+        }
+    }
+
+    pub fn with_disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+
+    /// Show the group in the error colors regardless of the selection
+    pub fn with_error(self, error: bool) -> Self {
+        Self { error, ..self }
+    }
+
+    /// Select the checkboxes in a color role
+    pub fn with_color(self, color: ColorRole) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn with_style(self, style: MaterialCheckboxGroupStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this group with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+
+    /// Number of selected items
+    pub fn selected_count(&self) -> usize {
+        (self.items.iter().enumerate())
+            .filter(|(index, item)| self.selection.is_selected(*index, item))
+            .count()
+    }
+
+    /// Whether the selection is within the min / max limits
+    pub fn is_valid(&self) -> bool {
+        let count = self.selected_count();
+        count >= self.min_selected && self.max_selected.is_none_or(|max| count <= max)
+    }
+}
+
+impl<T: ToString + Eq + Hash + Clone> Widget for MaterialCheckboxGroup<'_, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let error = self.error || !self.is_valid();
+        let count = self.selected_count();
+        let Self {
+            items,
+            mut selection,
+            label,
+            select_all,
+            supporting_text,
+            max_selected,
+            disable,
+            color,
+            style,
+            scheme,
+            ..
+        } = self;
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| match color {
+            Some(color) => MaterialCheckboxGroupStyle::with_checkbox(
+                &tokens,
+                MaterialCheckboxStyle::colored(&tokens, &color),
+            ),
+            None => MaterialCheckboxGroupStyle::normal(&tokens),
+        });
+        let inner = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            let mut changed = false;
+            let mut rows = Vec::with_capacity(items.len() + 1);
+
+            // 组标题
+            if let Some(label) = label {
+                let color = match disable {
+                    true => style
+                        .disabled_label_color
+                        .linear_multiply(style.disabled_label_opacity),
+                    false => style.label_color,
+                };
+                let text_format = style.label_text.text_format(ui.ctx(), color);
+                ui.add(Label::new(LayoutJob::single_section(label, text_format)).selectable(false));
+            }
+
+            // 全选复选框，部分选中时显示为不确定状态，最多可选数量少于全部时禁用
+            if let Some(select_all) = select_all {
+                let select_all_disable =
+                    disable || max_selected.is_some_and(|max| max < items.len());
+                let mut all = match count {
+                    0 => CheckboxState::Unchecked,
+                    count if count == items.len() => CheckboxState::Checked,
+                    _ => CheckboxState::Indeterminate,
+                };
+                let response = ui.add(
+                    MaterialCheckbox::tri_state(&mut all)
+                        .with_label(select_all)
+                        .with_style(style.checkbox.clone())
+                        .with_disable(select_all_disable)
+                        .with_error(error),
+                );
+                if response.changed() {
+                    for (index, item) in items.iter().enumerate() {
+                        selection.set_selected(index, item, all == CheckboxState::Checked);
+                    }
+                    changed = true;
+                }
+                rows.push(response);
+            }
+
+            // 各选项，位于全选之下时缩进
+            let indent = match rows.is_empty() {
+                true => 0.0,
+                false => style.nested_indent,
+            };
+            for (index, item) in items.iter().enumerate() {
+                let mut checked = selection.is_selected(index, item);
+                let response = ui
+                    .horizontal(|ui| {
+                        ui.add_space(indent);
+                        ui.add(
+                            MaterialCheckbox::new(&mut checked)
+                                .with_label(item.to_string())
+                                .with_style(style.checkbox.clone())
+                                .with_disable(disable)
+                                .with_error(error),
+                        )
+                    })
+                    .inner;
+                if response.changed() {
+                    selection.set_selected(index, item, checked);
+                    changed = true;
+                }
+                rows.push(response);
+            }
+
+            // 辅助文字，出错时使用错误颜色
+            if let Some(text) = supporting_text {
+                let color = match (disable, error) {
+                    (true, _) => style
+                        .disabled_label_color
+                        .linear_multiply(style.disabled_label_opacity),
+                    (false, true) => style.checkbox.error_supporting_text_color,
+                    (false, false) => style.checkbox.supporting_text_color,
+                };
+                let text_format = style.checkbox.supporting_text.text_format(ui.ctx(), color);
+                ui.add(Label::new(LayoutJob::single_section(text, text_format)).selectable(false));
+            }
+
+            // 方向键在各行之间移动焦点
+            arrow_key_focus(ui, &rows, false);
+            changed
+        });
+
+        let mut response = inner.response;
+        if inner.inner {
+            // 选择变化后校验结果在下一帧显示
+            ui.ctx().request_repaint();
+            response.mark_changed();
+        }
+        response
+    }
+}
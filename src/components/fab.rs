@@ -0,0 +1,439 @@
+//! Material Design Floating Action Buttons
+
+use egui::{
+    Align2, Area, Color32, Order, Rect, Response, Sense, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+    pos2, text::LayoutJob, vec2,
+};
+use std::sync::Arc;
+
+use super::ripple::Ripple;
+use super::state_layer::*;
+use crate::color::prelude::*;
+use crate::elevation::ElevationLevel;
+use crate::icon::prelude::Icon;
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::shape::{CornerSize, MaterialShape};
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
+
+/// Size of a floating action button
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FabSize {
+    /// 40dp
+    Small,
+    /// 56dp
+    #[default]
+    Regular,
+    /// 96dp
+    Large,
+    /// 56dp high with the label next to the icon
+    Extended,
+}
+
+/// Color set of a floating action button
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FabColor {
+    #[default]
+    Primary,
+    Secondary,
+    Tertiary,
+    /// Surface container with a primary icon
+    Surface,
+}
+
+/// Material FAB Style
+///
+/// See: [material design document](https://m3.material.io/components/floating-action-button/specs)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialFabStyle {
+    pub container_color: Color32,
+    pub content_color: Color32,
+    pub shadow_color: Color32,
+    /// Overlaid on the container color with the opacity of the elevation level
+    pub tint_color: Color32,
+    pub elevation: ElevationLevel,
+    pub hovered_elevation: ElevationLevel,
+    pub pressed_elevation: ElevationLevel,
+    /// Height, and width when not extended
+    pub container_size: f32,
+    pub icon_size: f32,
+    pub shape: MaterialShape,
+    pub label_text: TypeStyle,
+    /// Space before the icon of an extended FAB
+    pub leading_space: f32,
+    /// Space after the label of an extended FAB
+    pub trailing_space: f32,
+    pub between_icon_label_space: f32,
+    /// Opacity of the state layer when hovered, focused, pressed or dragged
+    pub state_layer_opacity: StateLayerOpacity,
+}
+
+impl MaterialFabStyle {
+    /// Primary FAB of the regular size
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::new(tokens, FabSize::Regular, FabColor::Primary)
+    }
+
+    pub fn new(tokens: &MaterialTokens, size: FabSize, color: FabColor) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        let (container_color, content_color) = match color {
+            FabColor::Primary => (scheme.primary_container(), scheme.on_primary_container()),
+            FabColor::Secondary => (
+                scheme.secondary_container(),
+                scheme.on_secondary_container(),
+            ),
+            FabColor::Tertiary => (scheme.tertiary_container(), scheme.on_tertiary_container()),
+            FabColor::Surface => (scheme.surface_container_high(), scheme.primary()),
+        };
+        let (container_size, icon_size, corner) = match size {
+            FabSize::Small => (40.0, 24.0, CornerSize::Medium),
+            FabSize::Regular | FabSize::Extended => (56.0, 24.0, CornerSize::Large),
+            FabSize::Large => (96.0, 36.0, CornerSize::ExtraLarge),
+        };
+
+        Self {
+            container_color,
+            content_color,
+            shadow_color: scheme.shadow(),
+            tint_color: scheme.surface_tint(),
+            elevation: ElevationLevel::Level3,
+            hovered_elevation: ElevationLevel::Level4,
+            pressed_elevation: ElevationLevel::Level3,
+            container_size,
+            icon_size,
+            shape: MaterialShape::all(corner),
+            label_text: typography.label_large.clone(),
+            leading_space: 16.0,
+            trailing_space: 20.0,
+            between_icon_label_space: 12.0,
+            state_layer_opacity: StateLayerOpacity::default(),
+        }
+    }
+
+    /// Lowered FAB, for placing it on a surface that is already elevated
+    pub fn lowered(self) -> Self {
+        Self {
+            elevation: ElevationLevel::Level1,
+            hovered_elevation: ElevationLevel::Level2,
+            pressed_elevation: ElevationLevel::Level1,
+            ..self
+        }
+    }
+}
+
+/// Material Design Floating Action Button
+///
+/// An extended FAB collapses to its icon while the content scrolls down
+/// and expands again when it scrolls back up, see [`Self::with_scroll_offset`].
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let output = egui::ScrollArea::vertical().show(ui, |ui| {
+///     for i in 0..50 {
+///         ui.label(format!("Message {i}"));
+///     }
+/// });
+/// MaterialFab::extended(MaterialIcon::Edit, "Compose")
+///     .with_scroll_offset(output.state.offset.y)
+///     .show_anchored(ui, egui::Align2::RIGHT_BOTTOM);
+/// # });
+/// ```
+pub struct MaterialFab {
+    icon: Icon,
+    label: Option<String>,
+    size: FabSize,
+    color: FabColor,
+    style: Option<MaterialFabStyle>,
+    scheme: Option<Arc<Scheme>>,
+    lowered: bool,
+    expanded: bool,
+    scroll_offset: Option<f32>,
+    visible: bool,
+}
+
+impl MaterialFab {
+    pub fn new(icon: impl Into<Icon>) -> Self {
+        Self {
+            icon: icon.into(),
+            label: None,
+            size: FabSize::Regular,
+            color: FabColor::Primary,
+            style: None,
+            scheme: None,
+            lowered: false,
+            expanded: true,
+            scroll_offset: None,
+            visible: true,
+        }
+    }
+
+    /// Extended FAB with a label next to the icon
+    pub fn extended(icon: impl Into<Icon>, label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            size: FabSize::Extended,
+            ..Self::new(icon)
+        }
+    }
+
+    pub fn with_size(self, size: FabSize) -> Self {
+        Self { size, ..self }
+    }
+
+    pub fn with_color(self, color: FabColor) -> Self {
+        Self { color, ..self }
+    }
+
+    pub fn with_lowered(self, lowered: bool) -> Self {
+        Self { lowered, ..self }
+    }
+
+    /// Show the label of an extended FAB, collapsing to the icon when false
+    pub fn with_expanded(self, expanded: bool) -> Self {
+        Self { expanded, ..self }
+    }
+
+    /// Collapse while this offset grows and expand while it shrinks or is at the top
+    ///
+    /// Pass the vertical offset of the scroll area the FAB floats over.
+    pub fn with_scroll_offset(self, offset: f32) -> Self {
+        Self {
+            scroll_offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Hide the FAB, it shrinks away and stops reacting to the pointer
+    pub fn with_visible(self, visible: bool) -> Self {
+        Self { visible, ..self }
+    }
+
+    pub fn with_style(self, style: MaterialFabStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this FAB with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+
+    /// Show the FAB floating over a corner of the ui, 16dp from its edges
+    ///
+    /// The floating area is identified by the icon, label and corner of the FAB.
+    pub fn show_anchored(self, ui: &Ui, corner: Align2) -> Response {
+        let rect = ui.clip_rect().intersect(ui.max_rect()).shrink(16.0);
+        let pos = pos2(
+            corner.x().to_factor() * rect.width() + rect.left(),
+            corner.y().to_factor() * rect.height() + rect.top(),
+        );
+        let id = ui.id().with(("fab", self.icon, &self.label, corner));
+        Area::new(id)
+            .order(Order::Foreground)
+            .fixed_pos(pos)
+            .pivot(corner)
+            .show(ui.ctx(), |ui| ui.add(self))
+            .inner
+    }
+}
+
+/// Scroll offset seen in the previous pass and whether the FAB was expanded
+#[derive(Clone, Copy)]
+struct ScrollState {
+    offset: f32,
+    /// Offset where the current scroll direction began
+    anchor: f32,
+    expanded: bool,
+}
+
+impl Widget for MaterialFab {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            icon,
+            label,
+            size,
+            color,
+            style,
+            scheme,
+            lowered,
+            expanded,
+            scroll_offset,
+            visible,
+        } = self;
+
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| {
+            let style = MaterialFabStyle::new(&tokens, size, color);
+            match lowered {
+                true => style.lowered(),
+                false => style,
+            }
+        });
+        let id = ui.next_auto_id();
+
+        // 向下滚动时收起，向上滚动或回到顶部时展开，按换向后累计的滚动距离判断
+        let expanded = match scroll_offset {
+            Some(offset) => {
+                let state_id = id.with("scroll");
+                let previous = ui.data(|d| d.get_temp::<ScrollState>(state_id));
+                let anchor = match previous {
+                    Some(previous)
+                        if (previous.offset - previous.anchor) * (offset - previous.offset)
+                            < 0.0 =>
+                    {
+                        previous.offset
+                    }
+                    Some(previous) => previous.anchor,
+                    None => offset,
+                };
+                let scroll_expanded = match previous {
+                    _ if offset <= 0.0 => true,
+                    Some(_) if offset > anchor + 1.0 => false,
+                    Some(_) if offset < anchor - 1.0 => true,
+                    Some(previous) => previous.expanded,
+                    None => true,
+                };
+                let state = ScrollState {
+                    offset,
+                    anchor,
+                    expanded: scroll_expanded,
+                };
+                ui.data_mut(|d| d.insert_temp(state_id, state));
+                expanded && scroll_expanded
+            }
+            None => expanded,
+        };
+
+        let spec = MotionSpec::new(MotionDuration::Medium2, MotionEasing::Emphasized);
+        let extension = match label {
+            Some(_) => tokens
+                .motion
+                .animate_bool(ui.ctx(), id.with("expanded"), expanded, spec),
+            None => 0.0,
+        };
+        let shown = tokens.motion.animate_bool(
+            ui.ctx(),
+            id.with("visible"),
+            visible,
+            MotionSpec::new(MotionDuration::Short4, MotionEasing::Standard),
+        );
+
+        // 无障碍名称：扩展标签，否则为图标名称
+        let accessible_label = match &label {
+            Some(label) => label.clone(),
+            None => icon.accessible_name(),
+        };
+
+        // 展开宽度：图标、间距与标签
+        let galley = label.map(|label| {
+            let text_format = style.label_text.text_format(ui.ctx(), Color32::PLACEHOLDER);
+            ui.fonts(|f| f.layout_job(LayoutJob::single_section(label, text_format)))
+        });
+        let extended_width = match &galley {
+            Some(galley) => {
+                style.leading_space
+                    + style.icon_size
+                    + style.between_icon_label_space
+                    + galley.size().x
+                    + style.trailing_space
+            }
+            None => style.container_size,
+        };
+        let width =
+            style.container_size + (extended_width - style.container_size).max(0.0) * extension;
+
+        let sense = match visible {
+            true => Sense::click(),
+            false => Sense::hover(),
+        };
+        let (rect, response) = ui.allocate_exact_size(vec2(width, style.container_size), sense);
+        let state = InteractionState::from_response(&response, !visible);
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::Button, visible, accessible_label.as_str())
+        });
+
+        // 阴影高度随状态平滑变化，按下时降低
+        let elevation = match state {
+            InteractionState::Pressed | InteractionState::Dragged => style.pressed_elevation,
+            InteractionState::Hovered | InteractionState::Focused => style.hovered_elevation,
+            _ => style.elevation,
+        };
+        let elevation = tokens.motion.animate_value(
+            ui.ctx(),
+            response.id.with("elevation"),
+            elevation.index(),
+            MotionSpec::new(MotionDuration::Short3, MotionEasing::Standard),
+        );
+
+        if shown > 0.0 && ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            // 隐藏时向中心缩小
+            let container_rect = Rect::from_center_size(rect.center(), rect.size() * shown);
+            let rounding = style
+                .shape
+                .corner_radius(&tokens.shapes, Vec2::splat(style.container_size * shown));
+            let content_color = style.content_color.gamma_multiply(shown);
+
+            tokens.elevation.paint(
+                painter,
+                container_rect,
+                rounding,
+                elevation * shown,
+                style.shadow_color,
+            );
+            // 容器叠加当前高度的色调
+            let container_color =
+                (tokens.elevation).tinted(elevation, style.container_color, style.tint_color);
+            painter.rect_filled(container_rect, rounding, container_color);
+
+            StateLayer::new(&response, style.content_color)
+                .with_state(match state {
+                    InteractionState::Pressed => InteractionState::Hovered,
+                    state => state,
+                })
+                .with_opacity(style.state_layer_opacity)
+                .paint(painter, container_rect, rounding);
+            Ripple::new(&response, style.content_color)
+                .with_opacity(style.state_layer_opacity.pressed)
+                .paint(painter, container_rect, rounding);
+
+            // 图标从居中移动到左侧，标签随展开淡入
+            let collapsed_x = container_rect.center().x;
+            let extended_x = container_rect.left() + style.leading_space + style.icon_size / 2.0;
+            let icon_x = collapsed_x + (extended_x - collapsed_x) * extension;
+            let icon_size = style.icon_size * shown;
+            icon.paint(
+                painter,
+                pos2(icon_x, container_rect.center().y),
+                icon_size,
+                content_color,
+            );
+
+            if let Some(galley) = galley
+                && extension > 0.0
+            {
+                let text_pos = pos2(
+                    icon_x + style.icon_size / 2.0 + style.between_icon_label_space,
+                    container_rect.center().y - galley.size().y / 2.0,
+                );
+                painter
+                    .with_clip_rect(container_rect.intersect(painter.clip_rect()))
+                    .galley(text_pos, galley, content_color.gamma_multiply(extension));
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,365 @@
+//! Material Design Icon Buttons
+
+use egui::{
+    Color32, Rect, Response, Sense, Stroke, StrokeKind, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+};
+use std::sync::Arc;
+
+use super::ripple::Ripple;
+use super::state_layer::*;
+use crate::color::prelude::*;
+use crate::icon::prelude::Icon;
+use crate::shape::{CornerSize, MaterialShape};
+use crate::tokens::MaterialTokens;
+use crate::utils::clone_scheme;
+
+/// The icon button variants, from the lowest to the highest emphasis
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconButtonVariant {
+    /// Only the icon, e.g. in top app bars
+    #[default]
+    Standard,
+    /// Outline around a transparent container
+    Outlined,
+    /// Secondary container
+    FilledTonal,
+    /// Primary container, for the most important action
+    Filled,
+}
+
+/// Material Icon Button Style
+///
+/// The plain colors are used by buttons without a toggle state and by
+/// unselected toggle buttons, the `selected_` ones by selected toggle buttons.
+///
+/// See: [material design document](https://m3.material.io/components/icon-buttons/specs)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialIconButtonStyle {
+    pub container_size: f32,
+    /// Size of the interactive area around the container
+    pub target_size: f32,
+    pub icon_size: f32,
+    pub shape: MaterialShape,
+    pub container_color: Color32,
+    pub icon_color: Color32,
+    pub outline_width: f32,
+    pub outline_color: Color32,
+    pub selected_container_color: Color32,
+    pub selected_icon_color: Color32,
+    pub selected_outline_color: Color32,
+    pub disabled_container_color: Color32,
+    pub disabled_container_opacity: f32,
+    pub disabled_icon_color: Color32,
+    pub disabled_icon_opacity: f32,
+    pub disabled_outline_color: Color32,
+    pub disabled_outline_opacity: f32,
+    /// Opacity of the state layer when hovered, focused, pressed or dragged
+    pub state_layer_opacity: StateLayerOpacity,
+}
+
+impl MaterialIconButtonStyle {
+    /// Standard icon button
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::variant(tokens, IconButtonVariant::Standard)
+    }
+
+    /// Icon button of a variant without a toggle state
+    pub fn variant(tokens: &MaterialTokens, variant: IconButtonVariant) -> Self {
+        let scheme = &tokens.scheme;
+        let toggle = Self::toggle(tokens, variant);
+        match variant {
+            IconButtonVariant::Standard | IconButtonVariant::Outlined => toggle,
+            IconButtonVariant::FilledTonal => Self {
+                container_color: scheme.secondary_container(),
+                icon_color: scheme.on_secondary_container(),
+                ..toggle
+            },
+            IconButtonVariant::Filled => Self {
+                container_color: scheme.primary(),
+                icon_color: scheme.on_primary(),
+                ..toggle
+            },
+        }
+    }
+
+    /// Icon button of a variant with a selected and an unselected state
+    pub fn toggle(tokens: &MaterialTokens, variant: IconButtonVariant) -> Self {
+        let scheme = &tokens.scheme;
+        let base = Self {
+            container_size: 40.0,
+            target_size: 48.0,
+            icon_size: 24.0,
+            shape: MaterialShape::all(CornerSize::Full),
+            container_color: Color32::TRANSPARENT,
+            icon_color: scheme.on_surface_variant(),
+            outline_width: 0.0,
+            outline_color: Color32::TRANSPARENT,
+            selected_container_color: Color32::TRANSPARENT,
+            selected_icon_color: scheme.primary(),
+            selected_outline_color: Color32::TRANSPARENT,
+            disabled_container_color: scheme.on_surface(),
+            disabled_container_opacity: DISABLED_CONTAINER_OPACITY,
+            disabled_icon_color: scheme.on_surface(),
+            disabled_icon_opacity: DISABLED_CONTENT_OPACITY,
+            disabled_outline_color: scheme.on_surface(),
+            disabled_outline_opacity: DISABLED_CONTAINER_OPACITY,
+            state_layer_opacity: StateLayerOpacity::default(),
+        };
+
+        match variant {
+            IconButtonVariant::Standard => Self {
+                disabled_container_opacity: 0.0,
+                ..base
+            },
+            IconButtonVariant::Outlined => Self {
+                outline_width: 1.0,
+                outline_color: scheme.outline(),
+                selected_container_color: scheme.inverse_surface(),
+                selected_icon_color: scheme.inverse_on_surface(),
+                ..base
+            },
+            IconButtonVariant::FilledTonal => Self {
+                container_color: scheme.surface_container_highest(),
+                selected_container_color: scheme.secondary_container(),
+                selected_icon_color: scheme.on_secondary_container(),
+                ..base
+            },
+            IconButtonVariant::Filled => Self {
+                container_color: scheme.surface_container_highest(),
+                icon_color: scheme.primary(),
+                selected_container_color: scheme.primary(),
+                selected_icon_color: scheme.on_primary(),
+                ..base
+            },
+        }
+    }
+}
+
+/// Material Design Icon Button
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let mut favorite = false;
+/// ui.add(MaterialIconButton::new(MaterialIcon::Settings).with_tooltip("Settings"));
+/// ui.add(
+///     MaterialIconButton::new(MaterialIcon::Favorite)
+///         .with_selected(&mut favorite)
+///         .with_selected_icon(MaterialIcon::Favorite.filled())
+///         .with_variant(IconButtonVariant::FilledTonal),
+/// );
+/// # });
+/// ```
+pub struct MaterialIconButton<'a> {
+    icon: Icon,
+    selected_icon: Option<Icon>,
+    selected: Option<&'a mut bool>,
+    variant: IconButtonVariant,
+    style: Option<MaterialIconButtonStyle>,
+    scheme: Option<Arc<Scheme>>,
+    tooltip: Option<String>,
+    disable: bool,
+}
+
+impl<'a> MaterialIconButton<'a> {
+    pub fn new(icon: impl Into<Icon>) -> Self {
+        Self {
+            icon: icon.into(),
+            selected_icon: None,
+            selected: None,
+            variant: IconButtonVariant::Standard,
+            style: None,
+            scheme: None,
+            tooltip: None,
+            disable: false,
+        }
+    }
+
+    pub fn with_disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+
+    pub fn with_variant(self, variant: IconButtonVariant) -> Self {
+        Self { variant, ..self }
+    }
+
+    /// Make this a toggle button, clicking it flips `selected`
+    pub fn with_selected(self, selected: &'a mut bool) -> Self {
+        Self {
+            selected: Some(selected),
+            ..self
+        }
+    }
+
+    /// Icon shown while selected, e.g. the filled glyph of the icon
+    pub fn with_selected_icon(self, icon: impl Into<Icon>) -> Self {
+        Self {
+            selected_icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    /// Text shown when hovering the button, icon buttons should describe their action
+    pub fn with_tooltip(self, tooltip: impl Into<String>) -> Self {
+        Self {
+            tooltip: Some(tooltip.into()),
+            ..self
+        }
+    }
+
+    pub fn with_style(self, style: MaterialIconButtonStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this button with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+}
+
+impl Widget for MaterialIconButton<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            icon,
+            selected_icon,
+            mut selected,
+            variant,
+            style,
+            scheme,
+            tooltip,
+            disable,
+        } = self;
+
+        // 未指定样式时从上下文中的主题生成，切换按钮使用选中/未选中配色
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| match selected {
+            Some(_) => MaterialIconButtonStyle::toggle(&tokens, variant),
+            None => MaterialIconButtonStyle::variant(&tokens, variant),
+        });
+
+        let sense = if disable {
+            Sense::hover()
+        } else {
+            Sense::click()
+        };
+        let target_size = style.target_size.max(style.container_size);
+        let (rect, mut response) = ui.allocate_exact_size(Vec2::splat(target_size), sense);
+
+        // 切换选中状态
+        if !disable
+            && response.clicked()
+            && let Some(selected) = selected.as_deref_mut()
+        {
+            *selected = !*selected;
+            response.mark_changed();
+        }
+        let toggle = selected.is_some();
+        let is_selected = selected.is_some_and(|selected| *selected);
+        let state = InteractionState::from_response(&response, disable);
+
+        // 无文字按钮以提示文字或图标名称作为无障碍名称
+        let accessible_label = match &tooltip {
+            Some(tooltip) => tooltip.clone(),
+            None => icon.accessible_name(),
+        };
+        response.widget_info(|| match toggle {
+            true => WidgetInfo::selected(
+                WidgetType::Button,
+                !disable,
+                is_selected,
+                accessible_label.as_str(),
+            ),
+            false => WidgetInfo::labeled(WidgetType::Button, !disable, accessible_label.as_str()),
+        });
+
+        // 计算颜色
+        let (container_color, icon_color, outline_color) = if disable {
+            let container = match is_selected {
+                true => style.selected_container_color,
+                false => style.container_color,
+            };
+            (
+                match container == Color32::TRANSPARENT {
+                    true => Color32::TRANSPARENT,
+                    false => style
+                        .disabled_container_color
+                        .linear_multiply(style.disabled_container_opacity),
+                },
+                style
+                    .disabled_icon_color
+                    .linear_multiply(style.disabled_icon_opacity),
+                style
+                    .disabled_outline_color
+                    .linear_multiply(style.disabled_outline_opacity),
+            )
+        } else if is_selected {
+            (
+                style.selected_container_color,
+                style.selected_icon_color,
+                style.selected_outline_color,
+            )
+        } else {
+            (style.container_color, style.icon_color, style.outline_color)
+        };
+
+        let container_rect =
+            Rect::from_center_size(rect.center(), Vec2::splat(style.container_size));
+        let rounding = style
+            .shape
+            .corner_radius(&tokens.shapes, container_rect.size());
+
+        // 绘制按钮外观
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+
+            painter.rect_filled(container_rect, rounding, container_color);
+            if style.outline_width > 0.0 && !is_selected {
+                painter.rect_stroke(
+                    container_rect,
+                    rounding,
+                    Stroke::new(style.outline_width, outline_color),
+                    StrokeKind::Inside,
+                );
+            }
+
+            // 绘制 state layer 与水波纹，颜色与图标一致
+            let layer_state = match state {
+                InteractionState::Pressed => InteractionState::Hovered,
+                state => state,
+            };
+            StateLayer::new(&response, icon_color)
+                .with_state(layer_state)
+                .with_opacity(style.state_layer_opacity)
+                .paint(painter, container_rect, rounding);
+            Ripple::new(&response, icon_color)
+                .with_opacity(style.state_layer_opacity.pressed)
+                .paint(painter, container_rect, rounding);
+
+            // 绘制图标，选中时可使用单独的图标
+            let icon = match (is_selected, selected_icon) {
+                (true, Some(selected_icon)) => selected_icon,
+                _ => icon,
+            };
+            icon.paint(
+                painter,
+                container_rect.center(),
+                style.icon_size,
+                icon_color,
+            );
+        }
+
+        match tooltip {
+            Some(tooltip) if disable => response.on_disabled_hover_text(tooltip),
+            Some(tooltip) => response.on_hover_text(tooltip),
+            None => response,
+        }
+    }
+}
@@ -1,4 +1,13 @@
 pub mod prelude;
 pub mod button;
 pub mod checkbox;
+pub mod checkbox_group;
+pub mod fab;
+pub mod icon_button;
+pub mod radio;
+pub mod radio_group;
+pub mod ripple;
+pub mod segmented_button;
+pub mod state_layer;
+pub mod switch;
 pub mod text_field;
@@ -1,3 +1,12 @@
 pub use super::button::*;
 pub use super::checkbox::*;
+pub use super::checkbox_group::*;
+pub use super::fab::*;
+pub use super::icon_button::*;
+pub use super::radio::*;
+pub use super::radio_group::*;
+pub use super::ripple::*;
+pub use super::segmented_button::*;
+pub use super::state_layer::*;
+pub use super::switch::*;
 pub use super::text_field::*;
@@ -0,0 +1,329 @@
+//! Material Design radio button
+
+use egui::{
+    Color32, CornerRadius, Rect, Sense, Stroke, Vec2, Widget, WidgetInfo, WidgetType,
+    text::LayoutJob, vec2,
+};
+use std::sync::Arc;
+
+use super::ripple::Ripple;
+use super::state_layer::*;
+use crate::color::prelude::*;
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
+
+/// Style for Material Design radio button
+///
+/// See: [material design document](https://m3.material.io/components/radio-button/specs)
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialRadioStyle {
+    /// Outer diameter of the ring
+    pub icon_size: f32,
+    pub ring_width: f32,
+    /// Diameter of the dot of a selected radio button
+    pub dot_size: f32,
+    pub layer_size: f32,
+    /// Size of the clickable area around the ring
+    pub target_size: f32,
+    /// Space between the touch target and the label
+    pub between_target_label_space: f32,
+    pub selected_color: Color32,
+    pub unselected_color: Color32,
+    pub hovered_unselected_color: Color32,
+    pub pressed_unselected_color: Color32,
+    pub error_color: Color32,
+    pub disabled_selected_color: Color32,
+    pub disabled_unselected_color: Color32,
+    pub disabled_opacity: f32,
+    pub selected_layer_color: Color32,
+    pub unselected_layer_color: Color32,
+    pub pressed_selected_layer_color: Color32,
+    pub pressed_unselected_layer_color: Color32,
+    pub error_layer_color: Color32,
+    /// Opacity of the state layer when hovered, focused or pressed
+    pub state_layer_opacity: StateLayerOpacity,
+    pub label_text: TypeStyle,
+    pub label_color: Color32,
+    pub disabled_label_color: Color32,
+    pub disabled_label_opacity: f32,
+}
+
+impl MaterialRadioStyle {
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::colored(tokens, &ColorRole::Primary)
+    }
+
+    /// Radio button selected in the given color role
+    pub fn colored(tokens: &MaterialTokens, role: &ColorRole) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        let roles = tokens.roles(role);
+        Self {
+            icon_size: 20.0,
+            ring_width: 2.0,
+            dot_size: 10.0,
+            layer_size: 40.0,
+            target_size: 48.0,
+            between_target_label_space: 4.0,
+            selected_color: roles.color,
+            unselected_color: scheme.on_surface_variant(),
+            hovered_unselected_color: scheme.on_surface(),
+            pressed_unselected_color: scheme.on_surface(),
+            error_color: scheme.error(),
+            disabled_selected_color: scheme.on_surface(),
+            disabled_unselected_color: scheme.on_surface(),
+            disabled_opacity: DISABLED_CONTENT_OPACITY,
+            selected_layer_color: roles.color,
+            unselected_layer_color: scheme.on_surface(),
+            pressed_selected_layer_color: scheme.on_surface(),
+            pressed_unselected_layer_color: roles.color,
+            error_layer_color: scheme.error(),
+            state_layer_opacity: StateLayerOpacity::default(),
+            label_text: typography.body_large.clone(),
+            label_color: scheme.on_surface(),
+            disabled_label_color: scheme.on_surface(),
+            disabled_label_opacity: DISABLED_CONTENT_OPACITY,
+        }
+    }
+
+    /// Size of the clickable area around the ring, 48 by default
+    pub fn with_target_size(self, target_size: f32) -> Self {
+        Self {
+            target_size,
+            ..self
+        }
+    }
+
+    pub fn with_label_text(self, label_text: TypeStyle) -> Self {
+        Self { label_text, ..self }
+    }
+}
+
+/// Material Design Radio Button
+///
+/// Shows whether it is selected, set the value when it is clicked.
+/// [`MaterialRadioGroup`](super::radio_group::MaterialRadioGroup) binds a
+/// set of radio buttons to a value.
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let mut dark = false;
+/// if ui.add(MaterialRadio::new(!dark).with_label("Light")).clicked() {
+///     dark = false;
+/// }
+/// if ui.add(MaterialRadio::new(dark).with_label("Dark")).clicked() {
+///     dark = true;
+/// }
+/// # });
+/// ```
+pub struct MaterialRadio {
+    selected: bool,
+    label: Option<String>,
+    disable: bool,
+    error: bool,
+    color: Option<ColorRole>,
+    style: Option<MaterialRadioStyle>,
+    scheme: Option<Arc<Scheme>>,
+}
+
+impl MaterialRadio {
+    pub fn new(selected: bool) -> Self {
+        Self {
+            selected,
+            label: None,
+            disable: false,
+            error: false,
+            color: None,
+            style: None,
+            scheme: None,
+        }
+    }
+
+    /// Text next to the ring, clicking it selects the radio button too
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    pub fn with_disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+
+    /// Show the radio button in the error colors
+    pub fn with_error(self, error: bool) -> Self {
+        Self { error, ..self }
+    }
+
+    /// Select the radio button in a color role
+    pub fn with_color(self, color: ColorRole) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn with_style(self, style: MaterialRadioStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this radio button with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+}
+
+impl Widget for MaterialRadio {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let Self {
+            selected,
+            label,
+            disable,
+            error,
+            color,
+            style,
+            scheme,
+        } = self;
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| match color {
+            Some(color) => MaterialRadioStyle::colored(&tokens, &color),
+            None => MaterialRadioStyle::normal(&tokens),
+        });
+
+        let sense = if disable {
+            Sense::hover()
+        } else {
+            Sense::click()
+        };
+
+        // 排版标签
+        let accessible_label = label.clone().unwrap_or_default();
+        let galley = label.map(|label| {
+            let text_format = style.label_text.text_format(ui.ctx(), Color32::PLACEHOLDER);
+            ui.fonts(|f| f.layout_job(LayoutJob::single_section(label, text_format)))
+        });
+
+        // 整行都可点击，单选框周围保留触控区域
+        let target_size = style.target_size.max(style.icon_size);
+        let row_size = match &galley {
+            Some(galley) => vec2(
+                target_size + style.between_target_label_space + galley.size().x,
+                target_size.max(galley.size().y),
+            ),
+            None => Vec2::splat(target_size),
+        };
+        let (row_rect, response) = ui.allocate_exact_size(row_size, sense);
+        let target_rect = Rect::from_min_size(
+            row_rect.left_center() - vec2(0.0, target_size / 2.0),
+            Vec2::splat(target_size),
+        );
+        let center = target_rect.center();
+        let layer_rect = Rect::from_center_size(center, Vec2::splat(style.layer_size));
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::RadioButton,
+                !disable,
+                selected,
+                accessible_label.as_str(),
+            )
+        });
+
+        let state = InteractionState::from_response(&response, disable);
+        let is_pressed = matches!(state, InteractionState::Pressed | InteractionState::Dragged);
+        let is_hovering = matches!(state, InteractionState::Hovered | InteractionState::Focused);
+
+        // 中心圆点随选中状态放大或缩小
+        let dot_progress = tokens.motion.animate_bool(
+            ui.ctx(),
+            response.id.with("dot"),
+            selected,
+            MotionSpec::new(MotionDuration::Short4, MotionEasing::EmphasizedDecelerate),
+        );
+
+        // 计算颜色
+        let icon_color = if disable {
+            match selected {
+                true => style.disabled_selected_color,
+                false => style.disabled_unselected_color,
+            }
+            .linear_multiply(style.disabled_opacity)
+        } else if error {
+            style.error_color
+        } else if selected {
+            style.selected_color
+        } else if is_pressed {
+            style.pressed_unselected_color
+        } else if is_hovering {
+            style.hovered_unselected_color
+        } else {
+            style.unselected_color
+        };
+        let layer_color = match (error, selected, is_pressed) {
+            (true, _, _) => style.error_layer_color,
+            (false, true, true) => style.pressed_selected_layer_color,
+            (false, false, true) => style.pressed_unselected_layer_color,
+            (false, true, false) => style.selected_layer_color,
+            (false, false, false) => style.unselected_layer_color,
+        };
+
+        // 绘制
+        if ui.is_rect_visible(row_rect) {
+            let painter = ui.painter();
+            let layer_rounding = CornerRadius::from(style.layer_size / 2.0);
+
+            // 绘制 state layer（悬停/聚焦反馈），按下由水波纹表现
+            let layer_state = match state {
+                InteractionState::Pressed => InteractionState::Hovered,
+                state => state,
+            };
+            StateLayer::new(&response, layer_color)
+                .with_state(layer_state)
+                .with_opacity(style.state_layer_opacity)
+                .paint(painter, layer_rect, layer_rounding);
+            Ripple::new(&response, layer_color)
+                .with_opacity(style.state_layer_opacity.pressed)
+                .paint(painter, layer_rect, layer_rounding);
+
+            // 绘制外圈与中心圆点
+            let ring_radius = (style.icon_size - style.ring_width) / 2.0;
+            painter.circle_stroke(
+                center,
+                ring_radius,
+                Stroke::new(style.ring_width, icon_color),
+            );
+            if dot_progress > 0.0 {
+                painter.circle_filled(center, style.dot_size / 2.0 * dot_progress, icon_color);
+            }
+
+            // 绘制标签
+            if let Some(galley) = galley {
+                let label_color = match disable {
+                    true => style
+                        .disabled_label_color
+                        .linear_multiply(style.disabled_label_opacity),
+                    false => style.label_color,
+                };
+                let pos = egui::pos2(
+                    target_rect.right() + style.between_target_label_space,
+                    row_rect.center().y - galley.size().y / 2.0,
+                );
+                painter.galley(pos, galley, label_color);
+            }
+        }
+
+        response
+    }
+}
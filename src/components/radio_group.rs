@@ -0,0 +1,250 @@
+//! Material Design radio groups
+
+use egui::{Color32, Label, Response, Ui, Widget, text::LayoutJob};
+use std::sync::Arc;
+
+use super::radio::*;
+use super::state_layer::DISABLED_CONTENT_OPACITY;
+use crate::color::prelude::*;
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::{arrow_key_focus, clone_scheme};
+
+/// Style for Material Design radio group
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialRadioGroupStyle {
+    /// Style of every radio button
+    pub radio: MaterialRadioStyle,
+    pub label_text: TypeStyle,
+    pub label_color: Color32,
+    pub disabled_label_color: Color32,
+    pub disabled_label_opacity: f32,
+    /// Space between the options of a horizontal group
+    pub horizontal_space: f32,
+}
+
+impl MaterialRadioGroupStyle {
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::with_radio(tokens, MaterialRadioStyle::normal(tokens))
+    }
+
+    /// Group of radio buttons with the given style
+    pub fn with_radio(tokens: &MaterialTokens, radio: MaterialRadioStyle) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        Self {
+            radio,
+            label_text: typography.title_small.clone(),
+            label_color: scheme.on_surface_variant(),
+            disabled_label_color: scheme.on_surface(),
+            disabled_label_opacity: DISABLED_CONTENT_OPACITY,
+            horizontal_space: 16.0,
+        }
+    }
+}
+
+/// A set of radio buttons bound to a value, only one option is selected
+///
+/// The arrow keys move the focus between the options and select them,
+/// wrapping around at the ends.
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(PartialEq)]
+/// enum Size {
+///     Small,
+///     Medium,
+///     Large,
+/// }
+///
+/// let mut size = Size::Medium;
+/// ui.add(
+///     MaterialRadioGroup::new(&mut size)
+///         .with_label("Size")
+///         .with_option(Size::Small, "Small")
+///         .with_option(Size::Medium, "Medium")
+///         .with_option(Size::Large, "Large")
+///         .with_horizontal(true),
+/// );
+/// # });
+/// ```
+pub struct MaterialRadioGroup<'a, T> {
+    value: &'a mut T,
+    options: Vec<(T, String)>,
+    label: Option<String>,
+    horizontal: bool,
+    disable: bool,
+    error: bool,
+    color: Option<ColorRole>,
+    style: Option<MaterialRadioGroupStyle>,
+    scheme: Option<Arc<Scheme>>,
+}
+
+impl<'a, T: PartialEq> MaterialRadioGroup<'a, T> {
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            options: Vec::new(),
+            label: None,
+            horizontal: false,
+            disable: false,
+            error: false,
+            color: None,
+            style: None,
+            scheme: None,
+        }
+    }
+
+    /// Add an option that sets `value` when selected
+    pub fn with_option(mut self, value: T, label: impl Into<String>) -> Self {
+        self.options.push((value, label.into()));
+        self
+    }
+
+    /// Add several options at once
+    pub fn with_options<S: Into<String>>(
+        mut self,
+        options: impl IntoIterator<Item = (T, S)>,
+    ) -> Self {
+        (self.options).extend(
+            options
+                .into_iter()
+                .map(|(value, label)| (value, label.into())),
+        );
+        self
+    }
+
+    /// Heading above the options
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Lay the options out in a row instead of a column
+    pub fn with_horizontal(self, horizontal: bool) -> Self {
+        Self { horizontal, ..self }
+    }
+
+    pub fn with_disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+
+    /// Show the options in the error colors
+    pub fn with_error(self, error: bool) -> Self {
+        Self { error, ..self }
+    }
+
+    /// Select the options in a color role
+    pub fn with_color(self, color: ColorRole) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn with_style(self, style: MaterialRadioGroupStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this group with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+}
+
+impl<T: PartialEq> Widget for MaterialRadioGroup<'_, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            options,
+            label,
+            horizontal,
+            disable,
+            error,
+            color,
+            style,
+            scheme,
+        } = self;
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| match color {
+            Some(color) => MaterialRadioGroupStyle::with_radio(
+                &tokens,
+                MaterialRadioStyle::colored(&tokens, &color),
+            ),
+            None => MaterialRadioGroupStyle::normal(&tokens),
+        });
+
+        let inner = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+
+            // 组标题
+            if let Some(label) = label {
+                let color = match disable {
+                    true => style
+                        .disabled_label_color
+                        .linear_multiply(style.disabled_label_opacity),
+                    false => style.label_color,
+                };
+                let text_format = style.label_text.text_format(ui.ctx(), color);
+                ui.add(Label::new(LayoutJob::single_section(label, text_format)).selectable(false));
+            }
+
+            // 各选项，横向或纵向排列
+            let add_options = |ui: &mut Ui| {
+                let mut clicked = None;
+                let rows: Vec<Response> = (options.iter().enumerate())
+                    .map(|(index, (option, label))| {
+                        let response = ui.add(
+                            MaterialRadio::new(option == &*value)
+                                .with_label(label.clone())
+                                .with_style(style.radio.clone())
+                                .with_disable(disable)
+                                .with_error(error),
+                        );
+                        if response.clicked() {
+                            clicked = Some(index);
+                        }
+                        response
+                    })
+                    .collect();
+
+                // 方向键移动焦点并选中，首尾循环
+                match disable {
+                    true => clicked,
+                    false => arrow_key_focus(ui, &rows, true).or(clicked),
+                }
+            };
+            match horizontal {
+                true => {
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = style.horizontal_space;
+                        add_options(ui)
+                    })
+                    .inner
+                }
+                false => add_options(ui),
+            }
+        });
+
+        let mut response = inner.response;
+        if let Some(index) = inner.inner
+            && let Some((option, _)) = options.into_iter().nth(index)
+            && option != *value
+        {
+            *value = option;
+            response.mark_changed();
+        }
+        response
+    }
+}
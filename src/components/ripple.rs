@@ -0,0 +1,274 @@
+//! Material Design ripple (ink) press effect
+
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use egui::{
+    Color32, Context, CornerRadius, Id, Painter, Pos2, Rect, Response, Shape, Stroke, Vec2, pos2,
+    vec2,
+};
+
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::tokens::MaterialTokens;
+
+/// Motion of a ripple growing to cover the whole container
+const RIPPLE_GROW: MotionSpec =
+    MotionSpec::new(MotionDuration::Medium2, MotionEasing::StandardDecelerate);
+/// Motion of a released ripple fading out
+const RIPPLE_FADE: MotionSpec = MotionSpec::new(MotionDuration::Short3, MotionEasing::Linear);
+/// Radius a ripple starts with, relative to the larger side of the container
+const RIPPLE_START_RADIUS: f32 = 0.1;
+
+/// A single wave, the origin is relative to the container rect
+#[derive(Clone, Copy)]
+struct Wave {
+    origin: Vec2,
+    start: f64,
+    released: Option<f64>,
+}
+
+#[derive(Clone, Default)]
+struct RippleState {
+    pressed: bool,
+    waves: Vec<Wave>,
+}
+
+/// A ripple growing from the press position, clipped to the container shape
+///
+/// Ripples are kept per widget [`Id`] and keep growing and fading after the
+/// pointer is released, so paint it every frame, right after the state layer.
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let tokens = MaterialTokens::load(ui.ctx());
+/// // a custom list row with Material press feedback
+/// let (rect, response) = ui.allocate_exact_size(egui::vec2(240.0, 56.0), egui::Sense::click());
+/// StateLayer::new(&response, tokens.scheme.on_surface()).paint(ui.painter(), rect, 0.0);
+/// Ripple::new(&response, tokens.scheme.on_surface()).paint(ui.painter(), rect, 0.0);
+/// # });
+/// ```
+pub struct Ripple {
+    id: Id,
+    pressed: bool,
+    press_pos: Option<Pos2>,
+    keyboard_click: bool,
+    color: Color32,
+    opacity: f32,
+}
+
+impl Ripple {
+    /// Ripple of a widget in the given color, usually the "on" color of its container
+    pub fn new(response: &Response, color: Color32) -> Self {
+        let press_pos = response.interact_pointer_pos();
+        Self {
+            id: response.id.with("ripple"),
+            pressed: response.is_pointer_button_down_on(),
+            press_pos,
+            // 键盘触发的点击没有指针位置
+            keyboard_click: response.clicked() && press_pos.is_none(),
+            color,
+            opacity: 0.1,
+        }
+    }
+
+    /// Opacity of the ripple while pressed, the pressed state layer opacity by default
+    pub fn with_opacity(self, opacity: f32) -> Self {
+        Self { opacity, ..self }
+    }
+
+    /// Update and paint the ripples over a rounded rect container
+    pub fn paint(&self, painter: &Painter, rect: Rect, corner_radius: impl Into<CornerRadius>) {
+        let ctx = painter.ctx();
+        // 减少动效时水波纹立即铺满并立即消失
+        let motion = MaterialTokens::load(ctx).motion;
+        let grow_time = motion.seconds(RIPPLE_GROW.duration).max(f32::EPSILON);
+        let fade_time = motion.seconds(RIPPLE_FADE.duration).max(f32::EPSILON);
+        let Some(waves) = self.update(ctx, rect, fade_time) else {
+            return;
+        };
+
+        let now = ctx.input(|i| i.time);
+        let clip = rounded_rect_path(rect, corner_radius.into());
+        let max_radius = rect.size().max_elem();
+        for wave in waves {
+            let origin = rect.min + wave.origin;
+            // 覆盖整个容器所需的半径
+            let end_radius = [
+                rect.left_top(),
+                rect.right_top(),
+                rect.left_bottom(),
+                rect.right_bottom(),
+            ]
+            .into_iter()
+            .map(|corner| corner.distance(origin))
+            .fold(0.0, f32::max);
+
+            let grow = ((now - wave.start) as f32 / grow_time).min(1.0);
+            let start_radius = max_radius * RIPPLE_START_RADIUS;
+            let radius =
+                start_radius + (end_radius - start_radius) * RIPPLE_GROW.easing.apply(grow);
+
+            let fade = wave
+                .released
+                .map(|released| {
+                    RIPPLE_FADE
+                        .easing
+                        .apply((now - released) as f32 / fade_time)
+                })
+                .unwrap_or(0.0);
+            let color = self.color.linear_multiply(self.opacity * (1.0 - fade));
+
+            let points = clip_convex(&circle_path(origin, radius), &clip);
+            if points.len() >= 3 {
+                painter.add(Shape::convex_polygon(points, color, Stroke::NONE));
+            }
+        }
+    }
+
+    /// Paint the ripples over a circular container
+    pub fn paint_circle(&self, painter: &Painter, center: Pos2, radius: f32) {
+        let rect = Rect::from_center_size(center, Vec2::splat(radius * 2.0));
+        self.paint(painter, rect, radius);
+    }
+
+    /// Track presses and drop finished waves, `None` when nothing is left to paint
+    fn update(&self, ctx: &Context, rect: Rect, fade_time: f32) -> Option<Vec<Wave>> {
+        let now = ctx.input(|i| i.time);
+        let mut state: RippleState = ctx.data(|d| d.get_temp(self.id)).unwrap_or_default();
+        if !self.pressed && !state.pressed && state.waves.is_empty() && !self.keyboard_click {
+            return None;
+        }
+
+        // 新的按下，从指针位置开始（键盘触发时从中心开始）
+        if self.pressed && !state.pressed {
+            let origin = self.press_pos.unwrap_or(rect.center());
+            state.waves.push(Wave {
+                origin: origin - rect.min,
+                start: now,
+                released: None,
+            });
+        } else if self.keyboard_click {
+            state.waves.push(Wave {
+                origin: rect.size() / 2.0,
+                start: now,
+                released: Some(now),
+            });
+        }
+
+        // 松开后开始淡出
+        if !self.pressed {
+            for wave in &mut state.waves {
+                wave.released.get_or_insert(now);
+            }
+        }
+        state.pressed = self.pressed;
+        state.waves.retain(|wave| {
+            wave.released
+                .is_none_or(|released| now - released < f64::from(fade_time))
+        });
+
+        let waves = state.waves.clone();
+        if waves.is_empty() && !state.pressed {
+            ctx.data_mut(|d| d.remove::<RippleState>(self.id));
+        } else {
+            ctx.data_mut(|d| d.insert_temp(self.id, state));
+            ctx.request_repaint();
+        }
+        Some(waves)
+    }
+}
+
+fn circle_path(center: Pos2, radius: f32) -> Vec<Pos2> {
+    let segments = ((radius * 0.5) as usize).clamp(16, 64);
+    (0..segments)
+        .map(|i| {
+            let angle = i as f32 / segments as f32 * TAU;
+            center + radius * Vec2::angled(angle)
+        })
+        .collect()
+}
+
+/// Outline of a rounded rect, clockwise in screen coordinates
+fn rounded_rect_path(rect: Rect, corner_radius: CornerRadius) -> Vec<Pos2> {
+    let max = rect.size().min_elem() / 2.0;
+    let corners = [
+        // 左上、右上、右下、左下，以及各自圆弧的起始角度
+        (
+            f32::from(corner_radius.nw).min(max),
+            rect.left_top(),
+            vec2(1.0, 1.0),
+            PI,
+        ),
+        (
+            f32::from(corner_radius.ne).min(max),
+            rect.right_top(),
+            vec2(-1.0, 1.0),
+            -FRAC_PI_2,
+        ),
+        (
+            f32::from(corner_radius.se).min(max),
+            rect.right_bottom(),
+            vec2(-1.0, -1.0),
+            0.0,
+        ),
+        (
+            f32::from(corner_radius.sw).min(max),
+            rect.left_bottom(),
+            vec2(1.0, -1.0),
+            FRAC_PI_2,
+        ),
+    ];
+
+    let mut path = Vec::new();
+    for (radius, corner, inward, start_angle) in corners {
+        if radius <= 0.0 {
+            path.push(corner);
+            continue;
+        }
+        let center = corner + inward * radius;
+        let segments = 8;
+        for i in 0..=segments {
+            let angle = start_angle + i as f32 / segments as f32 * FRAC_PI_2;
+            path.push(center + radius * Vec2::angled(angle));
+        }
+    }
+    path
+}
+
+/// Intersection of a polygon with a convex clockwise clip polygon (Sutherland–Hodgman)
+fn clip_convex(subject: &[Pos2], clip: &[Pos2]) -> Vec<Pos2> {
+    let inside = |a: Pos2, b: Pos2, p: Pos2| (b - a).x * (p - a).y - (b - a).y * (p - a).x >= 0.0;
+    let intersect = |a: Pos2, b: Pos2, p: Pos2, q: Pos2| {
+        let r = b - a;
+        let s = q - p;
+        let denom = r.x * s.y - r.y * s.x;
+        if denom.abs() < f32::EPSILON {
+            return p;
+        }
+        let t = ((p - a).x * s.y - (p - a).y * s.x) / denom;
+        pos2(a.x + r.x * t, a.y + r.y * t)
+    };
+
+    let mut output = subject.to_vec();
+    for i in 0..clip.len() {
+        let (a, b) = (clip[i], clip[(i + 1) % clip.len()]);
+        let input = std::mem::take(&mut output);
+        for j in 0..input.len() {
+            let (p, q) = (input[j], input[(j + 1) % input.len()]);
+            match (inside(a, b, p), inside(a, b, q)) {
+                (true, true) => output.push(q),
+                (true, false) => output.push(intersect(a, b, p, q)),
+                (false, true) => {
+                    output.push(intersect(a, b, p, q));
+                    output.push(q);
+                }
+                (false, false) => {}
+            }
+        }
+        if output.is_empty() {
+            break;
+        }
+    }
+    output
+}
@@ -0,0 +1,410 @@
+//! Material Design Segmented Buttons
+
+use egui::{
+    Color32, Rect, Response, Sense, Stroke, StrokeKind, Ui, Widget, WidgetInfo, WidgetType, pos2,
+    text::LayoutJob, vec2,
+};
+use std::sync::Arc;
+
+use super::ripple::Ripple;
+use super::state_layer::*;
+use crate::color::prelude::*;
+use crate::icon::prelude::{Icon, VectorIcon};
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::shape::{CornerSize, MaterialShape};
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
+
+/// A segment of a segmented button, with a label, an icon or both
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment {
+    pub label: Option<String>,
+    pub icon: Option<Icon>,
+    pub disable: bool,
+}
+
+impl Segment {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            icon: None,
+            disable: false,
+        }
+    }
+
+    /// Segment showing only an icon
+    pub fn icon(icon: impl Into<Icon>) -> Self {
+        Self {
+            label: None,
+            icon: Some(icon.into()),
+            disable: false,
+        }
+    }
+
+    /// Show an icon before the label, replaced by the check while selected
+    pub fn with_icon(self, icon: impl Into<Icon>) -> Self {
+        Self {
+            icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    pub fn with_disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+}
+
+/// Selection a segmented button is bound to
+pub enum SegmentSelection<'a> {
+    /// Index of the only selected segment
+    Single(&'a mut usize),
+    /// Selected flag of every segment
+    Multi(&'a mut [bool]),
+    /// Selected segments as bits, bit `i` for segment `i`
+    Bits(&'a mut u64),
+}
+
+impl SegmentSelection<'_> {
+    fn is_selected(&self, index: usize) -> bool {
+        match self {
+            SegmentSelection::Single(selected) => **selected == index,
+            SegmentSelection::Multi(selected) => selected.get(index).copied().unwrap_or(false),
+            SegmentSelection::Bits(bits) => index < 64 && **bits & (1 << index) != 0,
+        }
+    }
+
+    /// Toggle a segment, returns whether the selection changed
+    fn toggle(&mut self, index: usize) -> bool {
+        match self {
+            SegmentSelection::Single(selected) => {
+                let changed = **selected != index;
+                **selected = index;
+                changed
+            }
+            SegmentSelection::Multi(selected) => match selected.get_mut(index) {
+                Some(selected) => {
+                    *selected = !*selected;
+                    true
+                }
+                None => false,
+            },
+            SegmentSelection::Bits(bits) => {
+                if index < 64 {
+                    **bits ^= 1 << index;
+                }
+                index < 64
+            }
+        }
+    }
+}
+
+/// Material Segmented Button Style
+///
+/// See: [material design document](https://m3.material.io/components/segmented-buttons/specs)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialSegmentedButtonStyle {
+    pub height: f32,
+    /// Height removed per density step below zero
+    pub density_step: f32,
+    pub min_segment_width: f32,
+    pub padding_x: f32,
+    pub icon_size: f32,
+    pub between_icon_label_space: f32,
+    pub shape: MaterialShape,
+    pub label_text: TypeStyle,
+    pub outline_width: f32,
+    pub outline_color: Color32,
+    pub label_color: Color32,
+    pub selected_container_color: Color32,
+    pub selected_label_color: Color32,
+    pub disabled_label_color: Color32,
+    pub disabled_label_opacity: f32,
+    pub disabled_outline_color: Color32,
+    pub disabled_outline_opacity: f32,
+    /// Opacity of the state layer when hovered, focused, pressed or dragged
+    pub state_layer_opacity: StateLayerOpacity,
+}
+
+impl MaterialSegmentedButtonStyle {
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        Self {
+            height: 40.0,
+            density_step: 4.0,
+            min_segment_width: 48.0,
+            padding_x: 12.0,
+            icon_size: 18.0,
+            between_icon_label_space: 8.0,
+            shape: MaterialShape::all(CornerSize::Full),
+            label_text: typography.label_large.clone(),
+            outline_width: 1.0,
+            outline_color: scheme.outline(),
+            label_color: scheme.on_surface(),
+            selected_container_color: scheme.secondary_container(),
+            selected_label_color: scheme.on_secondary_container(),
+            disabled_label_color: scheme.on_surface(),
+            disabled_label_opacity: DISABLED_CONTENT_OPACITY,
+            disabled_outline_color: scheme.on_surface(),
+            disabled_outline_opacity: DISABLED_CONTAINER_OPACITY,
+            state_layer_opacity: StateLayerOpacity::default(),
+        }
+    }
+}
+
+/// Material Design Segmented Button
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let mut period = 0;
+/// ui.add(MaterialSegmentedButton::single(
+///     vec![Segment::new("Day"), Segment::new("Week"), Segment::new("Month")],
+///     &mut period,
+/// ));
+///
+/// let mut sizes = [false, true, false];
+/// ui.add(
+///     MaterialSegmentedButton::multi(
+///         vec![Segment::new("S"), Segment::new("M"), Segment::new("L").with_disable(true)],
+///         &mut sizes,
+///     )
+///     .with_density(-2),
+/// );
+/// # });
+/// ```
+pub struct MaterialSegmentedButton<'a> {
+    segments: Vec<Segment>,
+    selection: SegmentSelection<'a>,
+    density: i32,
+    style: Option<MaterialSegmentedButtonStyle>,
+    scheme: Option<Arc<Scheme>>,
+    disable: bool,
+}
+
+impl<'a> MaterialSegmentedButton<'a> {
+    pub fn new(segments: Vec<Segment>, selection: SegmentSelection<'a>) -> Self {
+        Self {
+            segments,
+            selection,
+            density: 0,
+            style: None,
+            scheme: None,
+            disable: false,
+        }
+    }
+
+    /// Exactly one segment selected at a time
+    pub fn single(segments: Vec<Segment>, selected: &'a mut usize) -> Self {
+        Self::new(segments, SegmentSelection::Single(selected))
+    }
+
+    /// Any number of segments selected, one flag per segment
+    pub fn multi(segments: Vec<Segment>, selected: &'a mut [bool]) -> Self {
+        Self::new(segments, SegmentSelection::Multi(selected))
+    }
+
+    /// Any number of segments selected, one bit per segment
+    pub fn multi_bits(segments: Vec<Segment>, selected: &'a mut u64) -> Self {
+        Self::new(segments, SegmentSelection::Bits(selected))
+    }
+
+    /// Density from 0 down to -3, every step makes the button 4dp lower
+    pub fn with_density(self, density: i32) -> Self {
+        Self {
+            density: density.clamp(-3, 0),
+            ..self
+        }
+    }
+
+    pub fn with_disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+
+    pub fn with_style(self, style: MaterialSegmentedButtonStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this button with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+}
+
+impl Widget for MaterialSegmentedButton<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            segments,
+            mut selection,
+            density,
+            style,
+            scheme,
+            disable,
+        } = self;
+
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| MaterialSegmentedButtonStyle::normal(&tokens));
+        let height = style.height + style.density_step * density as f32;
+
+        // 排版所有标签，各段等宽
+        let galleys: Vec<_> = segments
+            .iter()
+            .map(|segment| {
+                segment.label.clone().map(|label| {
+                    let text_format = style.label_text.text_format(ui.ctx(), Color32::PLACEHOLDER);
+                    ui.fonts(|f| f.layout_job(LayoutJob::single_section(label, text_format)))
+                })
+            })
+            .collect();
+        let segment_width = galleys
+            .iter()
+            .map(|galley| {
+                let label = galley
+                    .as_ref()
+                    .map_or(0.0, |g| g.size().x + style.between_icon_label_space);
+                style.padding_x * 2.0 + style.icon_size + label
+            })
+            .fold(style.min_segment_width, f32::max);
+
+        let count = segments.len().max(1);
+        let (rect, mut response) =
+            ui.allocate_exact_size(vec2(segment_width * count as f32, height), Sense::hover());
+        let outer_rounding = style.shape.corner_radius(&tokens.shapes, rect.size());
+
+        for (index, (segment, galley)) in segments.iter().zip(galleys).enumerate() {
+            let segment_rect = Rect::from_min_size(
+                pos2(rect.left() + segment_width * index as f32, rect.top()),
+                vec2(segment_width, height),
+            );
+            let segment_disabled = disable || segment.disable;
+            let sense = match segment_disabled {
+                true => Sense::hover(),
+                false => Sense::click(),
+            };
+            let segment_response = ui.interact(segment_rect, response.id.with(index), sense);
+
+            // 切换选中状态
+            if !segment_disabled && segment_response.clicked() && selection.toggle(index) {
+                response.mark_changed();
+            }
+            let selected = selection.is_selected(index);
+            let state = InteractionState::from_response(&segment_response, segment_disabled);
+            segment_response.widget_info(|| {
+                let label = match (&segment.label, segment.icon) {
+                    (Some(label), _) => label.clone(),
+                    (None, Some(icon)) => icon.accessible_name(),
+                    (None, None) => String::new(),
+                };
+                WidgetInfo::selected(
+                    WidgetType::SelectableLabel,
+                    !segment_disabled,
+                    selected,
+                    label,
+                )
+            });
+
+            if !ui.is_rect_visible(segment_rect) {
+                continue;
+            }
+            let painter = ui.painter();
+
+            // 首尾段只圆化外侧的角
+            let shape = match (index == 0, index + 1 == segments.len()) {
+                (true, true) => style.shape,
+                (true, false) => MaterialShape::left(style.shape.nw),
+                (false, true) => MaterialShape::right(style.shape.ne),
+                (false, false) => MaterialShape::all(CornerSize::None),
+            };
+            let rounding = shape.corner_radius(&tokens.shapes, rect.size());
+
+            let content_color = if segment_disabled {
+                style
+                    .disabled_label_color
+                    .linear_multiply(style.disabled_label_opacity)
+            } else if selected {
+                style.selected_label_color
+            } else {
+                style.label_color
+            };
+            if selected {
+                painter.rect_filled(segment_rect, rounding, style.selected_container_color);
+            }
+
+            StateLayer::new(&segment_response, content_color)
+                .with_state(match state {
+                    InteractionState::Pressed => InteractionState::Hovered,
+                    state => state,
+                })
+                .with_opacity(style.state_layer_opacity)
+                .paint(painter, segment_rect, rounding);
+            Ripple::new(&segment_response, content_color)
+                .with_opacity(style.state_layer_opacity.pressed)
+                .paint(painter, segment_rect, rounding);
+
+            // 选中时在图标位置绘制勾选标记，只有图标的段保留图标
+            let check = tokens.motion.animate_bool(
+                ui.ctx(),
+                segment_response.id.with("check"),
+                selected,
+                MotionSpec::new(MotionDuration::Short4, MotionEasing::EmphasizedDecelerate),
+            );
+            let (icon, progress) = match (&galley, segment.icon) {
+                (Some(_), _) if check > 0.0 => (Some(Icon::Vector(VectorIcon::Check)), check),
+                (_, icon) => (icon, 1.0),
+            };
+            let icon_width = match icon {
+                Some(_) => style.icon_size,
+                None => 0.0,
+            };
+            let label_width = galley.as_ref().map_or(0.0, |g| g.size().x);
+            let gap = match (icon, &galley) {
+                (Some(_), Some(_)) => style.between_icon_label_space,
+                _ => 0.0,
+            };
+            let content_left = segment_rect.center().x - (icon_width + gap + label_width) / 2.0;
+
+            if let Some(icon) = icon {
+                let center = pos2(content_left + icon_width / 2.0, segment_rect.center().y);
+                icon.paint_progress(painter, center, style.icon_size, content_color, progress);
+            }
+            if let Some(galley) = galley {
+                let text_pos = pos2(
+                    content_left + icon_width + gap,
+                    segment_rect.center().y - galley.size().y / 2.0,
+                );
+                painter.galley(text_pos, galley, content_color);
+            }
+
+            response = response.union(segment_response);
+        }
+
+        // 绘制外轮廓与分隔线
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter();
+            let outline_color = if disable {
+                style
+                    .disabled_outline_color
+                    .linear_multiply(style.disabled_outline_opacity)
+            } else {
+                style.outline_color
+            };
+            let stroke = Stroke::new(style.outline_width, outline_color);
+            painter.rect_stroke(rect, outer_rounding, stroke, StrokeKind::Inside);
+            for index in 1..segments.len() {
+                let x = rect.left() + segment_width * index as f32;
+                painter.vline(x, rect.y_range(), stroke);
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,175 @@
+//! Material Design state layers
+//!
+//! See: [material design document](https://m3.material.io/foundations/interaction/states/state-layers)
+
+use egui::{Color32, Context, CornerRadius, Id, Painter, Pos2, Rect, Response};
+
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::tokens::MaterialTokens;
+
+/// Opacity of disabled containers, applied to the on surface color
+pub const DISABLED_CONTAINER_OPACITY: f32 = 0.12;
+/// Opacity of disabled labels, icons and outlines
+pub const DISABLED_CONTENT_OPACITY: f32 = 0.38;
+
+/// Motion of the state layer fading between states
+const STATE_LAYER_MOTION: MotionSpec =
+    MotionSpec::new(MotionDuration::Short3, MotionEasing::Linear);
+
+/// Interaction state of a component
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InteractionState {
+    #[default]
+    Enabled,
+    Hovered,
+    Focused,
+    Pressed,
+    Dragged,
+    Disabled,
+}
+
+impl InteractionState {
+    /// State of a widget from its response
+    ///
+    /// Dragged wins over pressed, pressed over focused and focused over hovered.
+    pub fn from_response(response: &Response, disabled: bool) -> Self {
+        if disabled {
+            InteractionState::Disabled
+        } else if response.dragged() {
+            InteractionState::Dragged
+        } else if response.is_pointer_button_down_on() {
+            InteractionState::Pressed
+        } else if response.has_focus() {
+            InteractionState::Focused
+        } else if response.hovered() {
+            InteractionState::Hovered
+        } else {
+            InteractionState::Enabled
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        *self == InteractionState::Disabled
+    }
+}
+
+/// Opacity of the state layer in every interaction state
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateLayerOpacity {
+    pub hovered: f32,
+    pub focused: f32,
+    pub pressed: f32,
+    pub dragged: f32,
+}
+
+impl Default for StateLayerOpacity {
+    /// Opacities from the spec: 8% hovered, 10% focused and pressed, 16% dragged
+    fn default() -> Self {
+        Self {
+            hovered: 0.08,
+            focused: 0.10,
+            pressed: 0.10,
+            dragged: 0.16,
+        }
+    }
+}
+
+impl StateLayerOpacity {
+    /// Opacity in a state, enabled and disabled components have no state layer
+    pub fn get(&self, state: InteractionState) -> f32 {
+        match state {
+            InteractionState::Enabled | InteractionState::Disabled => 0.0,
+            InteractionState::Hovered => self.hovered,
+            InteractionState::Focused => self.focused,
+            InteractionState::Pressed => self.pressed,
+            InteractionState::Dragged => self.dragged,
+        }
+    }
+}
+
+/// The translucent overlay showing the interaction state of a component
+///
+/// Paint it right after the container and before the content, the opacity
+/// fades between states so it works over any container color and shape.
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let tokens = MaterialTokens::load(ui.ctx());
+/// let (rect, response) = ui.allocate_exact_size(egui::vec2(120.0, 40.0), egui::Sense::click());
+/// ui.painter().rect_filled(rect, 20.0, tokens.scheme.primary());
+/// StateLayer::new(&response, tokens.scheme.on_primary()).paint(ui.painter(), rect, 20.0);
+/// # });
+/// ```
+pub struct StateLayer {
+    id: Id,
+    state: InteractionState,
+    color: Color32,
+    opacity: StateLayerOpacity,
+}
+
+impl StateLayer {
+    /// State layer of a widget in the given color, usually the "on" color of its container
+    pub fn new(response: &Response, color: Color32) -> Self {
+        Self {
+            id: response.id,
+            state: InteractionState::from_response(response, false),
+            color,
+            opacity: StateLayerOpacity::default(),
+        }
+    }
+
+    /// State layer with an explicit state, e.g. for a part of a widget
+    pub fn with_state(self, state: InteractionState) -> Self {
+        Self { state, ..self }
+    }
+
+    pub fn with_disabled(self, disabled: bool) -> Self {
+        match disabled {
+            true => self.with_state(InteractionState::Disabled),
+            false => self,
+        }
+    }
+
+    pub fn with_color(self, color: Color32) -> Self {
+        Self { color, ..self }
+    }
+
+    pub fn with_opacity(self, opacity: StateLayerOpacity) -> Self {
+        Self { opacity, ..self }
+    }
+
+    pub fn state(&self) -> InteractionState {
+        self.state
+    }
+
+    /// Layer color of this pass, with the opacity fading toward the current state
+    pub fn color(&self, ctx: &Context) -> Color32 {
+        let opacity = MaterialTokens::load(ctx).motion.animate_value(
+            ctx,
+            self.id.with("state_layer"),
+            self.opacity.get(self.state),
+            STATE_LAYER_MOTION,
+        );
+        self.color.linear_multiply(opacity)
+    }
+
+    /// Paint the layer over a rounded rect container
+    pub fn paint(&self, painter: &Painter, rect: Rect, corner_radius: impl Into<CornerRadius>) {
+        let color = self.color(painter.ctx());
+        if color != Color32::TRANSPARENT {
+            painter.rect_filled(rect, corner_radius, color);
+        }
+    }
+
+    /// Paint the layer over a circular container
+    pub fn paint_circle(&self, painter: &Painter, center: Pos2, radius: f32) {
+        let color = self.color(painter.ctx());
+        if color != Color32::TRANSPARENT {
+            painter.circle_filled(center, radius, color);
+        }
+    }
+}
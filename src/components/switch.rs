@@ -0,0 +1,467 @@
+//! Material Design switch
+
+use egui::{
+    Color32, CornerRadius, Rect, Sense, Stroke, StrokeKind, Vec2, Widget, WidgetInfo, WidgetType,
+    pos2, text::LayoutJob, vec2,
+};
+use std::sync::Arc;
+
+use super::ripple::Ripple;
+use super::state_layer::*;
+use crate::color::prelude::*;
+use crate::icon::prelude::{Icon, VectorIcon};
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::shape::{CornerSize, MaterialShape};
+use crate::tokens::MaterialTokens;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
+
+/// Style for Material Design switch
+///
+/// See: [material design document](https://m3.material.io/components/switch/specs)
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialSwitchStyle {
+    pub track_width: f32,
+    pub track_height: f32,
+    pub track_shape: MaterialShape,
+    pub track_outline_width: f32,
+    /// Height of the clickable area around the track
+    pub target_height: f32,
+    pub handle_size: f32,
+    /// Size of the unselected handle when it shows an icon
+    pub icon_handle_size: f32,
+    pub selected_handle_size: f32,
+    pub pressed_handle_size: f32,
+    pub icon_size: f32,
+    pub layer_size: f32,
+    pub selected_track_color: Color32,
+    pub selected_handle_color: Color32,
+    pub hovered_selected_handle_color: Color32,
+    pub selected_icon_color: Color32,
+    pub selected_layer_color: Color32,
+    pub unselected_track_color: Color32,
+    pub unselected_outline_color: Color32,
+    pub unselected_handle_color: Color32,
+    pub hovered_unselected_handle_color: Color32,
+    pub unselected_icon_color: Color32,
+    pub unselected_layer_color: Color32,
+    pub disabled_selected_track_color: Color32,
+    pub disabled_selected_handle_color: Color32,
+    pub disabled_selected_icon_color: Color32,
+    pub disabled_unselected_track_color: Color32,
+    pub disabled_unselected_outline_color: Color32,
+    pub disabled_unselected_handle_color: Color32,
+    pub disabled_unselected_icon_color: Color32,
+    pub disabled_track_opacity: f32,
+    pub disabled_unselected_handle_opacity: f32,
+    pub disabled_icon_opacity: f32,
+    /// Opacity of the state layer when hovered, focused or pressed
+    pub state_layer_opacity: StateLayerOpacity,
+    /// Space between the track and the label
+    pub between_track_label_space: f32,
+    pub label_text: TypeStyle,
+    pub label_color: Color32,
+    pub disabled_label_color: Color32,
+    pub disabled_label_opacity: f32,
+}
+
+impl MaterialSwitchStyle {
+    pub fn normal(tokens: &MaterialTokens) -> Self {
+        Self::colored(tokens, &ColorRole::Primary)
+    }
+
+    /// Switch selected in the given color role
+    pub fn colored(tokens: &MaterialTokens, role: &ColorRole) -> Self {
+        let MaterialTokens {
+            scheme, typography, ..
+        } = tokens;
+        let roles = tokens.roles(role);
+        Self {
+            track_width: 52.0,
+            track_height: 32.0,
+            track_shape: MaterialShape::all(CornerSize::Full),
+            track_outline_width: 2.0,
+            target_height: 48.0,
+            handle_size: 16.0,
+            icon_handle_size: 24.0,
+            selected_handle_size: 24.0,
+            pressed_handle_size: 28.0,
+            icon_size: 16.0,
+            layer_size: 40.0,
+            selected_track_color: roles.color,
+            selected_handle_color: roles.on_color,
+            hovered_selected_handle_color: roles.color_container,
+            selected_icon_color: roles.on_color_container,
+            selected_layer_color: roles.color,
+            unselected_track_color: scheme.surface_container_highest(),
+            unselected_outline_color: scheme.outline(),
+            unselected_handle_color: scheme.outline(),
+            hovered_unselected_handle_color: scheme.on_surface_variant(),
+            unselected_icon_color: scheme.surface_container_highest(),
+            unselected_layer_color: scheme.on_surface(),
+            disabled_selected_track_color: scheme.on_surface(),
+            disabled_selected_handle_color: scheme.surface(),
+            disabled_selected_icon_color: scheme.on_surface(),
+            disabled_unselected_track_color: scheme.surface_container_highest(),
+            disabled_unselected_outline_color: scheme.on_surface(),
+            disabled_unselected_handle_color: scheme.on_surface(),
+            disabled_unselected_icon_color: scheme.surface_container_highest(),
+            disabled_track_opacity: DISABLED_CONTAINER_OPACITY,
+            disabled_unselected_handle_opacity: DISABLED_CONTENT_OPACITY,
+            disabled_icon_opacity: DISABLED_CONTENT_OPACITY,
+            state_layer_opacity: StateLayerOpacity::default(),
+            between_track_label_space: 12.0,
+            label_text: typography.body_large.clone(),
+            label_color: scheme.on_surface(),
+            disabled_label_color: scheme.on_surface(),
+            disabled_label_opacity: DISABLED_CONTENT_OPACITY,
+        }
+    }
+}
+
+/// Material Design Switch
+///
+/// Click the switch or drag its handle across the track to toggle it.
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let mut wifi = true;
+/// let mut bluetooth = false;
+/// ui.add(MaterialSwitch::new(&mut wifi).with_label("Wi-Fi"));
+/// ui.add(MaterialSwitch::new(&mut bluetooth).with_icons());
+/// # });
+/// ```
+pub struct MaterialSwitch<'a> {
+    selected: &'a mut bool,
+    label: Option<String>,
+    selected_icon: Option<Icon>,
+    unselected_icon: Option<Icon>,
+    disable: bool,
+    color: Option<ColorRole>,
+    style: Option<MaterialSwitchStyle>,
+    scheme: Option<Arc<Scheme>>,
+}
+
+impl<'a> MaterialSwitch<'a> {
+    pub fn new(selected: &'a mut bool) -> Self {
+        Self {
+            selected,
+            label: None,
+            selected_icon: None,
+            unselected_icon: None,
+            disable: false,
+            color: None,
+            style: None,
+            scheme: None,
+        }
+    }
+
+    /// Text next to the track, also the name screen readers announce
+    ///
+    /// Clicking it toggles the switch too.
+    pub fn with_label(self, label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+
+    /// Show a check in the selected handle and a close icon in the unselected one
+    pub fn with_icons(self) -> Self {
+        Self {
+            selected_icon: Some(Icon::Vector(VectorIcon::Check)),
+            unselected_icon: Some(Icon::Vector(VectorIcon::Close)),
+            ..self
+        }
+    }
+
+    /// Icon in the handle while selected
+    pub fn with_selected_icon(self, icon: impl Into<Icon>) -> Self {
+        Self {
+            selected_icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    /// Icon in the handle while unselected, the handle grows to fit it
+    pub fn with_unselected_icon(self, icon: impl Into<Icon>) -> Self {
+        Self {
+            unselected_icon: Some(icon.into()),
+            ..self
+        }
+    }
+
+    pub fn with_disable(self, disable: bool) -> Self {
+        Self { disable, ..self }
+    }
+
+    /// Select the switch in a color role
+    pub fn with_color(self, color: ColorRole) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    pub fn with_style(self, style: MaterialSwitchStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this switch with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+}
+
+impl Widget for MaterialSwitch<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let Self {
+            selected,
+            label,
+            selected_icon,
+            unselected_icon,
+            disable,
+            color,
+            style,
+            scheme,
+        } = self;
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let style = style.unwrap_or_else(|| match color {
+            Some(color) => MaterialSwitchStyle::colored(&tokens, &color),
+            None => MaterialSwitchStyle::normal(&tokens),
+        });
+
+        let sense = if disable {
+            Sense::hover()
+        } else {
+            Sense::click_and_drag()
+        };
+
+        // 排版标签
+        let accessible_label = label.clone().unwrap_or_default();
+        let galley = label.map(|label| {
+            let text_format = style.label_text.text_format(ui.ctx(), Color32::PLACEHOLDER);
+            ui.fonts(|f| f.layout_job(LayoutJob::single_section(label, text_format)))
+        });
+
+        // 整行都可点击，轨道上下保留触控区域
+        let target_height = style.target_height.max(style.track_height);
+        let row_size = match &galley {
+            Some(galley) => vec2(
+                style.track_width + style.between_track_label_space + galley.size().x,
+                target_height.max(galley.size().y),
+            ),
+            None => vec2(style.track_width, target_height),
+        };
+        let (row_rect, mut response) = ui.allocate_exact_size(row_size, sense);
+        let rect = Rect::from_min_size(
+            row_rect.left_center() - vec2(0.0, target_height / 2.0),
+            vec2(style.track_width, target_height),
+        );
+        let track_rect =
+            Rect::from_center_size(rect.center(), vec2(style.track_width, style.track_height));
+        // 滑块中心的移动范围
+        let travel = (track_rect.width() - track_rect.height()).max(f32::EPSILON);
+
+        let state = InteractionState::from_response(&response, disable);
+        let is_pressed = matches!(state, InteractionState::Pressed | InteractionState::Dragged);
+        let is_hovering = matches!(state, InteractionState::Hovered | InteractionState::Focused);
+
+        // 点击切换，拖动时滑块跟随指针，松开后按所在的一侧决定状态
+        let handle_id = response.id.with("handle");
+        let drag_id = response.id.with("drag");
+        let travel_spec = MotionSpec::new(MotionDuration::Medium1, MotionEasing::Standard);
+        let mut drag_position = None;
+        if !disable {
+            if response.drag_started() {
+                let start =
+                    (tokens.motion).animate_bool(ui.ctx(), handle_id, *selected, travel_spec);
+                ui.data_mut(|d| d.insert_temp(drag_id, start));
+            }
+            let dragged = ui.data(|d| d.get_temp::<f32>(drag_id)).unwrap_or(0.0);
+            if response.clicked() {
+                *selected = !*selected;
+                response.mark_changed();
+            } else if response.drag_stopped() {
+                if (dragged > 0.5) != *selected {
+                    *selected = dragged > 0.5;
+                    response.mark_changed();
+                }
+            } else if response.dragged() {
+                let position = (dragged + response.drag_delta().x / travel).clamp(0.0, 1.0);
+                ui.data_mut(|d| d.insert_temp(drag_id, position));
+                tokens.motion.set_value(ui.ctx(), handle_id, position);
+                drag_position = Some(position);
+            }
+        }
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Checkbox,
+                !disable,
+                *selected,
+                accessible_label.as_str(),
+            )
+        });
+
+        // 滑块位置，0 为未选中一侧，1 为选中一侧
+        let position = drag_position.unwrap_or_else(|| {
+            tokens
+                .motion
+                .animate_bool(ui.ctx(), handle_id, *selected, travel_spec)
+        });
+        let on = position > 0.5;
+
+        // 滑块尺寸：未选中 16，带图标或选中 24，按下 28
+        let icon = match on {
+            true => selected_icon,
+            false => unselected_icon,
+        };
+        let handle_size = if is_pressed && !disable {
+            style.pressed_handle_size
+        } else if on {
+            style.selected_handle_size
+        } else if icon.is_some() {
+            style.icon_handle_size
+        } else {
+            style.handle_size
+        };
+        let handle_size = tokens.motion.animate_value(
+            ui.ctx(),
+            response.id.with("handle_size"),
+            handle_size,
+            MotionSpec::new(MotionDuration::Short3, MotionEasing::Standard),
+        );
+
+        // 计算颜色，按滑块位置在未选中与选中配色之间过渡
+        let colors = |selected: bool| {
+            if disable {
+                let track = match selected {
+                    true => style.disabled_selected_track_color,
+                    false => style.disabled_unselected_track_color,
+                }
+                .linear_multiply(style.disabled_track_opacity);
+                let outline = match selected {
+                    true => Color32::TRANSPARENT,
+                    false => style
+                        .disabled_unselected_outline_color
+                        .linear_multiply(style.disabled_track_opacity),
+                };
+                let handle = match selected {
+                    true => style.disabled_selected_handle_color,
+                    false => style
+                        .disabled_unselected_handle_color
+                        .linear_multiply(style.disabled_unselected_handle_opacity),
+                };
+                let icon = match selected {
+                    true => style.disabled_selected_icon_color,
+                    false => style.disabled_unselected_icon_color,
+                }
+                .linear_multiply(style.disabled_icon_opacity);
+                (track, outline, handle, icon, Color32::TRANSPARENT)
+            } else if selected {
+                let handle = match is_pressed || is_hovering {
+                    true => style.hovered_selected_handle_color,
+                    false => style.selected_handle_color,
+                };
+                (
+                    style.selected_track_color,
+                    style.selected_track_color,
+                    handle,
+                    style.selected_icon_color,
+                    style.selected_layer_color,
+                )
+            } else {
+                let handle = match is_pressed || is_hovering {
+                    true => style.hovered_unselected_handle_color,
+                    false => style.unselected_handle_color,
+                };
+                (
+                    style.unselected_track_color,
+                    style.unselected_outline_color,
+                    handle,
+                    style.unselected_icon_color,
+                    style.unselected_layer_color,
+                )
+            }
+        };
+        let (track_color, outline_color, handle_color, icon_color, layer_color) = {
+            let (from, to) = (colors(false), colors(true));
+            let mix = |a: Color32, b: Color32| a.lerp_to_gamma(b, position);
+            (
+                mix(from.0, to.0),
+                mix(from.1, to.1),
+                mix(from.2, to.2),
+                mix(from.3, to.3),
+                mix(from.4, to.4),
+            )
+        };
+
+        // 绘制
+        if ui.is_rect_visible(row_rect) {
+            let painter = ui.painter();
+            let rounding = style
+                .track_shape
+                .corner_radius(&tokens.shapes, track_rect.size());
+
+            // 绘制轨道，未选中时带边框
+            painter.rect(
+                track_rect,
+                rounding,
+                track_color,
+                Stroke::new(style.track_outline_width, outline_color),
+                StrokeKind::Inside,
+            );
+
+            // 绘制滑块周围的 state layer 与水波纹
+            let handle_center = pos2(
+                track_rect.left() + track_rect.height() / 2.0 + travel * position,
+                track_rect.center().y,
+            );
+            let layer_rect = Rect::from_center_size(handle_center, Vec2::splat(style.layer_size));
+            let layer_rounding = CornerRadius::from(style.layer_size / 2.0);
+            let layer_state = match state {
+                InteractionState::Pressed => InteractionState::Hovered,
+                state => state,
+            };
+            StateLayer::new(&response, layer_color)
+                .with_state(layer_state)
+                .with_opacity(style.state_layer_opacity)
+                .paint(painter, layer_rect, layer_rounding);
+            Ripple::new(&response, layer_color)
+                .with_opacity(style.state_layer_opacity.pressed)
+                .paint(painter, layer_rect, layer_rounding);
+
+            // 绘制滑块与其中的图标
+            painter.circle_filled(handle_center, handle_size / 2.0, handle_color);
+            if let Some(icon) = icon {
+                icon.paint(painter, handle_center, style.icon_size, icon_color);
+            }
+
+            // 绘制标签
+            if let Some(galley) = galley {
+                let label_color = match disable {
+                    true => style
+                        .disabled_label_color
+                        .linear_multiply(style.disabled_label_opacity),
+                    false => style.label_color,
+                };
+                let pos = pos2(
+                    rect.right() + style.between_track_label_space,
+                    row_rect.center().y - galley.size().y / 2.0,
+                );
+                painter.galley(pos, galley, label_color);
+            }
+        }
+
+        response
+    }
+}
@@ -1,5 +1,6 @@
 pub mod output;
 pub mod state;
+#[allow(clippy::module_inception)]
 pub mod text_field;
 pub mod style;
 
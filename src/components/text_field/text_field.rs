@@ -8,24 +8,32 @@ use egui::text::{CCursor, CCursorRange, LayoutJob};
 use egui::text_selection::text_cursor_state::cursor_rect;
 use egui::text_selection::visuals::paint_text_selection;
 use egui::{
-    Align2, Context, CornerRadius, CursorIcon, Event, EventFilter, FontId, Galley, Id, ImeEvent,
-    Key, KeyboardShortcut, Margin, Modifiers, NumExt, Rect, Sense, Stroke, TextBuffer, Ui, Widget,
-    WidgetText, pos2, response, text_selection, vec2,
+    Align2, Context, CursorIcon, Event, EventFilter, Galley, Id, ImeEvent, Key, KeyboardShortcut,
+    Margin, Modifiers, NumExt, Rect, Sense, Stroke, TextBuffer, Ui, Widget, WidgetText, pos2,
+    response, text_selection, vec2,
 };
 use material_colors::scheme::Scheme;
 
 use super::MaterialTextFieldStyle;
 use super::{output::TextEditOutput, state::TextEditState};
+use crate::motion::{MotionDuration, MotionEasing, MotionSpec};
+use crate::tokens::MaterialTokens;
+use crate::utils::clone_scheme;
 
 /// Material Design Text Field
 pub struct MaterialTextField<'t> {
     pub text: &'t mut String,
     pub label: Option<String>,
-    pub style: MaterialTextFieldStyle,
+    /// Explicit style, built from the context tokens when `None`
+    pub style: Option<MaterialTextFieldStyle>,
+    /// Scheme used instead of the one stored in the context
+    pub scheme: Option<Arc<Scheme>>,
     pub disable: bool,
     pub error: bool,
     pub multiline: bool,
     pub password: bool,
+    pub width: Option<f32>,
+    pub height: Option<f32>,
 }
 
 impl MaterialTextField<'_> {
@@ -39,27 +47,25 @@ impl MaterialTextField<'_> {
 }
 
 impl<'t> MaterialTextField<'t> {
-    pub fn singleline(text: &'t mut String, scheme: &Scheme) -> Self {
+    pub fn singleline(text: &'t mut String) -> Self {
         Self {
             text,
             label: None,
-            style: MaterialTextFieldStyle::normal(scheme),
+            style: None,
+            scheme: None,
             disable: false,
             error: false,
             multiline: false,
             password: false,
+            width: None,
+            height: None,
         }
     }
 
-    pub fn multiline(text: &'t mut String, scheme: &Scheme) -> Self {
+    pub fn multiline(text: &'t mut String) -> Self {
         Self {
-            text,
-            label: None,
-            style: MaterialTextFieldStyle::normal(scheme),
-            disable: false,
-            error: false,
             multiline: true,
-            password: false,
+            ..Self::singleline(text)
         }
     }
 
@@ -67,22 +73,31 @@ impl<'t> MaterialTextField<'t> {
         Self { disable, ..self }
     }
 
+    pub fn with_style(self, style: MaterialTextFieldStyle) -> Self {
+        Self {
+            style: Some(style),
+            ..self
+        }
+    }
+
+    /// Style this text field with the given scheme instead of the active theme
+    pub fn with_scheme(self, scheme: &Scheme) -> Self {
+        Self {
+            scheme: Some(Arc::new(clone_scheme(scheme))),
+            ..self
+        }
+    }
+
     pub fn with_width(self, width: f32) -> Self {
         Self {
-            style: MaterialTextFieldStyle {
-                width: Some(width),
-                ..self.style
-            },
+            width: Some(width),
             ..self
         }
     }
 
     pub fn with_height(self, height: f32) -> Self {
         Self {
-            style: MaterialTextFieldStyle {
-                height: Some(height),
-                ..self.style
-            },
+            height: Some(height),
             ..self
         }
     }
@@ -111,12 +126,25 @@ impl MaterialTextField<'_> {
             text,
             label,
             style,
+            scheme,
             disable,
             error,
             multiline,
             password,
+            width,
+            height,
         } = self;
 
+        // 未指定样式时从上下文中的主题生成
+        let tokens = MaterialTokens::load_with(ui.ctx(), scheme);
+        let mut style = style.unwrap_or_else(|| MaterialTextFieldStyle::normal(&tokens));
+        if width.is_some() {
+            style.width = width;
+        }
+        if height.is_some() {
+            style.height = height;
+        }
+
         // 对齐,是否换行方式
         let (_align, clip_text) = if multiline {
             (Align2::LEFT_TOP, false)
@@ -157,17 +185,15 @@ impl MaterialTextField<'_> {
             )
         };
 
-        let font_id = FontId::monospace(style.input_font_size);
+        let font_id = style.input_text.resolve(ui.ctx());
         let row_height = ui.fonts(|f| f.row_height(&font_id));
 
         // 计算大小
         let desired_width = style.width.unwrap_or_else(|| ui.available_width());
-        let desired_height = style.height.unwrap_or_else(|| {
-            if multiline {
-                style.input_font_line_height * 4.0 + 16.0 // 默认4行高度
-            } else {
-                style.input_font_line_height + 16.0 // 单行高度
-            }
+        let desired_height = style.height.unwrap_or(if multiline {
+            style.input_text.line_height * 4.0 + 16.0 // 默认4行高度
+        } else {
+            style.input_text.line_height + 16.0 // 单行高度
         });
 
         // 排列
@@ -214,40 +240,38 @@ impl MaterialTextField<'_> {
         // 绘制器
         let painter = ui.painter_at(rect.expand(1.0));
         // 交互逻辑
-        if !disable {
-            if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-                // 如果悬停且文本可变，设置可变文本标志
-                if response.hovered() && text.is_mutable() {
-                    ui.output_mut(|o| o.mutable_text_under_cursor = true);
-                }
-                // 计算指针位置对应的光标位置
-                let cursor_at_pointer =
-                    galley.cursor_from_pos(pointer_pos - rect.min + state.text_offset);
-                // 如果启用了光标预览且指针在移动，显示光标预览
-                if ui.visuals().text_cursor.preview
-                    && response.hovered()
-                    && ui.input(|i| i.pointer.is_moving())
-                {
-                    // text cursor preview:
-                    let cursor_rect = TSTransform::from_translation(rect.min.to_vec2())
-                        * cursor_rect(&galley, &cursor_at_pointer, row_height);
-                    text_selection::visuals::paint_cursor_end(&painter, ui.visuals(), cursor_rect);
-                }
-                // 检查是否正在拖动
-                let is_being_dragged = ui.ctx().is_being_dragged(response.id);
-                // 处理光标交互
-                let did_interact = state.cursor.pointer_interaction(
-                    ui,
-                    &response,
-                    cursor_at_pointer,
-                    &galley,
-                    is_being_dragged,
-                );
-                // 如果有交互或点击，请求焦点并记录交互时间
-                if did_interact || response.clicked() {
-                    ui.memory_mut(|mem| mem.request_focus(response.id));
-                    state.last_interaction_time = ui.ctx().input(|i| i.time);
-                }
+        if !disable && let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
+            // 如果悬停且文本可变，设置可变文本标志
+            if response.hovered() && text.is_mutable() {
+                ui.output_mut(|o| o.mutable_text_under_cursor = true);
+            }
+            // 计算指针位置对应的光标位置
+            let cursor_at_pointer =
+                galley.cursor_from_pos(pointer_pos - rect.min + state.text_offset);
+            // 如果启用了光标预览且指针在移动，显示光标预览
+            if ui.visuals().text_cursor.preview
+                && response.hovered()
+                && ui.input(|i| i.pointer.is_moving())
+            {
+                // text cursor preview:
+                let cursor_rect = TSTransform::from_translation(rect.min.to_vec2())
+                    * cursor_rect(&galley, &cursor_at_pointer, row_height);
+                text_selection::visuals::paint_cursor_end(&painter, ui.visuals(), cursor_rect);
+            }
+            // 检查是否正在拖动
+            let is_being_dragged = ui.ctx().is_being_dragged(response.id);
+            // 处理光标交互
+            let did_interact = state.cursor.pointer_interaction(
+                ui,
+                &response,
+                cursor_at_pointer,
+                &galley,
+                is_being_dragged,
+            );
+            // 如果有交互或点击，请求焦点并记录交互时间
+            if did_interact || response.clicked() {
+                ui.memory_mut(|mem| mem.request_focus(response.id));
+                state.last_interaction_time = ui.ctx().input(|i| i.time);
             }
         }
 
@@ -358,11 +382,10 @@ impl MaterialTextField<'_> {
         // 矩形可见时进行绘制
         if ui.is_rect_visible(rect) {
             // 绘制容器背景
-            painter.rect_filled(
-                rect,
-                CornerRadius::same(style.container_rounding as u8),
-                container_color,
-            );
+            let corner_radius = style
+                .container_shape
+                .corner_radius(&tokens.shapes, rect.size());
+            painter.rect_filled(rect, corner_radius, container_color);
             // 绘制边框
             let has_focus = response.has_focus();
             let (final_outline_color, final_outline_width) = if has_focus && !disable {
@@ -372,44 +395,51 @@ impl MaterialTextField<'_> {
             };
             painter.rect_stroke(
                 rect,
-                CornerRadius::same(style.container_rounding as u8),
+                corner_radius,
                 Stroke::new(final_outline_width, final_outline_color),
                 egui::StrokeKind::Middle,
             );
 
-            // 绘制标签（当文本为空且没有焦点时）
-            if let Some(ref label_text) = label {
-                if text.is_empty() && !has_focus {
-                    let label_font =
-                        egui::FontId::new(style.label_font_size, egui::FontFamily::Monospace);
-                    let label_galley = if multiline {
-                        WidgetText::from(label_text).into_galley(
-                            ui,
-                            Some(egui::TextWrapMode::Wrap),
-                            desired_width,
-                            label_font,
-                        )
-                    } else {
-                        WidgetText::from(label_text).into_galley(
-                            ui,
-                            Some(egui::TextWrapMode::Extend),
-                            f32::INFINITY,
-                            label_font,
-                        )
-                    };
-                    let label_pos = pos2(
-                        inner_rect.min.x,
-                        inner_rect.center().y - label_galley.size().y / 2.0,
-                    );
-                    painter.galley(label_pos, label_galley, label_color);
-                }
+            // 绘制标签（当文本为空且没有焦点时），获得焦点时淡出
+            let label_opacity = tokens.motion.animate_bool(
+                ui.ctx(),
+                id.with("label"),
+                text.is_empty() && !has_focus,
+                MotionSpec::new(MotionDuration::Short2, MotionEasing::Linear),
+            );
+            if let Some(ref label_text) = label
+                && label_opacity > 0.0
+            {
+                let label_font = style.label_text.resolve(ui.ctx());
+                let label_galley = if multiline {
+                    WidgetText::from(label_text).into_galley(
+                        ui,
+                        Some(egui::TextWrapMode::Wrap),
+                        desired_width,
+                        label_font,
+                    )
+                } else {
+                    WidgetText::from(label_text).into_galley(
+                        ui,
+                        Some(egui::TextWrapMode::Extend),
+                        f32::INFINITY,
+                        label_font,
+                    )
+                };
+                let label_pos = pos2(
+                    inner_rect.min.x,
+                    inner_rect.center().y - label_galley.size().y / 2.0,
+                );
+                painter.galley(
+                    label_pos,
+                    label_galley,
+                    label_color.gamma_multiply(label_opacity),
+                );
             }
             // 如果有焦点且有选择范围，绘制选择高亮
-            if has_focus {
-                if let Some(cursor_range) = state.cursor.range(&galley) {
-                    // Add text selection rectangles to the galley:
-                    paint_text_selection(&mut galley, ui.visuals(), &cursor_range, None);
-                }
+            if has_focus && let Some(cursor_range) = state.cursor.range(&galley) {
+                // Add text selection rectangles to the galley:
+                paint_text_selection(&mut galley, ui.visuals(), &cursor_range, None);
             }
             // 如果不裁剪文本且编辑导致尺寸变化，分配额外空间
             if !clip_text {
@@ -428,48 +458,45 @@ impl MaterialTextField<'_> {
             }
 
             // 如果有焦点，绘制光标和处理相关逻辑
-            if has_focus {
-                if let Some(cursor_range) = state.cursor.range(&galley) {
-                    // 计算主光标矩形
-                    let primary_cursor_rect =
-                        cursor_rect(&galley, &cursor_range.primary, row_height)
-                            .translate(galley_pos.to_vec2());
-
-                    // 如果文本更改或选择更改，滚动到光标位置
+            if has_focus && let Some(cursor_range) = state.cursor.range(&galley) {
+                // 计算主光标矩形
+                let primary_cursor_rect = cursor_rect(&galley, &cursor_range.primary, row_height)
+                    .translate(galley_pos.to_vec2());
+
+                // 如果文本更改或选择更改，滚动到光标位置
+                if response.changed() || selection_changed {
+                    ui.scroll_to_rect(primary_cursor_rect + Margin::symmetric(0, 0), None);
+                }
+
+                // 如果文本可变且可交互，绘制闪烁光标
+                if text.is_mutable() && !disable {
+                    let now = ui.ctx().input(|i| i.time);
                     if response.changed() || selection_changed {
-                        ui.scroll_to_rect(primary_cursor_rect + Margin::symmetric(0, 0), None);
+                        state.last_interaction_time = now;
                     }
 
-                    // 如果文本可变且可交互，绘制闪烁光标
-                    if text.is_mutable() && !disable {
-                        let now = ui.ctx().input(|i| i.time);
-                        if response.changed() || selection_changed {
-                            state.last_interaction_time = now;
-                        }
-
-                        // 只有当egui视口有焦点时才显示（和闪烁）光标
-                        let viewport_has_focus = ui.ctx().input(|i| i.focused);
-                        if viewport_has_focus {
-                            text_selection::visuals::paint_text_cursor(
-                                ui,
-                                &painter,
-                                primary_cursor_rect,
-                                now - state.last_interaction_time,
-                            );
-                        }
+                    // 只有当egui视口有焦点时才显示（和闪烁）光标
+                    let viewport_has_focus = ui.ctx().input(|i| i.focused);
+                    if viewport_has_focus {
+                        text_selection::visuals::paint_text_cursor(
+                            ui,
+                            &painter,
+                            primary_cursor_rect,
+                            now - state.last_interaction_time,
+                        );
+                    }
 
-                        // 设置IME输出（屏幕坐标）
-                        let to_global = ui
-                            .ctx()
-                            .layer_transform_to_global(ui.layer_id())
-                            .unwrap_or_default();
-                        ui.ctx().output_mut(|o| {
-                            o.ime = Some(egui::output::IMEOutput {
-                                rect: to_global * rect,
-                                cursor_rect: to_global * primary_cursor_rect,
-                            });
+                    // 设置IME输出（屏幕坐标）
+                    let to_global = ui
+                        .ctx()
+                        .layer_transform_to_global(ui.layer_id())
+                        .unwrap_or_default();
+                    ui.ctx().output_mut(|o| {
+                        o.ime = Some(egui::output::IMEOutput {
+                            rect: to_global * rect,
+                            cursor_rect: to_global * primary_cursor_rect,
                         });
-                    }
+                    });
                 }
             }
         }
@@ -532,9 +559,11 @@ impl MaterialTextField<'_> {
 
 fn mask_if_password(is_password: bool, text: &str) -> String {
     fn mask_password(text: &str) -> String {
-        std::iter::repeat(egui::epaint::text::PASSWORD_REPLACEMENT_CHAR)
-            .take(text.chars().count())
-            .collect::<String>()
+        std::iter::repeat_n(
+            egui::epaint::text::PASSWORD_REPLACEMENT_CHAR,
+            text.chars().count(),
+        )
+        .collect::<String>()
     }
 
     if is_password {
@@ -0,0 +1,230 @@
+//! Material Design elevation
+//!
+//! See: [material design document](https://m3.material.io/styles/elevation/tokens)
+
+use egui::{Color32, CornerRadius, Painter, Rect, Shadow, epaint::RectShape, vec2};
+
+/// Elevation level of a surface
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ElevationLevel {
+    /// 0dp, flat surfaces and filled buttons
+    #[default]
+    Level0,
+    /// 1dp, elevated buttons and cards
+    Level1,
+    /// 3dp, menus and navigation bars
+    Level2,
+    /// 6dp, FABs, dialogs and search bars
+    Level3,
+    /// 8dp
+    Level4,
+    /// 12dp
+    Level5,
+}
+
+impl ElevationLevel {
+    /// Level index from 0 to 5, e.g. for animating between levels
+    pub fn index(&self) -> f32 {
+        *self as u8 as f32
+    }
+
+    /// Elevation in dp
+    pub fn dp(&self) -> f32 {
+        match self {
+            ElevationLevel::Level0 => 0.0,
+            ElevationLevel::Level1 => 1.0,
+            ElevationLevel::Level2 => 3.0,
+            ElevationLevel::Level3 => 6.0,
+            ElevationLevel::Level4 => 8.0,
+            ElevationLevel::Level5 => 12.0,
+        }
+    }
+}
+
+/// A single shadow, its color is the scheme shadow role at `opacity`
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShadowTokens {
+    pub offset_y: f32,
+    pub blur: f32,
+    pub spread: f32,
+    pub opacity: f32,
+}
+
+impl ShadowTokens {
+    const fn new(offset_y: f32, blur: f32, spread: f32, opacity: f32) -> Self {
+        Self {
+            offset_y,
+            blur,
+            spread,
+            opacity,
+        }
+    }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            offset_y: lerp(self.offset_y, other.offset_y),
+            blur: lerp(self.blur, other.blur),
+            spread: lerp(self.spread, other.spread),
+            opacity: lerp(self.opacity, other.opacity),
+        }
+    }
+
+    /// egui shadow in the given shadow color
+    pub fn shadow(&self, color: Color32) -> Shadow {
+        Shadow {
+            offset: [0, self.offset_y.round() as i8],
+            blur: self.blur.round() as u8,
+            spread: self.spread.round() as u8,
+            color: color.linear_multiply(self.opacity),
+        }
+    }
+
+    fn paint(&self, painter: &Painter, rect: Rect, corner_radius: CornerRadius, color: Color32) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+        let rect = rect.translate(vec2(0.0, self.offset_y)).expand(self.spread);
+        let corner_radius = corner_radius + CornerRadius::from(self.spread);
+        let shape = RectShape::filled(rect, corner_radius, color.linear_multiply(self.opacity))
+            .with_blur_width(self.blur);
+        painter.add(shape);
+    }
+}
+
+/// Shadows and surface tint of one elevation level
+///
+/// Every level casts a sharp key shadow and a soft ambient shadow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElevationTokens {
+    pub key_shadow: ShadowTokens,
+    pub ambient_shadow: ShadowTokens,
+    /// Opacity of the surface tint color over the container
+    pub tint_opacity: f32,
+}
+
+impl ElevationTokens {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            key_shadow: self.key_shadow.lerp(&other.key_shadow, t),
+            ambient_shadow: self.ambient_shadow.lerp(&other.ambient_shadow, t),
+            tint_opacity: self.tint_opacity + (other.tint_opacity - self.tint_opacity) * t,
+        }
+    }
+}
+
+/// Material Design elevation levels
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let tokens = MaterialTokens::load(ui.ctx());
+/// // a card at level 1
+/// let (rect, _) = ui.allocate_exact_size(egui::vec2(200.0, 120.0), egui::Sense::hover());
+/// tokens.elevation.paint(ui.painter(), rect, 12.0, ElevationLevel::Level1.index(), tokens.scheme.shadow());
+/// ui.painter().rect_filled(rect, 12.0, tokens.scheme.surface_container_low());
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialElevation {
+    pub level0: ElevationTokens,
+    pub level1: ElevationTokens,
+    pub level2: ElevationTokens,
+    pub level3: ElevationTokens,
+    pub level4: ElevationTokens,
+    pub level5: ElevationTokens,
+}
+
+impl Default for MaterialElevation {
+    fn default() -> Self {
+        let level = |key: ShadowTokens, ambient: ShadowTokens, tint_opacity: f32| ElevationTokens {
+            key_shadow: key,
+            ambient_shadow: ambient,
+            tint_opacity,
+        };
+        let key = |offset_y, blur| ShadowTokens::new(offset_y, blur, 0.0, 0.3);
+        let ambient = |offset_y, blur, spread| ShadowTokens::new(offset_y, blur, spread, 0.15);
+
+        Self {
+            level0: level(
+                ShadowTokens::new(0.0, 0.0, 0.0, 0.0),
+                ShadowTokens::new(0.0, 0.0, 0.0, 0.0),
+                0.0,
+            ),
+            level1: level(key(1.0, 2.0), ambient(1.0, 3.0, 1.0), 0.05),
+            level2: level(key(1.0, 2.0), ambient(2.0, 6.0, 2.0), 0.08),
+            level3: level(key(1.0, 3.0), ambient(4.0, 8.0, 3.0), 0.11),
+            level4: level(key(2.0, 3.0), ambient(6.0, 10.0, 4.0), 0.12),
+            level5: level(key(4.0, 4.0), ambient(8.0, 12.0, 6.0), 0.14),
+        }
+    }
+}
+
+impl MaterialElevation {
+    pub fn get(&self, level: ElevationLevel) -> &ElevationTokens {
+        match level {
+            ElevationLevel::Level0 => &self.level0,
+            ElevationLevel::Level1 => &self.level1,
+            ElevationLevel::Level2 => &self.level2,
+            ElevationLevel::Level3 => &self.level3,
+            ElevationLevel::Level4 => &self.level4,
+            ElevationLevel::Level5 => &self.level5,
+        }
+    }
+
+    /// Tokens at a fractional level index, e.g. `1.5` halfway between level 1 and 2
+    pub fn at(&self, level: f32) -> ElevationTokens {
+        let levels = [
+            &self.level0,
+            &self.level1,
+            &self.level2,
+            &self.level3,
+            &self.level4,
+            &self.level5,
+        ];
+        let level = level.clamp(0.0, 5.0);
+        let lower = level.floor() as usize;
+        let upper = (lower + 1).min(5);
+        levels[lower].lerp(levels[upper], level - lower as f32)
+    }
+
+    /// egui shadow of a level for windows, popups and frames
+    ///
+    /// egui draws a single shadow, so only the ambient shadow is used.
+    pub fn shadow(&self, level: ElevationLevel, color: Color32) -> Shadow {
+        self.get(level).ambient_shadow.shadow(color)
+    }
+
+    /// Container color with the surface tint overlay of a fractional level
+    ///
+    /// See [`Self::at`] for the level.
+    pub fn tinted(&self, level: f32, container: Color32, tint: Color32) -> Color32 {
+        container.lerp_to_gamma(tint, self.at(level).tint_opacity)
+    }
+
+    /// Paint the shadows of a fractional level under a rounded rect surface
+    ///
+    /// Paint it before the surface itself, see [`Self::at`] for the level.
+    pub fn paint(
+        &self,
+        painter: &Painter,
+        rect: Rect,
+        corner_radius: impl Into<CornerRadius>,
+        level: f32,
+        shadow_color: Color32,
+    ) {
+        let tokens = self.at(level);
+        let corner_radius = corner_radius.into();
+        tokens
+            .ambient_shadow
+            .paint(painter, rect, corner_radius, shadow_color);
+        tokens
+            .key_shadow
+            .paint(painter, rect, corner_radius, shadow_color);
+    }
+}
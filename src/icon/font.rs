@@ -0,0 +1,141 @@
+//! Bundled Material Symbols fonts
+
+use std::sync::Arc;
+
+use egui::{
+    Context, FontData, FontDefinitions, FontFamily, Id,
+    epaint::text::{FontInsert, FontPriority, InsertFontFamily},
+};
+
+/// Fill of a Material Symbols icon
+///
+/// The bundled fonts are cuts at weight 400, egui cannot vary the fill and
+/// weight axes of the variable font.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconFill {
+    /// Unfilled glyphs, the default for most components
+    #[default]
+    Unfilled,
+    /// Filled glyphs, e.g. for selected navigation items
+    Filled,
+}
+
+/// Style of the Material Symbols glyphs
+///
+/// Rounded is always bundled, Sharp with the `sharp-icons` feature. The Sharp
+/// font has no filled cut, filled Sharp icons use the filled Rounded glyphs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IconStyle {
+    /// Rounded corners and terminals
+    #[default]
+    Rounded,
+    /// Square corners and terminals
+    #[cfg(feature = "sharp-icons")]
+    Sharp,
+}
+
+/// Bundled icon font used for a style and fill
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum IconFont {
+    Rounded,
+    RoundedFilled,
+    #[cfg(feature = "sharp-icons")]
+    Sharp,
+}
+
+impl IconFont {
+    const ALL: &[IconFont] = &[
+        IconFont::Rounded,
+        IconFont::RoundedFilled,
+        #[cfg(feature = "sharp-icons")]
+        IconFont::Sharp,
+    ];
+
+    fn new(style: IconStyle, fill: IconFill) -> Self {
+        match (style, fill) {
+            (IconStyle::Rounded, IconFill::Unfilled) => IconFont::Rounded,
+            (_, IconFill::Filled) => IconFont::RoundedFilled,
+            #[cfg(feature = "sharp-icons")]
+            (IconStyle::Sharp, IconFill::Unfilled) => IconFont::Sharp,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            IconFont::Rounded => "MaterialSymbolsRounded-Regular",
+            IconFont::RoundedFilled => "MaterialSymbolsRounded_Filled-Regular",
+            #[cfg(feature = "sharp-icons")]
+            IconFont::Sharp => "MaterialSymbolsSharp",
+        }
+    }
+
+    fn data(&self) -> &'static [u8] {
+        match self {
+            IconFont::Rounded => {
+                include_bytes!("../../assets/icons/MaterialSymbolsRounded-Regular.ttf")
+            }
+            IconFont::RoundedFilled => {
+                include_bytes!("../../assets/icons/MaterialSymbolsRounded_Filled-Regular.ttf")
+            }
+            // 可变字体，egui 使用其默认实例：未填充、字重 400
+            #[cfg(feature = "sharp-icons")]
+            IconFont::Sharp => include_bytes!("../../assets/icons/MaterialSymbolsSharp.ttf"),
+        }
+    }
+
+    fn family(&self) -> FontFamily {
+        FontFamily::Name(self.name().into())
+    }
+}
+
+/// Font family of the bundled Material Symbols font with the given style and fill
+///
+/// Only available after the fonts are installed, see [`add_icon_fonts`].
+pub fn icon_family(style: IconStyle, fill: IconFill) -> FontFamily {
+    IconFont::new(style, fill).family()
+}
+
+/// Register the Material Symbols fonts in egui font definitions
+///
+/// [`font_definitions`](crate::typography::prelude::font_definitions) already
+/// includes them.
+pub fn add_icon_fonts(fonts: &mut FontDefinitions) {
+    for font in IconFont::ALL {
+        let name = font.name();
+        fonts.font_data.insert(
+            name.to_owned(),
+            Arc::new(FontData::from_static(font.data())),
+        );
+        fonts.families.insert(font.family(), vec![name.to_owned()]);
+    }
+}
+
+/// Whether the icon font is installed, installing it for the next pass if not
+pub(crate) fn ensure_icon_font(ctx: &Context, style: IconStyle, fill: IconFill) -> bool {
+    let font = IconFont::new(style, fill);
+    let family = font.family();
+    if ctx.fonts(|f| f.families().contains(&family)) {
+        return true;
+    }
+
+    // 字体在下一帧开始时才可用，等待期间不重复插入
+    let pending_id = Id::new("egui_material::icon_font").with(font);
+    let pass = ctx.cumulative_pass_nr();
+    let pending = ctx.data(|d| d.get_temp::<u64>(pending_id));
+    if pending.is_some_and(|inserted| pass <= inserted + 1) {
+        return false;
+    }
+    ctx.data_mut(|d| d.insert_temp(pending_id, pass));
+    ctx.add_font(FontInsert::new(
+        font.name(),
+        FontData::from_static(font.data()),
+        vec![InsertFontFamily {
+            family,
+            priority: FontPriority::Highest,
+        }],
+    ));
+    ctx.request_repaint();
+    false
+}
@@ -0,0 +1,110 @@
+//! Icons of components
+
+use egui::{Align2, Color32, FontId, Painter, Pos2, Rect, Vec2};
+
+use super::font::{IconFill, IconStyle, ensure_icon_font, icon_family};
+use super::symbols::MaterialIcon;
+use super::vector::VectorIcon;
+
+/// An icon of a component, a Material Symbols glyph or a vector icon
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// let tokens = MaterialTokens::load(ui.ctx());
+/// let (rect, _) = ui.allocate_exact_size(egui::vec2(24.0, 24.0), egui::Sense::hover());
+/// Icon::from(MaterialIcon::Favorite).paint(ui.painter(), rect.center(), 24.0, tokens.scheme.primary());
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Icon {
+    Symbol(MaterialIcon, IconFill, IconStyle),
+    Vector(VectorIcon),
+}
+
+impl Icon {
+    /// Icon name read out by screen readers, e.g. `chevron left`
+    pub fn accessible_name(&self) -> String {
+        let name = match self {
+            Icon::Symbol(icon, _, _) => icon.name(),
+            Icon::Vector(icon) => icon.name(),
+        };
+        name.replace('_', " ")
+    }
+
+    /// Use another style for a symbol, vector icons are unchanged
+    pub fn with_style(self, style: IconStyle) -> Self {
+        match self {
+            Icon::Symbol(icon, fill, _) => Icon::Symbol(icon, fill, style),
+            icon => icon,
+        }
+    }
+
+    /// Paint the icon centered at `center`, `size` is the side of the icon square
+    ///
+    /// Symbols are skipped while their font is still being installed.
+    pub fn paint(&self, painter: &Painter, center: Pos2, size: f32, color: Color32) {
+        self.paint_progress(painter, center, size, color, 1.0);
+    }
+
+    /// Paint the icon partly drawn, vector icons draw their strokes and symbols
+    /// are revealed from the left
+    pub fn paint_progress(
+        &self,
+        painter: &Painter,
+        center: Pos2,
+        size: f32,
+        color: Color32,
+        progress: f32,
+    ) {
+        if progress <= 0.0 {
+            return;
+        }
+        let rect = Rect::from_center_size(center, Vec2::splat(size));
+        match *self {
+            Icon::Symbol(icon, fill, style) => {
+                if !ensure_icon_font(painter.ctx(), style, fill) {
+                    return;
+                }
+                let mut reveal = rect;
+                reveal.max.x = rect.min.x + rect.width() * progress.min(1.0);
+                painter
+                    .with_clip_rect(reveal.intersect(painter.clip_rect()))
+                    .text(
+                        center,
+                        Align2::CENTER_CENTER,
+                        icon.codepoint(),
+                        FontId::new(size, icon_family(style, fill)),
+                        color,
+                    );
+            }
+            Icon::Vector(icon) => icon.paint_progress(painter, rect, color, progress),
+        }
+    }
+}
+
+impl MaterialIcon {
+    /// The filled glyph of this icon
+    pub fn filled(self) -> Icon {
+        Icon::Symbol(self, IconFill::Filled, IconStyle::default())
+    }
+
+    /// The unfilled glyph of this icon in a style
+    pub fn with_style(self, style: IconStyle) -> Icon {
+        Icon::Symbol(self, IconFill::Unfilled, style)
+    }
+}
+
+impl From<MaterialIcon> for Icon {
+    fn from(icon: MaterialIcon) -> Self {
+        Icon::Symbol(icon, IconFill::Unfilled, IconStyle::default())
+    }
+}
+
+impl From<VectorIcon> for Icon {
+    fn from(icon: VectorIcon) -> Self {
+        Icon::Vector(icon)
+    }
+}
@@ -0,0 +1,10 @@
+//! Material Design icons
+//!
+//! See: [material design document](https://m3.material.io/styles/icons/overview)
+
+mod font;
+#[allow(clippy::module_inception)]
+mod icon;
+pub mod prelude;
+mod symbols;
+mod vector;
@@ -0,0 +1,4 @@
+pub use super::font::*;
+pub use super::icon::*;
+pub use super::symbols::*;
+pub use super::vector::*;
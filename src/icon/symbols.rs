@@ -0,0 +1,3720 @@
+//! Material Symbols codepoints
+//!
+//! Generated from `assets/icons/MaterialSymbolsRounded-Regular.codepoints`,
+//! one variant per icon name.
+
+macro_rules! material_icons {
+    ($($icon:ident = $name:literal $codepoint:literal,)*) => {
+        /// A Material Symbols icon
+        ///
+        /// Paint it with [`Icon`](super::prelude::Icon), or put [`MaterialIcon::codepoint`]
+        /// into a text laid out with [`icon_family`](super::prelude::icon_family).
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum MaterialIcon {
+            $($icon,)*
+        }
+
+        impl MaterialIcon {
+            /// Every icon, in the order of the codepoints file
+            pub const ALL: &[MaterialIcon] = &[$(MaterialIcon::$icon,)*];
+
+            /// Codepoint of the icon glyph in the Material Symbols fonts
+            pub const fn codepoint(self) -> char {
+                match self {
+                    $(MaterialIcon::$icon => $codepoint,)*
+                }
+            }
+
+            /// Snake case name, e.g. `"arrow_back"`
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(MaterialIcon::$icon => $name,)*
+                }
+            }
+
+            /// Icon by its snake case name
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(MaterialIcon::$icon),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+material_icons! {
+    Icon10k = "10k" '\u{e951}',
+    Icon10mp = "10mp" '\u{e952}',
+    Icon11mp = "11mp" '\u{e953}',
+    Icon123 = "123" '\u{eb8d}',
+    Icon12mp = "12mp" '\u{e954}',
+    Icon13mp = "13mp" '\u{e955}',
+    Icon14mp = "14mp" '\u{e956}',
+    Icon15mp = "15mp" '\u{e957}',
+    Icon16mp = "16mp" '\u{e958}',
+    Icon17mp = "17mp" '\u{e959}',
+    Icon18UpRating = "18_up_rating" '\u{f8fd}',
+    Icon18mp = "18mp" '\u{e95a}',
+    Icon19mp = "19mp" '\u{e95b}',
+    Icon1k = "1k" '\u{e95c}',
+    Icon1kPlus = "1k_plus" '\u{e95d}',
+    Icon1xMobiledata = "1x_mobiledata" '\u{efcd}',
+    Icon1xMobiledataBadge = "1x_mobiledata_badge" '\u{f7f1}',
+    Icon20mp = "20mp" '\u{e95e}',
+    Icon21mp = "21mp" '\u{e95f}',
+    Icon22mp = "22mp" '\u{e960}',
+    Icon23mp = "23mp" '\u{e961}',
+    Icon24fpsSelect = "24fps_select" '\u{f3f2}',
+    Icon24mp = "24mp" '\u{e962}',
+    Icon2d = "2d" '\u{ef37}',
+    Icon2k = "2k" '\u{e963}',
+    Icon2kPlus = "2k_plus" '\u{e964}',
+    Icon2mp = "2mp" '\u{e965}',
+    Icon30fps = "30fps" '\u{efce}',
+    Icon30fpsSelect = "30fps_select" '\u{efcf}',
+    Icon360 = "360" '\u{e577}',
+    Icon3dRotation = "3d_rotation" '\u{e84d}',
+    Icon3gMobiledata = "3g_mobiledata" '\u{efd0}',
+    Icon3gMobiledataBadge = "3g_mobiledata_badge" '\u{f7f0}',
+    Icon3k = "3k" '\u{e966}',
+    Icon3kPlus = "3k_plus" '\u{e967}',
+    Icon3mp = "3mp" '\u{e968}',
+    Icon3p = "3p" '\u{efd1}',
+    Icon4gMobiledata = "4g_mobiledata" '\u{efd2}',
+    Icon4gMobiledataBadge = "4g_mobiledata_badge" '\u{f7ef}',
+    Icon4gPlusMobiledata = "4g_plus_mobiledata" '\u{efd3}',
+    Icon4k = "4k" '\u{e072}',
+    Icon4kPlus = "4k_plus" '\u{e969}',
+    Icon4mp = "4mp" '\u{e96a}',
+    Icon50mp = "50mp" '\u{f6f3}',
+    Icon5g = "5g" '\u{ef38}',
+    Icon5gMobiledataBadge = "5g_mobiledata_badge" '\u{f7ee}',
+    Icon5k = "5k" '\u{e96b}',
+    Icon5kPlus = "5k_plus" '\u{e96c}',
+    Icon5mp = "5mp" '\u{e96d}',
+    Icon60fps = "60fps" '\u{efd4}',
+    Icon60fpsSelect = "60fps_select" '\u{efd5}',
+    Icon6FtApart = "6_ft_apart" '\u{f21e}',
+    Icon6k = "6k" '\u{e96e}',
+    Icon6kPlus = "6k_plus" '\u{e96f}',
+    Icon6mp = "6mp" '\u{e970}',
+    Icon7k = "7k" '\u{e971}',
+    Icon7kPlus = "7k_plus" '\u{e972}',
+    Icon7mp = "7mp" '\u{e973}',
+    Icon8k = "8k" '\u{e974}',
+    Icon8kPlus = "8k_plus" '\u{e975}',
+    Icon8mp = "8mp" '\u{e976}',
+    Icon9k = "9k" '\u{e977}',
+    Icon9kPlus = "9k_plus" '\u{e978}',
+    Icon9mp = "9mp" '\u{e979}',
+    Abc = "abc" '\u{eb94}',
+    AcUnit = "ac_unit" '\u{eb3b}',
+    AccessAlarm = "access_alarm" '\u{e855}',
+    AccessAlarms = "access_alarms" '\u{e855}',
+    AccessTime = "access_time" '\u{efd6}',
+    AccessTimeFilled = "access_time_filled" '\u{efd6}',
+    Accessibility = "accessibility" '\u{e84e}',
+    AccessibilityNew = "accessibility_new" '\u{e92c}',
+    Accessible = "accessible" '\u{e914}',
+    AccessibleForward = "accessible_forward" '\u{e934}',
+    AccountBalance = "account_balance" '\u{e84f}',
+    AccountBalanceWallet = "account_balance_wallet" '\u{e850}',
+    AccountBox = "account_box" '\u{e851}',
+    AccountChild = "account_child" '\u{e852}',
+    AccountChildInvert = "account_child_invert" '\u{e659}',
+    AccountCircle = "account_circle" '\u{f20b}',
+    AccountCircleFilled = "account_circle_filled" '\u{f20b}',
+    AccountCircleOff = "account_circle_off" '\u{f7b3}',
+    AccountTree = "account_tree" '\u{e97a}',
+    ActionKey = "action_key" '\u{f502}',
+    ActivityZone = "activity_zone" '\u{e1e6}',
+    Acute = "acute" '\u{e4cb}',
+    Ad = "ad" '\u{e65a}',
+    AdGroup = "ad_group" '\u{e65b}',
+    AdGroupOff = "ad_group_off" '\u{eae5}',
+    AdOff = "ad_off" '\u{f7b2}',
+    AdUnits = "ad_units" '\u{ef39}',
+    AdaptiveAudioMic = "adaptive_audio_mic" '\u{f4cc}',
+    AdaptiveAudioMicOff = "adaptive_audio_mic_off" '\u{f4cb}',
+    Adb = "adb" '\u{e60e}',
+    Add = "add" '\u{e145}',
+    AddAPhoto = "add_a_photo" '\u{e439}',
+    AddAd = "add_ad" '\u{e72a}',
+    AddAlarm = "add_alarm" '\u{e856}',
+    AddAlert = "add_alert" '\u{e003}',
+    AddBox = "add_box" '\u{e146}',
+    AddBusiness = "add_business" '\u{e729}',
+    AddCall = "add_call" '\u{f0b7}',
+    AddCard = "add_card" '\u{eb86}',
+    AddChart = "add_chart" '\u{ef3c}',
+    AddCircle = "add_circle" '\u{e3ba}',
+    AddCircleOutline = "add_circle_outline" '\u{e3ba}',
+    AddColumnLeft = "add_column_left" '\u{f425}',
+    AddColumnRight = "add_column_right" '\u{f424}',
+    AddComment = "add_comment" '\u{e266}',
+    AddDiamond = "add_diamond" '\u{f49c}',
+    AddHome = "add_home" '\u{f8eb}',
+    AddHomeWork = "add_home_work" '\u{f8ed}',
+    AddIcCall = "add_ic_call" '\u{f0b7}',
+    AddLink = "add_link" '\u{e178}',
+    AddLocation = "add_location" '\u{e567}',
+    AddLocationAlt = "add_location_alt" '\u{ef3a}',
+    AddModerator = "add_moderator" '\u{e97d}',
+    AddNotes = "add_notes" '\u{e091}',
+    AddPhotoAlternate = "add_photo_alternate" '\u{e43e}',
+    AddReaction = "add_reaction" '\u{e1d3}',
+    AddRoad = "add_road" '\u{ef3b}',
+    AddRowAbove = "add_row_above" '\u{f423}',
+    AddRowBelow = "add_row_below" '\u{f422}',
+    AddShoppingCart = "add_shopping_cart" '\u{e854}',
+    AddTask = "add_task" '\u{f23a}',
+    AddToDrive = "add_to_drive" '\u{e65c}',
+    AddToHomeScreen = "add_to_home_screen" '\u{e1fe}',
+    AddToPhotos = "add_to_photos" '\u{e39d}',
+    AddToQueue = "add_to_queue" '\u{e05c}',
+    AddTriangle = "add_triangle" '\u{f48e}',
+    Addchart = "addchart" '\u{ef3c}',
+    AdfScanner = "adf_scanner" '\u{eada}',
+    Adjust = "adjust" '\u{e39e}',
+    AdminMeds = "admin_meds" '\u{e48d}',
+    AdminPanelSettings = "admin_panel_settings" '\u{ef3d}',
+    AdsClick = "ads_click" '\u{e762}',
+    Agender = "agender" '\u{f888}',
+    Agriculture = "agriculture" '\u{ea79}',
+    Air = "air" '\u{efd8}',
+    AirFreshener = "air_freshener" '\u{e2ca}',
+    AirPurifier = "air_purifier" '\u{e97e}',
+    AirPurifierGen = "air_purifier_gen" '\u{e829}',
+    AirlineSeatFlat = "airline_seat_flat" '\u{e630}',
+    AirlineSeatFlatAngled = "airline_seat_flat_angled" '\u{e631}',
+    AirlineSeatIndividualSuite = "airline_seat_individual_suite" '\u{e632}',
+    AirlineSeatLegroomExtra = "airline_seat_legroom_extra" '\u{e633}',
+    AirlineSeatLegroomNormal = "airline_seat_legroom_normal" '\u{e634}',
+    AirlineSeatLegroomReduced = "airline_seat_legroom_reduced" '\u{e635}',
+    AirlineSeatReclineExtra = "airline_seat_recline_extra" '\u{e636}',
+    AirlineSeatReclineNormal = "airline_seat_recline_normal" '\u{e637}',
+    AirlineStops = "airline_stops" '\u{e7d0}',
+    Airlines = "airlines" '\u{e7ca}',
+    AirplaneTicket = "airplane_ticket" '\u{efd9}',
+    AirplanemodeActive = "airplanemode_active" '\u{e53d}',
+    AirplanemodeInactive = "airplanemode_inactive" '\u{e194}',
+    Airplay = "airplay" '\u{e055}',
+    AirportShuttle = "airport_shuttle" '\u{eb3c}',
+    Airware = "airware" '\u{f154}',
+    Airwave = "airwave" '\u{f154}',
+    Alarm = "alarm" '\u{e855}',
+    AlarmAdd = "alarm_add" '\u{e856}',
+    AlarmOff = "alarm_off" '\u{e857}',
+    AlarmOn = "alarm_on" '\u{e858}',
+    AlarmSmartWake = "alarm_smart_wake" '\u{f6b0}',
+    Album = "album" '\u{e019}',
+    AlignCenter = "align_center" '\u{e356}',
+    AlignEnd = "align_end" '\u{f797}',
+    AlignFlexCenter = "align_flex_center" '\u{f796}',
+    AlignFlexEnd = "align_flex_end" '\u{f795}',
+    AlignFlexStart = "align_flex_start" '\u{f794}',
+    AlignHorizontalCenter = "align_horizontal_center" '\u{e00f}',
+    AlignHorizontalLeft = "align_horizontal_left" '\u{e00d}',
+    AlignHorizontalRight = "align_horizontal_right" '\u{e010}',
+    AlignItemsStretch = "align_items_stretch" '\u{f793}',
+    AlignJustifyCenter = "align_justify_center" '\u{f792}',
+    AlignJustifyFlexEnd = "align_justify_flex_end" '\u{f791}',
+    AlignJustifyFlexStart = "align_justify_flex_start" '\u{f790}',
+    AlignJustifySpaceAround = "align_justify_space_around" '\u{f78f}',
+    AlignJustifySpaceBetween = "align_justify_space_between" '\u{f78e}',
+    AlignJustifySpaceEven = "align_justify_space_even" '\u{f78d}',
+    AlignJustifyStretch = "align_justify_stretch" '\u{f78c}',
+    AlignSelfStretch = "align_self_stretch" '\u{f78b}',
+    AlignSpaceAround = "align_space_around" '\u{f78a}',
+    AlignSpaceBetween = "align_space_between" '\u{f789}',
+    AlignSpaceEven = "align_space_even" '\u{f788}',
+    AlignStart = "align_start" '\u{f787}',
+    AlignStretch = "align_stretch" '\u{f786}',
+    AlignVerticalBottom = "align_vertical_bottom" '\u{e015}',
+    AlignVerticalCenter = "align_vertical_center" '\u{e011}',
+    AlignVerticalTop = "align_vertical_top" '\u{e00c}',
+    AllInbox = "all_inbox" '\u{e97f}',
+    AllInclusive = "all_inclusive" '\u{eb3d}',
+    AllMatch = "all_match" '\u{e093}',
+    AllOut = "all_out" '\u{e90b}',
+    Allergies = "allergies" '\u{e094}',
+    Allergy = "allergy" '\u{e64e}',
+    AltRoute = "alt_route" '\u{f184}',
+    AlternateEmail = "alternate_email" '\u{e0e6}',
+    Altitude = "altitude" '\u{f873}',
+    AmbientScreen = "ambient_screen" '\u{f6c4}',
+    Ambulance = "ambulance" '\u{f803}',
+    Amend = "amend" '\u{f802}',
+    AmpStories = "amp_stories" '\u{ea13}',
+    Analytics = "analytics" '\u{ef3e}',
+    Anchor = "anchor" '\u{f1cd}',
+    Android = "android" '\u{e859}',
+    AnimatedImages = "animated_images" '\u{f49a}',
+    Animation = "animation" '\u{e71c}',
+    Announcement = "announcement" '\u{e87f}',
+    Aod = "aod" '\u{efda}',
+    AodTablet = "aod_tablet" '\u{f89f}',
+    AodWatch = "aod_watch" '\u{f6ac}',
+    Apartment = "apartment" '\u{ea40}',
+    Api = "api" '\u{f1b7}',
+    ApkDocument = "apk_document" '\u{f88e}',
+    ApkInstall = "apk_install" '\u{f88f}',
+    AppBadging = "app_badging" '\u{f72f}',
+    AppBlocking = "app_blocking" '\u{ef3f}',
+    AppPromo = "app_promo" '\u{e981}',
+    AppRegistration = "app_registration" '\u{ef40}',
+    AppSettingsAlt = "app_settings_alt" '\u{ef41}',
+    AppShortcut = "app_shortcut" '\u{eae4}',
+    Apparel = "apparel" '\u{ef7b}',
+    Approval = "approval" '\u{e982}',
+    ApprovalDelegation = "approval_delegation" '\u{f84a}',
+    Apps = "apps" '\u{e5c3}',
+    AppsOutage = "apps_outage" '\u{e7cc}',
+    Aq = "aq" '\u{f55a}',
+    AqIndoor = "aq_indoor" '\u{f55b}',
+    ArOnYou = "ar_on_you" '\u{ef7c}',
+    ArStickers = "ar_stickers" '\u{e983}',
+    Architecture = "architecture" '\u{ea3b}',
+    Archive = "archive" '\u{e149}',
+    AreaChart = "area_chart" '\u{e770}',
+    ArmingCountdown = "arming_countdown" '\u{e78a}',
+    ArrowAndEdge = "arrow_and_edge" '\u{f5d7}',
+    ArrowBack = "arrow_back" '\u{e5c4}',
+    ArrowBack2 = "arrow_back_2" '\u{f43a}',
+    ArrowBackIos = "arrow_back_ios" '\u{e5e0}',
+    ArrowBackIosNew = "arrow_back_ios_new" '\u{e2ea}',
+    ArrowCircleDown = "arrow_circle_down" '\u{f181}',
+    ArrowCircleLeft = "arrow_circle_left" '\u{eaa7}',
+    ArrowCircleRight = "arrow_circle_right" '\u{eaaa}',
+    ArrowCircleUp = "arrow_circle_up" '\u{f182}',
+    ArrowCoolDown = "arrow_cool_down" '\u{f4b6}',
+    ArrowDownward = "arrow_downward" '\u{e5db}',
+    ArrowDownwardAlt = "arrow_downward_alt" '\u{e984}',
+    ArrowDropDown = "arrow_drop_down" '\u{e5c5}',
+    ArrowDropDownCircle = "arrow_drop_down_circle" '\u{e5c6}',
+    ArrowDropUp = "arrow_drop_up" '\u{e5c7}',
+    ArrowForward = "arrow_forward" '\u{e5c8}',
+    ArrowForwardIos = "arrow_forward_ios" '\u{e5e1}',
+    ArrowInsert = "arrow_insert" '\u{f837}',
+    ArrowLeft = "arrow_left" '\u{e5de}',
+    ArrowLeftAlt = "arrow_left_alt" '\u{ef7d}',
+    ArrowOrEdge = "arrow_or_edge" '\u{f5d6}',
+    ArrowOutward = "arrow_outward" '\u{f8ce}',
+    ArrowRange = "arrow_range" '\u{f69b}',
+    ArrowRight = "arrow_right" '\u{e5df}',
+    ArrowRightAlt = "arrow_right_alt" '\u{e941}',
+    ArrowSelectorTool = "arrow_selector_tool" '\u{f82f}',
+    ArrowSplit = "arrow_split" '\u{ea04}',
+    ArrowTopLeft = "arrow_top_left" '\u{f72e}',
+    ArrowTopRight = "arrow_top_right" '\u{f72d}',
+    ArrowUploadProgress = "arrow_upload_progress" '\u{f3f4}',
+    ArrowUploadReady = "arrow_upload_ready" '\u{f3f5}',
+    ArrowUpward = "arrow_upward" '\u{e5d8}',
+    ArrowUpwardAlt = "arrow_upward_alt" '\u{e986}',
+    ArrowWarmUp = "arrow_warm_up" '\u{f4b5}',
+    ArrowsMoreDown = "arrows_more_down" '\u{f8ab}',
+    ArrowsMoreUp = "arrows_more_up" '\u{f8ac}',
+    ArrowsOutward = "arrows_outward" '\u{f72c}',
+    ArtTrack = "art_track" '\u{e060}',
+    Article = "article" '\u{ef42}',
+    ArticleShortcut = "article_shortcut" '\u{f587}',
+    Artist = "artist" '\u{e01a}',
+    AspectRatio = "aspect_ratio" '\u{e85b}',
+    Assessment = "assessment" '\u{f0cc}',
+    Assignment = "assignment" '\u{e85d}',
+    AssignmentAdd = "assignment_add" '\u{f848}',
+    AssignmentInd = "assignment_ind" '\u{e85e}',
+    AssignmentLate = "assignment_late" '\u{e85f}',
+    AssignmentReturn = "assignment_return" '\u{e860}',
+    AssignmentReturned = "assignment_returned" '\u{e861}',
+    AssignmentTurnedIn = "assignment_turned_in" '\u{e862}',
+    AssistWalker = "assist_walker" '\u{f8d5}',
+    Assistant = "assistant" '\u{e39f}',
+    AssistantDevice = "assistant_device" '\u{e987}',
+    AssistantDirection = "assistant_direction" '\u{e988}',
+    AssistantNavigation = "assistant_navigation" '\u{e989}',
+    AssistantOnHub = "assistant_on_hub" '\u{f6c1}',
+    AssistantPhoto = "assistant_photo" '\u{f0c6}',
+    AssuredWorkload = "assured_workload" '\u{eb6f}',
+    Asterisk = "asterisk" '\u{f525}',
+    AstrophotographyAuto = "astrophotography_auto" '\u{f1d9}',
+    AstrophotographyOff = "astrophotography_off" '\u{f1da}',
+    Atm = "atm" '\u{e573}',
+    Atr = "atr" '\u{ebc7}',
+    AttachEmail = "attach_email" '\u{ea5e}',
+    AttachFile = "attach_file" '\u{e226}',
+    AttachFileAdd = "attach_file_add" '\u{f841}',
+    AttachFileOff = "attach_file_off" '\u{f4d9}',
+    AttachMoney = "attach_money" '\u{e227}',
+    Attachment = "attachment" '\u{e2bc}',
+    Attractions = "attractions" '\u{ea52}',
+    Attribution = "attribution" '\u{efdb}',
+    AudioDescription = "audio_description" '\u{f58c}',
+    AudioFile = "audio_file" '\u{eb82}',
+    AudioVideoReceiver = "audio_video_receiver" '\u{f5d3}',
+    Audiotrack = "audiotrack" '\u{e405}',
+    AutoActivityZone = "auto_activity_zone" '\u{f8ad}',
+    AutoAwesome = "auto_awesome" '\u{e65f}',
+    AutoAwesomeMosaic = "auto_awesome_mosaic" '\u{e660}',
+    AutoAwesomeMotion = "auto_awesome_motion" '\u{e661}',
+    AutoDelete = "auto_delete" '\u{ea4c}',
+    AutoDetectVoice = "auto_detect_voice" '\u{f83e}',
+    AutoDrawSolid = "auto_draw_solid" '\u{e98a}',
+    AutoFix = "auto_fix" '\u{e663}',
+    AutoFixHigh = "auto_fix_high" '\u{e663}',
+    AutoFixNormal = "auto_fix_normal" '\u{e664}',
+    AutoFixOff = "auto_fix_off" '\u{e665}',
+    AutoGraph = "auto_graph" '\u{e4fb}',
+    AutoLabel = "auto_label" '\u{f6be}',
+    AutoMeetingRoom = "auto_meeting_room" '\u{f6bf}',
+    AutoMode = "auto_mode" '\u{ec20}',
+    AutoReadPause = "auto_read_pause" '\u{f219}',
+    AutoReadPlay = "auto_read_play" '\u{f216}',
+    AutoSchedule = "auto_schedule" '\u{e214}',
+    AutoStories = "auto_stories" '\u{e666}',
+    AutoTimer = "auto_timer" '\u{ef7f}',
+    AutoTowing = "auto_towing" '\u{e71e}',
+    AutoTransmission = "auto_transmission" '\u{f53f}',
+    AutoVideocam = "auto_videocam" '\u{f6c0}',
+    AutofpsSelect = "autofps_select" '\u{efdc}',
+    Automation = "automation" '\u{f421}',
+    Autopause = "autopause" '\u{f6b6}',
+    Autopay = "autopay" '\u{f84b}',
+    Autoplay = "autoplay" '\u{f6b5}',
+    Autorenew = "autorenew" '\u{e863}',
+    Autostop = "autostop" '\u{f682}',
+    Av1 = "av1" '\u{f4b0}',
+    AvTimer = "av_timer" '\u{e01b}',
+    Avc = "avc" '\u{f4af}',
+    AvgPace = "avg_pace" '\u{f6bb}',
+    AvgTime = "avg_time" '\u{f813}',
+    AwardStar = "award_star" '\u{f612}',
+    Azm = "azm" '\u{f6ec}',
+    BabyChangingStation = "baby_changing_station" '\u{f19b}',
+    BackHand = "back_hand" '\u{e764}',
+    BackToTab = "back_to_tab" '\u{f72b}',
+    BackgroundDotLarge = "background_dot_large" '\u{f79e}',
+    BackgroundDotSmall = "background_dot_small" '\u{f514}',
+    BackgroundGridSmall = "background_grid_small" '\u{f79d}',
+    BackgroundReplace = "background_replace" '\u{f20a}',
+    BacklightHigh = "backlight_high" '\u{f7ed}',
+    BacklightHighOff = "backlight_high_off" '\u{f4ef}',
+    BacklightLow = "backlight_low" '\u{f7ec}',
+    Backpack = "backpack" '\u{f19c}',
+    Backspace = "backspace" '\u{e14a}',
+    Backup = "backup" '\u{e864}',
+    BackupTable = "backup_table" '\u{ef43}',
+    Badge = "badge" '\u{ea67}',
+    BadgeCriticalBattery = "badge_critical_battery" '\u{f156}',
+    BakeryDining = "bakery_dining" '\u{ea53}',
+    Balance = "balance" '\u{eaf6}',
+    Balcony = "balcony" '\u{e58f}',
+    Ballot = "ballot" '\u{e172}',
+    BarChart = "bar_chart" '\u{e26b}',
+    BarChart4Bars = "bar_chart_4_bars" '\u{f681}',
+    BarChartOff = "bar_chart_off" '\u{f411}',
+    Barcode = "barcode" '\u{e70b}',
+    BarcodeReader = "barcode_reader" '\u{f85c}',
+    BarcodeScanner = "barcode_scanner" '\u{e70c}',
+    Barefoot = "barefoot" '\u{f871}',
+    BatchPrediction = "batch_prediction" '\u{f0f5}',
+    BathOutdoor = "bath_outdoor" '\u{f6fb}',
+    BathPrivate = "bath_private" '\u{f6fa}',
+    BathPublicLarge = "bath_public_large" '\u{f6f9}',
+    Bathroom = "bathroom" '\u{efdd}',
+    Bathtub = "bathtub" '\u{ea41}',
+    Battery0Bar = "battery_0_bar" '\u{ebdc}',
+    Battery1Bar = "battery_1_bar" '\u{f09c}',
+    Battery20 = "battery_20" '\u{f09c}',
+    Battery2Bar = "battery_2_bar" '\u{f09d}',
+    Battery30 = "battery_30" '\u{f09d}',
+    Battery3Bar = "battery_3_bar" '\u{f09e}',
+    Battery4Bar = "battery_4_bar" '\u{f09f}',
+    Battery50 = "battery_50" '\u{f09e}',
+    Battery5Bar = "battery_5_bar" '\u{f0a0}',
+    Battery60 = "battery_60" '\u{f09f}',
+    Battery6Bar = "battery_6_bar" '\u{f0a1}',
+    Battery80 = "battery_80" '\u{f0a0}',
+    Battery90 = "battery_90" '\u{f0a1}',
+    BatteryAlert = "battery_alert" '\u{e19c}',
+    BatteryChange = "battery_change" '\u{f7eb}',
+    BatteryCharging20 = "battery_charging_20" '\u{f0a2}',
+    BatteryCharging30 = "battery_charging_30" '\u{f0a3}',
+    BatteryCharging50 = "battery_charging_50" '\u{f0a4}',
+    BatteryCharging60 = "battery_charging_60" '\u{f0a5}',
+    BatteryCharging80 = "battery_charging_80" '\u{f0a6}',
+    BatteryCharging90 = "battery_charging_90" '\u{f0a7}',
+    BatteryChargingFull = "battery_charging_full" '\u{e1a3}',
+    BatteryError = "battery_error" '\u{f7ea}',
+    BatteryFull = "battery_full" '\u{e1a5}',
+    BatteryFullAlt = "battery_full_alt" '\u{f13b}',
+    BatteryHoriz000 = "battery_horiz_000" '\u{f8ae}',
+    BatteryHoriz050 = "battery_horiz_050" '\u{f8af}',
+    BatteryHoriz075 = "battery_horiz_075" '\u{f8b0}',
+    BatteryLow = "battery_low" '\u{f155}',
+    BatteryPlus = "battery_plus" '\u{f7e9}',
+    BatteryProfile = "battery_profile" '\u{e206}',
+    BatterySaver = "battery_saver" '\u{efde}',
+    BatteryShare = "battery_share" '\u{f67e}',
+    BatteryStatusGood = "battery_status_good" '\u{f67d}',
+    BatteryStd = "battery_std" '\u{e1a5}',
+    BatteryUnknown = "battery_unknown" '\u{e1a6}',
+    BatteryVert005 = "battery_vert_005" '\u{f8b1}',
+    BatteryVert020 = "battery_vert_020" '\u{f8b2}',
+    BatteryVert050 = "battery_vert_050" '\u{f8b3}',
+    BatteryVeryLow = "battery_very_low" '\u{f156}',
+    BeachAccess = "beach_access" '\u{eb3e}',
+    Bed = "bed" '\u{efdf}',
+    BedroomBaby = "bedroom_baby" '\u{efe0}',
+    BedroomChild = "bedroom_child" '\u{efe1}',
+    BedroomParent = "bedroom_parent" '\u{efe2}',
+    Bedtime = "bedtime" '\u{ef44}',
+    BedtimeOff = "bedtime_off" '\u{eb76}',
+    Beenhere = "beenhere" '\u{e52d}',
+    Bento = "bento" '\u{f1f4}',
+    Bia = "bia" '\u{f6eb}',
+    BidLandscape = "bid_landscape" '\u{e678}',
+    BidLandscapeDisabled = "bid_landscape_disabled" '\u{ef81}',
+    BigtopUpdates = "bigtop_updates" '\u{e669}',
+    BikeDock = "bike_dock" '\u{f47b}',
+    BikeLane = "bike_lane" '\u{f47a}',
+    BikeScooter = "bike_scooter" '\u{ef45}',
+    Biotech = "biotech" '\u{ea3a}',
+    Blanket = "blanket" '\u{e828}',
+    Blender = "blender" '\u{efe3}',
+    Blind = "blind" '\u{f8d6}',
+    Blinds = "blinds" '\u{e286}',
+    BlindsClosed = "blinds_closed" '\u{ec1f}',
+    Block = "block" '\u{f08c}',
+    BloodPressure = "blood_pressure" '\u{e097}',
+    Bloodtype = "bloodtype" '\u{efe4}',
+    Bluetooth = "bluetooth" '\u{e1a7}',
+    BluetoothAudio = "bluetooth_audio" '\u{e60f}',
+    BluetoothConnected = "bluetooth_connected" '\u{e1a8}',
+    BluetoothDisabled = "bluetooth_disabled" '\u{e1a9}',
+    BluetoothDrive = "bluetooth_drive" '\u{efe5}',
+    BluetoothSearching = "bluetooth_searching" '\u{e60f}',
+    BlurCircular = "blur_circular" '\u{e3a2}',
+    BlurLinear = "blur_linear" '\u{e3a3}',
+    BlurMedium = "blur_medium" '\u{e84c}',
+    BlurOff = "blur_off" '\u{e3a4}',
+    BlurOn = "blur_on" '\u{e3a5}',
+    BlurShort = "blur_short" '\u{e8cf}',
+    BodyFat = "body_fat" '\u{e098}',
+    BodySystem = "body_system" '\u{e099}',
+    Bolt = "bolt" '\u{ea0b}',
+    Bomb = "bomb" '\u{f568}',
+    Book = "book" '\u{e86e}',
+    Book2 = "book_2" '\u{f53e}',
+    Book3 = "book_3" '\u{f53d}',
+    Book4 = "book_4" '\u{f53c}',
+    Book5 = "book_5" '\u{f53b}',
+    BookOnline = "book_online" '\u{f217}',
+    Bookmark = "bookmark" '\u{e8e7}',
+    BookmarkAdd = "bookmark_add" '\u{e598}',
+    BookmarkAdded = "bookmark_added" '\u{e599}',
+    BookmarkBag = "bookmark_bag" '\u{f410}',
+    BookmarkBorder = "bookmark_border" '\u{e8e7}',
+    BookmarkCheck = "bookmark_check" '\u{f457}',
+    BookmarkFlag = "bookmark_flag" '\u{f456}',
+    BookmarkHeart = "bookmark_heart" '\u{f455}',
+    BookmarkManager = "bookmark_manager" '\u{f7b1}',
+    BookmarkRemove = "bookmark_remove" '\u{e59a}',
+    BookmarkStar = "bookmark_star" '\u{f454}',
+    Bookmarks = "bookmarks" '\u{e98b}',
+    BorderAll = "border_all" '\u{e228}',
+    BorderBottom = "border_bottom" '\u{e229}',
+    BorderClear = "border_clear" '\u{e22a}',
+    BorderColor = "border_color" '\u{e22b}',
+    BorderHorizontal = "border_horizontal" '\u{e22c}',
+    BorderInner = "border_inner" '\u{e22d}',
+    BorderLeft = "border_left" '\u{e22e}',
+    BorderOuter = "border_outer" '\u{e22f}',
+    BorderRight = "border_right" '\u{e230}',
+    BorderStyle = "border_style" '\u{e231}',
+    BorderTop = "border_top" '\u{e232}',
+    BorderVertical = "border_vertical" '\u{e233}',
+    Borg = "borg" '\u{f40d}',
+    BottomAppBar = "bottom_app_bar" '\u{e730}',
+    BottomDrawer = "bottom_drawer" '\u{e72d}',
+    BottomNavigation = "bottom_navigation" '\u{e98c}',
+    BottomPanelClose = "bottom_panel_close" '\u{f72a}',
+    BottomPanelOpen = "bottom_panel_open" '\u{f729}',
+    BottomRightClick = "bottom_right_click" '\u{f684}',
+    BottomSheets = "bottom_sheets" '\u{e98d}',
+    Box = "box" '\u{f5a4}',
+    BoxAdd = "box_add" '\u{f5a5}',
+    BoxEdit = "box_edit" '\u{f5a6}',
+    Boy = "boy" '\u{eb67}',
+    BrandAwareness = "brand_awareness" '\u{e98e}',
+    BrandFamily = "brand_family" '\u{f4f1}',
+    BrandingWatermark = "branding_watermark" '\u{e06b}',
+    BreakfastDining = "breakfast_dining" '\u{ea54}',
+    BreakingNews = "breaking_news" '\u{ea08}',
+    BreakingNewsAlt1 = "breaking_news_alt_1" '\u{f0ba}',
+    Breastfeeding = "breastfeeding" '\u{f856}',
+    Brightness1 = "brightness_1" '\u{e3fa}',
+    Brightness2 = "brightness_2" '\u{f036}',
+    Brightness3 = "brightness_3" '\u{e3a8}',
+    Brightness4 = "brightness_4" '\u{e3a9}',
+    Brightness5 = "brightness_5" '\u{e3aa}',
+    Brightness6 = "brightness_6" '\u{e3ab}',
+    Brightness7 = "brightness_7" '\u{e3ac}',
+    BrightnessAlert = "brightness_alert" '\u{f5cf}',
+    BrightnessAuto = "brightness_auto" '\u{e1ab}',
+    BrightnessEmpty = "brightness_empty" '\u{f7e8}',
+    BrightnessHigh = "brightness_high" '\u{e1ac}',
+    BrightnessLow = "brightness_low" '\u{e1ad}',
+    BrightnessMedium = "brightness_medium" '\u{e1ae}',
+    BringYourOwnIp = "bring_your_own_ip" '\u{e016}',
+    BroadcastOnHome = "broadcast_on_home" '\u{f8f8}',
+    BroadcastOnPersonal = "broadcast_on_personal" '\u{f8f9}',
+    BrokenImage = "broken_image" '\u{e3ad}',
+    Browse = "browse" '\u{eb13}',
+    BrowseActivity = "browse_activity" '\u{f8a5}',
+    BrowseGallery = "browse_gallery" '\u{ebd1}',
+    BrowserNotSupported = "browser_not_supported" '\u{ef47}',
+    BrowserUpdated = "browser_updated" '\u{e7cf}',
+    BrunchDining = "brunch_dining" '\u{ea73}',
+    Brush = "brush" '\u{e3ae}',
+    Bubble = "bubble" '\u{ef83}',
+    BubbleChart = "bubble_chart" '\u{e6dd}',
+    Bubbles = "bubbles" '\u{f64e}',
+    BugReport = "bug_report" '\u{e868}',
+    Build = "build" '\u{f8cd}',
+    BuildCircle = "build_circle" '\u{ef48}',
+    Bungalow = "bungalow" '\u{e591}',
+    BurstMode = "burst_mode" '\u{e43c}',
+    BusAlert = "bus_alert" '\u{e98f}',
+    Business = "business" '\u{e7ee}',
+    BusinessCenter = "business_center" '\u{eb3f}',
+    BusinessChip = "business_chip" '\u{f84c}',
+    BusinessMessages = "business_messages" '\u{ef84}',
+    ButtonsAlt = "buttons_alt" '\u{e72f}',
+    Cabin = "cabin" '\u{e589}',
+    Cable = "cable" '\u{efe6}',
+    CableCar = "cable_car" '\u{f479}',
+    Cached = "cached" '\u{e86a}',
+    Cadence = "cadence" '\u{f4b4}',
+    Cake = "cake" '\u{e7e9}',
+    CakeAdd = "cake_add" '\u{f85b}',
+    Calculate = "calculate" '\u{ea5f}',
+    CalendarAddOn = "calendar_add_on" '\u{ef85}',
+    CalendarAppsScript = "calendar_apps_script" '\u{f0bb}',
+    CalendarClock = "calendar_clock" '\u{f540}',
+    CalendarMonth = "calendar_month" '\u{ebcc}',
+    CalendarToday = "calendar_today" '\u{e935}',
+    CalendarViewDay = "calendar_view_day" '\u{e936}',
+    CalendarViewMonth = "calendar_view_month" '\u{efe7}',
+    CalendarViewWeek = "calendar_view_week" '\u{efe8}',
+    Call = "call" '\u{f0d4}',
+    CallEnd = "call_end" '\u{f0bc}',
+    CallEndAlt = "call_end_alt" '\u{f0bc}',
+    CallLog = "call_log" '\u{e08e}',
+    CallMade = "call_made" '\u{e0b2}',
+    CallMerge = "call_merge" '\u{e0b3}',
+    CallMissed = "call_missed" '\u{e0b4}',
+    CallMissedOutgoing = "call_missed_outgoing" '\u{e0e4}',
+    CallQuality = "call_quality" '\u{f652}',
+    CallReceived = "call_received" '\u{e0b5}',
+    CallSplit = "call_split" '\u{e0b6}',
+    CallToAction = "call_to_action" '\u{e06c}',
+    Camera = "camera" '\u{e3af}',
+    CameraAlt = "camera_alt" '\u{e412}',
+    CameraEnhance = "camera_enhance" '\u{e8fc}',
+    CameraFront = "camera_front" '\u{e3b1}',
+    CameraIndoor = "camera_indoor" '\u{efe9}',
+    CameraOutdoor = "camera_outdoor" '\u{efea}',
+    CameraRear = "camera_rear" '\u{e3b2}',
+    CameraRoll = "camera_roll" '\u{e3b3}',
+    CameraVideo = "camera_video" '\u{f7a6}',
+    Cameraswitch = "cameraswitch" '\u{efeb}',
+    Campaign = "campaign" '\u{ef49}',
+    Camping = "camping" '\u{f8a2}',
+    Cancel = "cancel" '\u{e888}',
+    CancelPresentation = "cancel_presentation" '\u{e0e9}',
+    CancelScheduleSend = "cancel_schedule_send" '\u{ea39}',
+    Candle = "candle" '\u{f588}',
+    CandlestickChart = "candlestick_chart" '\u{ead4}',
+    CaptivePortal = "captive_portal" '\u{f728}',
+    Capture = "capture" '\u{f727}',
+    CarCrash = "car_crash" '\u{ebf2}',
+    CarRental = "car_rental" '\u{ea55}',
+    CarRepair = "car_repair" '\u{ea56}',
+    CarTag = "car_tag" '\u{f4e3}',
+    CardGiftcard = "card_giftcard" '\u{e8f6}',
+    CardMembership = "card_membership" '\u{e8f7}',
+    CardTravel = "card_travel" '\u{e8f8}',
+    CardioLoad = "cardio_load" '\u{f4b9}',
+    Cardiology = "cardiology" '\u{e09c}',
+    Cards = "cards" '\u{e991}',
+    Carpenter = "carpenter" '\u{f1f8}',
+    CarryOnBag = "carry_on_bag" '\u{eb08}',
+    CarryOnBagChecked = "carry_on_bag_checked" '\u{eb0b}',
+    CarryOnBagInactive = "carry_on_bag_inactive" '\u{eb0a}',
+    CarryOnBagQuestion = "carry_on_bag_question" '\u{eb09}',
+    Cases = "cases" '\u{e992}',
+    Casino = "casino" '\u{eb40}',
+    Cast = "cast" '\u{e307}',
+    CastConnected = "cast_connected" '\u{e308}',
+    CastForEducation = "cast_for_education" '\u{efec}',
+    CastPause = "cast_pause" '\u{f5f0}',
+    CastWarning = "cast_warning" '\u{f5ef}',
+    Castle = "castle" '\u{eab1}',
+    Category = "category" '\u{e574}',
+    CategorySearch = "category_search" '\u{f437}',
+    Celebration = "celebration" '\u{ea65}',
+    CellMerge = "cell_merge" '\u{f82e}',
+    CellTower = "cell_tower" '\u{ebba}',
+    CellWifi = "cell_wifi" '\u{e0ec}',
+    CenterFocusStrong = "center_focus_strong" '\u{e3b4}',
+    CenterFocusWeak = "center_focus_weak" '\u{e3b5}',
+    Chair = "chair" '\u{efed}',
+    ChairAlt = "chair_alt" '\u{efee}',
+    Chalet = "chalet" '\u{e585}',
+    ChangeCircle = "change_circle" '\u{e2e7}',
+    ChangeHistory = "change_history" '\u{e86b}',
+    Charger = "charger" '\u{e2ae}',
+    ChargingStation = "charging_station" '\u{f19d}',
+    ChartData = "chart_data" '\u{e473}',
+    Chat = "chat" '\u{e0c9}',
+    ChatAddOn = "chat_add_on" '\u{f0f3}',
+    ChatAppsScript = "chat_apps_script" '\u{f0bd}',
+    ChatBubble = "chat_bubble" '\u{e0cb}',
+    ChatBubbleOutline = "chat_bubble_outline" '\u{e0cb}',
+    ChatError = "chat_error" '\u{f7ac}',
+    ChatInfo = "chat_info" '\u{f52b}',
+    ChatPasteGo = "chat_paste_go" '\u{f6bd}',
+    Check = "check" '\u{e5ca}',
+    CheckBox = "check_box" '\u{e834}',
+    CheckBoxOutlineBlank = "check_box_outline_blank" '\u{e835}',
+    CheckCircle = "check_circle" '\u{f0be}',
+    CheckCircleFilled = "check_circle_filled" '\u{f0be}',
+    CheckCircleOutline = "check_circle_outline" '\u{f0be}',
+    CheckInOut = "check_in_out" '\u{f6f6}',
+    CheckIndeterminateSmall = "check_indeterminate_small" '\u{f88a}',
+    CheckSmall = "check_small" '\u{f88b}',
+    Checkbook = "checkbook" '\u{e70d}',
+    CheckedBag = "checked_bag" '\u{eb0c}',
+    CheckedBagQuestion = "checked_bag_question" '\u{eb0d}',
+    Checklist = "checklist" '\u{e6b1}',
+    ChecklistRtl = "checklist_rtl" '\u{e6b3}',
+    Checkroom = "checkroom" '\u{f19e}',
+    Cheer = "cheer" '\u{f6a8}',
+    Chess = "chess" '\u{f5e7}',
+    ChevronBackward = "chevron_backward" '\u{f46b}',
+    ChevronForward = "chevron_forward" '\u{f46a}',
+    ChevronLeft = "chevron_left" '\u{e5cb}',
+    ChevronRight = "chevron_right" '\u{e5cc}',
+    ChildCare = "child_care" '\u{eb41}',
+    ChildFriendly = "child_friendly" '\u{eb42}',
+    ChipExtraction = "chip_extraction" '\u{f821}',
+    Chips = "chips" '\u{e993}',
+    ChromeReaderMode = "chrome_reader_mode" '\u{e86d}',
+    Chromecast2 = "chromecast_2" '\u{f17b}',
+    ChromecastDevice = "chromecast_device" '\u{e83c}',
+    Chronic = "chronic" '\u{ebb2}',
+    Church = "church" '\u{eaae}',
+    CinematicBlur = "cinematic_blur" '\u{f853}',
+    Circle = "circle" '\u{ef4a}',
+    CircleNotifications = "circle_notifications" '\u{e994}',
+    Circles = "circles" '\u{e7ea}',
+    CirclesExt = "circles_ext" '\u{e7ec}',
+    Clarify = "clarify" '\u{f0bf}',
+    Class = "class" '\u{e86e}',
+    CleanHands = "clean_hands" '\u{f21f}',
+    Cleaning = "cleaning" '\u{e995}',
+    CleaningBucket = "cleaning_bucket" '\u{f8b4}',
+    CleaningServices = "cleaning_services" '\u{f0ff}',
+    Clear = "clear" '\u{e5cd}',
+    ClearAll = "clear_all" '\u{e0b8}',
+    ClearDay = "clear_day" '\u{f157}',
+    ClearNight = "clear_night" '\u{f159}',
+    ClimateMiniSplit = "climate_mini_split" '\u{f8b5}',
+    ClinicalNotes = "clinical_notes" '\u{e09e}',
+    ClockLoader10 = "clock_loader_10" '\u{f726}',
+    ClockLoader20 = "clock_loader_20" '\u{f725}',
+    ClockLoader40 = "clock_loader_40" '\u{f724}',
+    ClockLoader60 = "clock_loader_60" '\u{f723}',
+    ClockLoader80 = "clock_loader_80" '\u{f722}',
+    ClockLoader90 = "clock_loader_90" '\u{f721}',
+    Close = "close" '\u{e5cd}',
+    CloseFullscreen = "close_fullscreen" '\u{f1cf}',
+    CloseSmall = "close_small" '\u{f508}',
+    ClosedCaption = "closed_caption" '\u{e996}',
+    ClosedCaptionAdd = "closed_caption_add" '\u{f4ae}',
+    ClosedCaptionDisabled = "closed_caption_disabled" '\u{f1dc}',
+    ClosedCaptionOff = "closed_caption_off" '\u{e996}',
+    Cloud = "cloud" '\u{f15c}',
+    CloudCircle = "cloud_circle" '\u{e2be}',
+    CloudDone = "cloud_done" '\u{e2bf}',
+    CloudDownload = "cloud_download" '\u{e2c0}',
+    CloudOff = "cloud_off" '\u{e2c1}',
+    CloudQueue = "cloud_queue" '\u{f15c}',
+    CloudSync = "cloud_sync" '\u{eb5a}',
+    CloudUpload = "cloud_upload" '\u{e2c3}',
+    Cloudy = "cloudy" '\u{f15c}',
+    CloudyFilled = "cloudy_filled" '\u{f15c}',
+    CloudySnowing = "cloudy_snowing" '\u{e810}',
+    Co2 = "co2" '\u{e7b0}',
+    CoPresent = "co_present" '\u{eaf0}',
+    Code = "code" '\u{e86f}',
+    CodeBlocks = "code_blocks" '\u{f84d}',
+    CodeOff = "code_off" '\u{e4f3}',
+    Coffee = "coffee" '\u{efef}',
+    CoffeeMaker = "coffee_maker" '\u{eff0}',
+    Cognition = "cognition" '\u{e09f}',
+    CollapseAll = "collapse_all" '\u{e944}',
+    CollapseContent = "collapse_content" '\u{f507}',
+    Collections = "collections" '\u{e3d3}',
+    CollectionsBookmark = "collections_bookmark" '\u{e431}',
+    ColorLens = "color_lens" '\u{e40a}',
+    Colorize = "colorize" '\u{e3b8}',
+    Colors = "colors" '\u{e997}',
+    CombineColumns = "combine_columns" '\u{f420}',
+    ComedyMask = "comedy_mask" '\u{f4d6}',
+    ComicBubble = "comic_bubble" '\u{f5dd}',
+    Comment = "comment" '\u{e24c}',
+    CommentBank = "comment_bank" '\u{ea4e}',
+    CommentsDisabled = "comments_disabled" '\u{e7a2}',
+    Commit = "commit" '\u{eaf5}',
+    Communication = "communication" '\u{e27c}',
+    Communities = "communities" '\u{eb16}',
+    CommunitiesFilled = "communities_filled" '\u{eb16}',
+    Commute = "commute" '\u{e940}',
+    Compare = "compare" '\u{e3b9}',
+    CompareArrows = "compare_arrows" '\u{e915}',
+    CompassCalibration = "compass_calibration" '\u{e57c}',
+    ComponentExchange = "component_exchange" '\u{f1e7}',
+    Compost = "compost" '\u{e761}',
+    Compress = "compress" '\u{e94d}',
+    Computer = "computer" '\u{e31e}',
+    Concierge = "concierge" '\u{f561}',
+    Conditions = "conditions" '\u{e0a0}',
+    ConfirmationNumber = "confirmation_number" '\u{e638}',
+    Congenital = "congenital" '\u{e0a1}',
+    ConnectWithoutContact = "connect_without_contact" '\u{f223}',
+    ConnectedTv = "connected_tv" '\u{e998}',
+    ConnectingAirports = "connecting_airports" '\u{e7c9}',
+    Construction = "construction" '\u{ea3c}',
+    ContactEmergency = "contact_emergency" '\u{f8d1}',
+    ContactMail = "contact_mail" '\u{e0d0}',
+    ContactPage = "contact_page" '\u{f22e}',
+    ContactPhone = "contact_phone" '\u{f0c0}',
+    ContactPhoneFilled = "contact_phone_filled" '\u{f0c0}',
+    ContactSupport = "contact_support" '\u{e94c}',
+    Contactless = "contactless" '\u{ea71}',
+    ContactlessOff = "contactless_off" '\u{f858}',
+    Contacts = "contacts" '\u{e0ba}',
+    ContactsProduct = "contacts_product" '\u{e999}',
+    ContentCopy = "content_copy" '\u{e14d}',
+    ContentCut = "content_cut" '\u{e14e}',
+    ContentPaste = "content_paste" '\u{e14f}',
+    ContentPasteGo = "content_paste_go" '\u{ea8e}',
+    ContentPasteOff = "content_paste_off" '\u{e4f8}',
+    ContentPasteSearch = "content_paste_search" '\u{ea9b}',
+    ContextualToken = "contextual_token" '\u{f486}',
+    ContextualTokenAdd = "contextual_token_add" '\u{f485}',
+    Contract = "contract" '\u{f5a0}',
+    ContractDelete = "contract_delete" '\u{f5a2}',
+    ContractEdit = "contract_edit" '\u{f5a1}',
+    Contrast = "contrast" '\u{eb37}',
+    ContrastCircle = "contrast_circle" '\u{f49f}',
+    ContrastRtlOff = "contrast_rtl_off" '\u{ec72}',
+    ContrastSquare = "contrast_square" '\u{f4a0}',
+    ControlCamera = "control_camera" '\u{e074}',
+    ControlPoint = "control_point" '\u{e3ba}',
+    ControlPointDuplicate = "control_point_duplicate" '\u{e3bb}',
+    ControllerGen = "controller_gen" '\u{e83d}',
+    ConversionPath = "conversion_path" '\u{f0c1}',
+    ConversionPathOff = "conversion_path_off" '\u{f7b4}',
+    ConvertToText = "convert_to_text" '\u{f41f}',
+    ConveyorBelt = "conveyor_belt" '\u{f867}',
+    Cookie = "cookie" '\u{eaac}',
+    CookieOff = "cookie_off" '\u{f79a}',
+    Cooking = "cooking" '\u{e2b6}',
+    CoolToDry = "cool_to_dry" '\u{e276}',
+    CopyAll = "copy_all" '\u{e2ec}',
+    Copyright = "copyright" '\u{e90c}',
+    Coronavirus = "coronavirus" '\u{f221}',
+    CorporateFare = "corporate_fare" '\u{f1d0}',
+    Cottage = "cottage" '\u{e587}',
+    Counter0 = "counter_0" '\u{f785}',
+    Counter1 = "counter_1" '\u{f784}',
+    Counter2 = "counter_2" '\u{f783}',
+    Counter3 = "counter_3" '\u{f782}',
+    Counter4 = "counter_4" '\u{f781}',
+    Counter5 = "counter_5" '\u{f780}',
+    Counter6 = "counter_6" '\u{f77f}',
+    Counter7 = "counter_7" '\u{f77e}',
+    Counter8 = "counter_8" '\u{f77d}',
+    Counter9 = "counter_9" '\u{f77c}',
+    Countertops = "countertops" '\u{f1f7}',
+    Create = "create" '\u{f097}',
+    CreateNewFolder = "create_new_folder" '\u{e2cc}',
+    CreditCard = "credit_card" '\u{e8a1}',
+    CreditCardClock = "credit_card_clock" '\u{f438}',
+    CreditCardGear = "credit_card_gear" '\u{f52d}',
+    CreditCardHeart = "credit_card_heart" '\u{f52c}',
+    CreditCardOff = "credit_card_off" '\u{e4f4}',
+    CreditScore = "credit_score" '\u{eff1}',
+    Crib = "crib" '\u{e588}',
+    CrisisAlert = "crisis_alert" '\u{ebe9}',
+    Crop = "crop" '\u{e3be}',
+    Crop169 = "crop_16_9" '\u{e3bc}',
+    Crop32 = "crop_3_2" '\u{e3bd}',
+    Crop54 = "crop_5_4" '\u{e3bf}',
+    Crop75 = "crop_7_5" '\u{e3c0}',
+    Crop916 = "crop_9_16" '\u{f549}',
+    CropDin = "crop_din" '\u{e3c6}',
+    CropFree = "crop_free" '\u{e3c2}',
+    CropLandscape = "crop_landscape" '\u{e3c3}',
+    CropOriginal = "crop_original" '\u{e3f4}',
+    CropPortrait = "crop_portrait" '\u{e3c5}',
+    CropRotate = "crop_rotate" '\u{e437}',
+    CropSquare = "crop_square" '\u{e3c6}',
+    Crossword = "crossword" '\u{f5e5}',
+    Crowdsource = "crowdsource" '\u{eb18}',
+    CrueltyFree = "cruelty_free" '\u{e799}',
+    Css = "css" '\u{eb93}',
+    Csv = "csv" '\u{e6cf}',
+    CurrencyBitcoin = "currency_bitcoin" '\u{ebc5}',
+    CurrencyExchange = "currency_exchange" '\u{eb70}',
+    CurrencyFranc = "currency_franc" '\u{eafa}',
+    CurrencyLira = "currency_lira" '\u{eaef}',
+    CurrencyPound = "currency_pound" '\u{eaf1}',
+    CurrencyRuble = "currency_ruble" '\u{eaec}',
+    CurrencyRupee = "currency_rupee" '\u{eaf7}',
+    CurrencyRupeeCircle = "currency_rupee_circle" '\u{f460}',
+    CurrencyYen = "currency_yen" '\u{eafb}',
+    CurrencyYuan = "currency_yuan" '\u{eaf9}',
+    Curtains = "curtains" '\u{ec1e}',
+    CurtainsClosed = "curtains_closed" '\u{ec1d}',
+    CustomTypography = "custom_typography" '\u{e732}',
+    Cut = "cut" '\u{f08b}',
+    Cycle = "cycle" '\u{f854}',
+    Cyclone = "cyclone" '\u{ebd5}',
+    Dangerous = "dangerous" '\u{e99a}',
+    DarkMode = "dark_mode" '\u{e51c}',
+    Dashboard = "dashboard" '\u{e871}',
+    Dashboard2 = "dashboard_2" '\u{f3ea}',
+    DashboardCustomize = "dashboard_customize" '\u{e99b}',
+    DataAlert = "data_alert" '\u{f7f6}',
+    DataArray = "data_array" '\u{ead1}',
+    DataCheck = "data_check" '\u{f7f2}',
+    DataExploration = "data_exploration" '\u{e76f}',
+    DataInfoAlert = "data_info_alert" '\u{f7f5}',
+    DataLossPrevention = "data_loss_prevention" '\u{e2dc}',
+    DataObject = "data_object" '\u{ead3}',
+    DataSaverOff = "data_saver_off" '\u{eff2}',
+    DataSaverOn = "data_saver_on" '\u{eff3}',
+    DataTable = "data_table" '\u{e99c}',
+    DataThresholding = "data_thresholding" '\u{eb9f}',
+    DataUsage = "data_usage" '\u{eff2}',
+    Database = "database" '\u{f20e}',
+    DatabaseOff = "database_off" '\u{f414}',
+    Dataset = "dataset" '\u{f8ee}',
+    DatasetLinked = "dataset_linked" '\u{f8ef}',
+    DateRange = "date_range" '\u{e916}',
+    Deblur = "deblur" '\u{eb77}',
+    Deceased = "deceased" '\u{e0a5}',
+    DecimalDecrease = "decimal_decrease" '\u{f82d}',
+    DecimalIncrease = "decimal_increase" '\u{f82c}',
+    Deck = "deck" '\u{ea42}',
+    Dehaze = "dehaze" '\u{e3c7}',
+    Delete = "delete" '\u{e92e}',
+    DeleteForever = "delete_forever" '\u{e92b}',
+    DeleteHistory = "delete_history" '\u{f518}',
+    DeleteOutline = "delete_outline" '\u{e92e}',
+    DeleteSweep = "delete_sweep" '\u{e16c}',
+    Demography = "demography" '\u{e489}',
+    DensityLarge = "density_large" '\u{eba9}',
+    DensityMedium = "density_medium" '\u{eb9e}',
+    DensitySmall = "density_small" '\u{eba8}',
+    Dentistry = "dentistry" '\u{e0a6}',
+    DepartureBoard = "departure_board" '\u{e576}',
+    DeployedCode = "deployed_code" '\u{f720}',
+    DeployedCodeAccount = "deployed_code_account" '\u{f51b}',
+    DeployedCodeAlert = "deployed_code_alert" '\u{f5f2}',
+    DeployedCodeHistory = "deployed_code_history" '\u{f5f3}',
+    DeployedCodeUpdate = "deployed_code_update" '\u{f5f4}',
+    Dermatology = "dermatology" '\u{e0a7}',
+    Description = "description" '\u{e873}',
+    Deselect = "deselect" '\u{ebb6}',
+    DesignServices = "design_services" '\u{f10a}',
+    Desk = "desk" '\u{f8f4}',
+    Deskphone = "deskphone" '\u{f7fa}',
+    DesktopAccessDisabled = "desktop_access_disabled" '\u{e99d}',
+    DesktopLandscape = "desktop_landscape" '\u{f45e}',
+    DesktopLandscapeAdd = "desktop_landscape_add" '\u{f439}',
+    DesktopMac = "desktop_mac" '\u{e30b}',
+    DesktopPortrait = "desktop_portrait" '\u{f45d}',
+    DesktopWindows = "desktop_windows" '\u{e30c}',
+    Destruction = "destruction" '\u{f585}',
+    Details = "details" '\u{e3c8}',
+    DetectionAndZone = "detection_and_zone" '\u{e29f}',
+    Detector = "detector" '\u{e282}',
+    DetectorAlarm = "detector_alarm" '\u{e1f7}',
+    DetectorBattery = "detector_battery" '\u{e204}',
+    DetectorCo = "detector_co" '\u{e2af}',
+    DetectorOffline = "detector_offline" '\u{e223}',
+    DetectorSmoke = "detector_smoke" '\u{e285}',
+    DetectorStatus = "detector_status" '\u{e1e8}',
+    DeveloperBoard = "developer_board" '\u{e30d}',
+    DeveloperBoardOff = "developer_board_off" '\u{e4ff}',
+    DeveloperGuide = "developer_guide" '\u{e99e}',
+    DeveloperMode = "developer_mode" '\u{e1b0}',
+    DeveloperModeTv = "developer_mode_tv" '\u{e874}',
+    DeviceHub = "device_hub" '\u{e335}',
+    DeviceReset = "device_reset" '\u{e8b3}',
+    DeviceThermostat = "device_thermostat" '\u{e1ff}',
+    DeviceUnknown = "device_unknown" '\u{e339}',
+    Devices = "devices" '\u{e326}',
+    DevicesFold = "devices_fold" '\u{ebde}',
+    DevicesFold2 = "devices_fold_2" '\u{f406}',
+    DevicesOff = "devices_off" '\u{f7a5}',
+    DevicesOther = "devices_other" '\u{e337}',
+    DevicesWearables = "devices_wearables" '\u{f6ab}',
+    DewPoint = "dew_point" '\u{f879}',
+    Diagnosis = "diagnosis" '\u{e0a8}',
+    DiagonalLine = "diagonal_line" '\u{f41e}',
+    DialerSip = "dialer_sip" '\u{e0bb}',
+    Dialogs = "dialogs" '\u{e99f}',
+    Dialpad = "dialpad" '\u{e0bc}',
+    Diamond = "diamond" '\u{ead5}',
+    Dictionary = "dictionary" '\u{f539}',
+    Difference = "difference" '\u{eb7d}',
+    DigitalOutOfHome = "digital_out_of_home" '\u{f1de}',
+    DigitalWellbeing = "digital_wellbeing" '\u{ef86}',
+    Dining = "dining" '\u{eff4}',
+    DinnerDining = "dinner_dining" '\u{ea57}',
+    Directions = "directions" '\u{e52e}',
+    DirectionsAlt = "directions_alt" '\u{f880}',
+    DirectionsAltOff = "directions_alt_off" '\u{f881}',
+    DirectionsBike = "directions_bike" '\u{e52f}',
+    DirectionsBoat = "directions_boat" '\u{eff5}',
+    DirectionsBoatFilled = "directions_boat_filled" '\u{eff5}',
+    DirectionsBus = "directions_bus" '\u{eff6}',
+    DirectionsBusFilled = "directions_bus_filled" '\u{eff6}',
+    DirectionsCar = "directions_car" '\u{eff7}',
+    DirectionsCarFilled = "directions_car_filled" '\u{eff7}',
+    DirectionsOff = "directions_off" '\u{f10f}',
+    DirectionsRailway = "directions_railway" '\u{eff8}',
+    DirectionsRailway2 = "directions_railway_2" '\u{f462}',
+    DirectionsRailwayFilled = "directions_railway_filled" '\u{eff8}',
+    DirectionsRun = "directions_run" '\u{e566}',
+    DirectionsSubway = "directions_subway" '\u{effa}',
+    DirectionsSubwayFilled = "directions_subway_filled" '\u{effa}',
+    DirectionsTransit = "directions_transit" '\u{effa}',
+    DirectionsTransitFilled = "directions_transit_filled" '\u{effa}',
+    DirectionsWalk = "directions_walk" '\u{e536}',
+    DirectorySync = "directory_sync" '\u{e394}',
+    DirtyLens = "dirty_lens" '\u{ef4b}',
+    DisabledByDefault = "disabled_by_default" '\u{f230}',
+    DisabledVisible = "disabled_visible" '\u{e76e}',
+    DiscFull = "disc_full" '\u{e610}',
+    DiscoverTune = "discover_tune" '\u{e018}',
+    Dishwasher = "dishwasher" '\u{e9a0}',
+    DishwasherGen = "dishwasher_gen" '\u{e832}',
+    DisplayExternalInput = "display_external_input" '\u{f7e7}',
+    DisplaySettings = "display_settings" '\u{eb97}',
+    Distance = "distance" '\u{f6ea}',
+    Diversity1 = "diversity_1" '\u{f8d7}',
+    Diversity2 = "diversity_2" '\u{f8d8}',
+    Diversity3 = "diversity_3" '\u{f8d9}',
+    Diversity4 = "diversity_4" '\u{f857}',
+    Dns = "dns" '\u{e875}',
+    DoDisturb = "do_disturb" '\u{f08c}',
+    DoDisturbAlt = "do_disturb_alt" '\u{f08d}',
+    DoDisturbOff = "do_disturb_off" '\u{f08e}',
+    DoDisturbOn = "do_disturb_on" '\u{f08f}',
+    DoNotDisturb = "do_not_disturb" '\u{f08d}',
+    DoNotDisturbAlt = "do_not_disturb_alt" '\u{f08c}',
+    DoNotDisturbOff = "do_not_disturb_off" '\u{f08e}',
+    DoNotDisturbOn = "do_not_disturb_on" '\u{f08f}',
+    DoNotDisturbOnTotalSilence = "do_not_disturb_on_total_silence" '\u{effb}',
+    DoNotStep = "do_not_step" '\u{f19f}',
+    DoNotTouch = "do_not_touch" '\u{f1b0}',
+    Dock = "dock" '\u{e30e}',
+    DockToBottom = "dock_to_bottom" '\u{f7e6}',
+    DockToLeft = "dock_to_left" '\u{f7e5}',
+    DockToRight = "dock_to_right" '\u{f7e4}',
+    DocsAddOn = "docs_add_on" '\u{f0c2}',
+    DocsAppsScript = "docs_apps_script" '\u{f0c3}',
+    DocumentScanner = "document_scanner" '\u{e5fa}',
+    Domain = "domain" '\u{e7ee}',
+    DomainAdd = "domain_add" '\u{eb62}',
+    DomainDisabled = "domain_disabled" '\u{e0ef}',
+    DomainVerification = "domain_verification" '\u{ef4c}',
+    DomainVerificationOff = "domain_verification_off" '\u{f7b0}',
+    DominoMask = "domino_mask" '\u{f5e4}',
+    Done = "done" '\u{e876}',
+    DoneAll = "done_all" '\u{e877}',
+    DoneOutline = "done_outline" '\u{e92f}',
+    DonutLarge = "donut_large" '\u{e917}',
+    DonutSmall = "donut_small" '\u{e918}',
+    DoorBack = "door_back" '\u{effc}',
+    DoorFront = "door_front" '\u{effd}',
+    DoorOpen = "door_open" '\u{e77c}',
+    DoorSensor = "door_sensor" '\u{e28a}',
+    DoorSliding = "door_sliding" '\u{effe}',
+    Doorbell = "doorbell" '\u{efff}',
+    Doorbell3p = "doorbell_3p" '\u{e1e7}',
+    DoorbellChime = "doorbell_chime" '\u{e1f3}',
+    DoubleArrow = "double_arrow" '\u{ea50}',
+    DownhillSkiing = "downhill_skiing" '\u{e509}',
+    Download = "download" '\u{f090}',
+    Download2 = "download_2" '\u{f523}',
+    DownloadDone = "download_done" '\u{f091}',
+    DownloadForOffline = "download_for_offline" '\u{f000}',
+    Downloading = "downloading" '\u{f001}',
+    Draft = "draft" '\u{e66d}',
+    DraftOrders = "draft_orders" '\u{e7b3}',
+    Drafts = "drafts" '\u{e151}',
+    DragClick = "drag_click" '\u{f71f}',
+    DragHandle = "drag_handle" '\u{e25d}',
+    DragIndicator = "drag_indicator" '\u{e945}',
+    DragPan = "drag_pan" '\u{f71e}',
+    Draw = "draw" '\u{e746}',
+    DrawAbstract = "draw_abstract" '\u{f7f8}',
+    DrawCollage = "draw_collage" '\u{f7f7}',
+    DrawingRecognition = "drawing_recognition" '\u{eb00}',
+    Dresser = "dresser" '\u{e210}',
+    DriveEta = "drive_eta" '\u{eff7}',
+    DriveExport = "drive_export" '\u{f41d}',
+    DriveFileMove = "drive_file_move" '\u{e9a1}',
+    DriveFileMoveOutline = "drive_file_move_outline" '\u{e9a1}',
+    DriveFileMoveRtl = "drive_file_move_rtl" '\u{e9a1}',
+    DriveFileRenameOutline = "drive_file_rename_outline" '\u{e9a2}',
+    DriveFolderUpload = "drive_folder_upload" '\u{e9a3}',
+    DriveFusiontable = "drive_fusiontable" '\u{e678}',
+    Dropdown = "dropdown" '\u{e9a4}',
+    Dry = "dry" '\u{f1b3}',
+    DryCleaning = "dry_cleaning" '\u{ea58}',
+    DualScreen = "dual_screen" '\u{f6cf}',
+    Duo = "duo" '\u{e9a5}',
+    Dvr = "dvr" '\u{e1b2}',
+    DynamicFeed = "dynamic_feed" '\u{ea14}',
+    DynamicForm = "dynamic_form" '\u{f1bf}',
+    E911Avatar = "e911_avatar" '\u{f11a}',
+    E911Emergency = "e911_emergency" '\u{f119}',
+    EMobiledata = "e_mobiledata" '\u{f002}',
+    EMobiledataBadge = "e_mobiledata_badge" '\u{f7e3}',
+    Earbuds = "earbuds" '\u{f003}',
+    EarbudsBattery = "earbuds_battery" '\u{f004}',
+    EarlyOn = "early_on" '\u{e2ba}',
+    Earthquake = "earthquake" '\u{f64f}',
+    East = "east" '\u{f1df}',
+    Ecg = "ecg" '\u{f80f}',
+    EcgHeart = "ecg_heart" '\u{f6e9}',
+    Eco = "eco" '\u{ea35}',
+    Eda = "eda" '\u{f6e8}',
+    EdgesensorHigh = "edgesensor_high" '\u{f005}',
+    EdgesensorLow = "edgesensor_low" '\u{f006}',
+    Edit = "edit" '\u{f097}',
+    EditAttributes = "edit_attributes" '\u{e578}',
+    EditAudio = "edit_audio" '\u{f42d}',
+    EditCalendar = "edit_calendar" '\u{e742}',
+    EditDocument = "edit_document" '\u{f88c}',
+    EditLocation = "edit_location" '\u{e568}',
+    EditLocationAlt = "edit_location_alt" '\u{e1c5}',
+    EditNote = "edit_note" '\u{e745}',
+    EditNotifications = "edit_notifications" '\u{e525}',
+    EditOff = "edit_off" '\u{e950}',
+    EditRoad = "edit_road" '\u{ef4d}',
+    EditSquare = "edit_square" '\u{f88d}',
+    EditorChoice = "editor_choice" '\u{f528}',
+    Egg = "egg" '\u{eacc}',
+    EggAlt = "egg_alt" '\u{eac8}',
+    Eject = "eject" '\u{e8fb}',
+    Elderly = "elderly" '\u{f21a}',
+    ElderlyWoman = "elderly_woman" '\u{eb69}',
+    ElectricBike = "electric_bike" '\u{eb1b}',
+    ElectricBolt = "electric_bolt" '\u{ec1c}',
+    ElectricCar = "electric_car" '\u{eb1c}',
+    ElectricMeter = "electric_meter" '\u{ec1b}',
+    ElectricMoped = "electric_moped" '\u{eb1d}',
+    ElectricRickshaw = "electric_rickshaw" '\u{eb1e}',
+    ElectricScooter = "electric_scooter" '\u{eb1f}',
+    ElectricalServices = "electrical_services" '\u{f102}',
+    Elevation = "elevation" '\u{f6e7}',
+    Elevator = "elevator" '\u{f1a0}',
+    Email = "email" '\u{e159}',
+    Emergency = "emergency" '\u{e1eb}',
+    EmergencyHeat = "emergency_heat" '\u{f15d}',
+    EmergencyHeat2 = "emergency_heat_2" '\u{f4e5}',
+    EmergencyHome = "emergency_home" '\u{e82a}',
+    EmergencyRecording = "emergency_recording" '\u{ebf4}',
+    EmergencyShare = "emergency_share" '\u{ebf6}',
+    EmergencyShareOff = "emergency_share_off" '\u{f59e}',
+    EmojiEmotions = "emoji_emotions" '\u{ea22}',
+    EmojiEvents = "emoji_events" '\u{ea23}',
+    EmojiFlags = "emoji_flags" '\u{f0c6}',
+    EmojiFoodBeverage = "emoji_food_beverage" '\u{ea1b}',
+    EmojiLanguage = "emoji_language" '\u{f4cd}',
+    EmojiNature = "emoji_nature" '\u{ea1c}',
+    EmojiObjects = "emoji_objects" '\u{ea24}',
+    EmojiPeople = "emoji_people" '\u{ea1d}',
+    EmojiSymbols = "emoji_symbols" '\u{ea1e}',
+    EmojiTransportation = "emoji_transportation" '\u{ea1f}',
+    Emoticon = "emoticon" '\u{e5f3}',
+    EmptyDashboard = "empty_dashboard" '\u{f844}',
+    Enable = "enable" '\u{f188}',
+    Encrypted = "encrypted" '\u{e593}',
+    EncryptedAdd = "encrypted_add" '\u{f429}',
+    EncryptedAddCircle = "encrypted_add_circle" '\u{f42a}',
+    EncryptedMinusCircle = "encrypted_minus_circle" '\u{f428}',
+    EncryptedOff = "encrypted_off" '\u{f427}',
+    Endocrinology = "endocrinology" '\u{e0a9}',
+    Energy = "energy" '\u{e9a6}',
+    EnergyProgramSaving = "energy_program_saving" '\u{f15f}',
+    EnergyProgramTimeUsed = "energy_program_time_used" '\u{f161}',
+    EnergySavingsLeaf = "energy_savings_leaf" '\u{ec1a}',
+    Engineering = "engineering" '\u{ea3d}',
+    EnhancedEncryption = "enhanced_encryption" '\u{e63f}',
+    Ent = "ent" '\u{e0aa}',
+    Enterprise = "enterprise" '\u{e70e}',
+    EnterpriseOff = "enterprise_off" '\u{eb4d}',
+    Equal = "equal" '\u{f77b}',
+    Equalizer = "equalizer" '\u{e01d}',
+    EraserSize1 = "eraser_size_1" '\u{f3fc}',
+    EraserSize2 = "eraser_size_2" '\u{f3fb}',
+    EraserSize3 = "eraser_size_3" '\u{f3fa}',
+    EraserSize4 = "eraser_size_4" '\u{f3f9}',
+    EraserSize5 = "eraser_size_5" '\u{f3f8}',
+    Error = "error" '\u{f8b6}',
+    ErrorCircleRounded = "error_circle_rounded" '\u{f8b6}',
+    ErrorMed = "error_med" '\u{e49b}',
+    ErrorOutline = "error_outline" '\u{f8b6}',
+    Escalator = "escalator" '\u{f1a1}',
+    EscalatorWarning = "escalator_warning" '\u{f1ac}',
+    Euro = "euro" '\u{ea15}',
+    EuroSymbol = "euro_symbol" '\u{e926}',
+    EvCharger = "ev_charger" '\u{e56d}',
+    EvMobiledataBadge = "ev_mobiledata_badge" '\u{f7e2}',
+    EvShadow = "ev_shadow" '\u{ef8f}',
+    EvShadowAdd = "ev_shadow_add" '\u{f580}',
+    EvShadowMinus = "ev_shadow_minus" '\u{f57f}',
+    EvStation = "ev_station" '\u{e56d}',
+    Event = "event" '\u{e878}',
+    EventAvailable = "event_available" '\u{e614}',
+    EventBusy = "event_busy" '\u{e615}',
+    EventList = "event_list" '\u{f683}',
+    EventNote = "event_note" '\u{e616}',
+    EventRepeat = "event_repeat" '\u{eb7b}',
+    EventSeat = "event_seat" '\u{e903}',
+    EventUpcoming = "event_upcoming" '\u{f238}',
+    Exclamation = "exclamation" '\u{f22f}',
+    Exercise = "exercise" '\u{f6e6}',
+    ExitToApp = "exit_to_app" '\u{e879}',
+    Expand = "expand" '\u{e94f}',
+    ExpandAll = "expand_all" '\u{e946}',
+    ExpandCircleDown = "expand_circle_down" '\u{e7cd}',
+    ExpandCircleRight = "expand_circle_right" '\u{f591}',
+    ExpandCircleUp = "expand_circle_up" '\u{f5d2}',
+    ExpandContent = "expand_content" '\u{f830}',
+    ExpandLess = "expand_less" '\u{e5ce}',
+    ExpandMore = "expand_more" '\u{e5cf}',
+    Experiment = "experiment" '\u{e686}',
+    Explicit = "explicit" '\u{e01e}',
+    Explore = "explore" '\u{e87a}',
+    ExploreNearby = "explore_nearby" '\u{e538}',
+    ExploreOff = "explore_off" '\u{e9a8}',
+    Explosion = "explosion" '\u{f685}',
+    ExportNotes = "export_notes" '\u{e0ac}',
+    Exposure = "exposure" '\u{e3f6}',
+    ExposureNeg1 = "exposure_neg_1" '\u{e3cb}',
+    ExposureNeg2 = "exposure_neg_2" '\u{e3cc}',
+    ExposurePlus1 = "exposure_plus_1" '\u{e800}',
+    ExposurePlus2 = "exposure_plus_2" '\u{e3ce}',
+    ExposureZero = "exposure_zero" '\u{e3cf}',
+    Extension = "extension" '\u{e87b}',
+    ExtensionOff = "extension_off" '\u{e4f5}',
+    EyeTracking = "eye_tracking" '\u{f4c9}',
+    Eyeglasses = "eyeglasses" '\u{f6ee}',
+    Face = "face" '\u{f008}',
+    Face2 = "face_2" '\u{f8da}',
+    Face3 = "face_3" '\u{f8db}',
+    Face4 = "face_4" '\u{f8dc}',
+    Face5 = "face_5" '\u{f8dd}',
+    Face6 = "face_6" '\u{f8de}',
+    FaceDown = "face_down" '\u{f402}',
+    FaceLeft = "face_left" '\u{f401}',
+    FaceNod = "face_nod" '\u{f400}',
+    FaceRetouchingNatural = "face_retouching_natural" '\u{ef4e}',
+    FaceRetouchingOff = "face_retouching_off" '\u{f007}',
+    FaceRight = "face_right" '\u{f3ff}',
+    FaceShake = "face_shake" '\u{f3fe}',
+    FaceUnlock = "face_unlock" '\u{f008}',
+    FaceUp = "face_up" '\u{f3fd}',
+    FactCheck = "fact_check" '\u{f0c5}',
+    Factory = "factory" '\u{ebbc}',
+    Falling = "falling" '\u{f60d}',
+    FamiliarFaceAndZone = "familiar_face_and_zone" '\u{e21c}',
+    FamilyHistory = "family_history" '\u{e0ad}',
+    FamilyHome = "family_home" '\u{eb26}',
+    FamilyLink = "family_link" '\u{eb19}',
+    FamilyRestroom = "family_restroom" '\u{f1a2}',
+    FamilyStar = "family_star" '\u{f527}',
+    FarsightDigital = "farsight_digital" '\u{f559}',
+    FastForward = "fast_forward" '\u{e01f}',
+    FastRewind = "fast_rewind" '\u{e020}',
+    Fastfood = "fastfood" '\u{e57a}',
+    Faucet = "faucet" '\u{e278}',
+    Favorite = "favorite" '\u{e87e}',
+    FavoriteBorder = "favorite_border" '\u{e87e}',
+    Fax = "fax" '\u{ead8}',
+    FeatureSearch = "feature_search" '\u{e9a9}',
+    FeaturedPlayList = "featured_play_list" '\u{e06d}',
+    FeaturedSeasonalAndGifts = "featured_seasonal_and_gifts" '\u{ef91}',
+    FeaturedVideo = "featured_video" '\u{e06e}',
+    Feed = "feed" '\u{f009}',
+    Feedback = "feedback" '\u{e87f}',
+    Female = "female" '\u{e590}',
+    Femur = "femur" '\u{f891}',
+    FemurAlt = "femur_alt" '\u{f892}',
+    Fence = "fence" '\u{f1f6}',
+    Fertile = "fertile" '\u{f6e5}',
+    Festival = "festival" '\u{ea68}',
+    FiberDvr = "fiber_dvr" '\u{e05d}',
+    FiberManualRecord = "fiber_manual_record" '\u{e061}',
+    FiberNew = "fiber_new" '\u{e05e}',
+    FiberPin = "fiber_pin" '\u{e06a}',
+    FiberSmartRecord = "fiber_smart_record" '\u{e062}',
+    FileCopy = "file_copy" '\u{e173}',
+    FileCopyOff = "file_copy_off" '\u{f4d8}',
+    FileDownload = "file_download" '\u{f090}',
+    FileDownloadDone = "file_download_done" '\u{f091}',
+    FileDownloadOff = "file_download_off" '\u{e4fe}',
+    FileMap = "file_map" '\u{e2c5}',
+    FileOpen = "file_open" '\u{eaf3}',
+    FilePresent = "file_present" '\u{ea0e}',
+    FileSave = "file_save" '\u{f17f}',
+    FileSaveOff = "file_save_off" '\u{e505}',
+    FileUpload = "file_upload" '\u{f09b}',
+    FileUploadOff = "file_upload_off" '\u{f886}',
+    Filter = "filter" '\u{e3d3}',
+    Filter1 = "filter_1" '\u{e3d0}',
+    Filter2 = "filter_2" '\u{e3d1}',
+    Filter3 = "filter_3" '\u{e3d2}',
+    Filter4 = "filter_4" '\u{e3d4}',
+    Filter5 = "filter_5" '\u{e3d5}',
+    Filter6 = "filter_6" '\u{e3d6}',
+    Filter7 = "filter_7" '\u{e3d7}',
+    Filter8 = "filter_8" '\u{e3d8}',
+    Filter9 = "filter_9" '\u{e3d9}',
+    Filter9Plus = "filter_9_plus" '\u{e3da}',
+    FilterAlt = "filter_alt" '\u{ef4f}',
+    FilterAltOff = "filter_alt_off" '\u{eb32}',
+    FilterBAndW = "filter_b_and_w" '\u{e3db}',
+    FilterCenterFocus = "filter_center_focus" '\u{e3dc}',
+    FilterDrama = "filter_drama" '\u{e3dd}',
+    FilterFrames = "filter_frames" '\u{e3de}',
+    FilterHdr = "filter_hdr" '\u{e3df}',
+    FilterList = "filter_list" '\u{e152}',
+    FilterListAlt = "filter_list_alt" '\u{e94e}',
+    FilterListOff = "filter_list_off" '\u{eb57}',
+    FilterNone = "filter_none" '\u{e3e0}',
+    FilterRetrolux = "filter_retrolux" '\u{e3e1}',
+    FilterTiltShift = "filter_tilt_shift" '\u{e3e2}',
+    FilterVintage = "filter_vintage" '\u{e3e3}',
+    Finance = "finance" '\u{e6bf}',
+    FinanceChip = "finance_chip" '\u{f84e}',
+    FinanceMode = "finance_mode" '\u{ef92}',
+    FindInPage = "find_in_page" '\u{e880}',
+    FindReplace = "find_replace" '\u{e881}',
+    Fingerprint = "fingerprint" '\u{e90d}',
+    FingerprintOff = "fingerprint_off" '\u{f49d}',
+    FireExtinguisher = "fire_extinguisher" '\u{f1d8}',
+    FireHydrant = "fire_hydrant" '\u{f1a3}',
+    FireTruck = "fire_truck" '\u{f8f2}',
+    Fireplace = "fireplace" '\u{ea43}',
+    FirstPage = "first_page" '\u{e5dc}',
+    FitPage = "fit_page" '\u{f77a}',
+    FitScreen = "fit_screen" '\u{ea10}',
+    FitWidth = "fit_width" '\u{f779}',
+    FitnessCenter = "fitness_center" '\u{eb43}',
+    FitnessTracker = "fitness_tracker" '\u{f463}',
+    Flag = "flag" '\u{f0c6}',
+    Flag2 = "flag_2" '\u{f40f}',
+    FlagCircle = "flag_circle" '\u{eaf8}',
+    FlagFilled = "flag_filled" '\u{f0c6}',
+    Flaky = "flaky" '\u{ef50}',
+    Flare = "flare" '\u{e3e4}',
+    FlashAuto = "flash_auto" '\u{e3e5}',
+    FlashOff = "flash_off" '\u{e3e6}',
+    FlashOn = "flash_on" '\u{e3e7}',
+    FlashlightOff = "flashlight_off" '\u{f00a}',
+    FlashlightOn = "flashlight_on" '\u{f00b}',
+    Flatware = "flatware" '\u{f00c}',
+    FlexDirection = "flex_direction" '\u{f778}',
+    FlexNoWrap = "flex_no_wrap" '\u{f777}',
+    FlexWrap = "flex_wrap" '\u{f776}',
+    Flight = "flight" '\u{e539}',
+    FlightClass = "flight_class" '\u{e7cb}',
+    FlightLand = "flight_land" '\u{e904}',
+    FlightTakeoff = "flight_takeoff" '\u{e905}',
+    FlightsAndHotels = "flights_and_hotels" '\u{e9ab}',
+    Flightsmode = "flightsmode" '\u{ef93}',
+    Flip = "flip" '\u{e3e8}',
+    FlipCameraAndroid = "flip_camera_android" '\u{ea37}',
+    FlipCameraIos = "flip_camera_ios" '\u{ea38}',
+    FlipToBack = "flip_to_back" '\u{e882}',
+    FlipToFront = "flip_to_front" '\u{e883}',
+    FloatLandscape2 = "float_landscape_2" '\u{f45c}',
+    FloatPortrait2 = "float_portrait_2" '\u{f45b}',
+    Flood = "flood" '\u{ebe6}',
+    Floor = "floor" '\u{f6e4}',
+    FloorLamp = "floor_lamp" '\u{e21e}',
+    Flourescent = "flourescent" '\u{f07d}',
+    Flowsheet = "flowsheet" '\u{e0ae}',
+    Fluid = "fluid" '\u{e483}',
+    FluidBalance = "fluid_balance" '\u{f80d}',
+    FluidMed = "fluid_med" '\u{f80c}',
+    Fluorescent = "fluorescent" '\u{f07d}',
+    Flutter = "flutter" '\u{f1dd}',
+    FlutterDash = "flutter_dash" '\u{e00b}',
+    Flyover = "flyover" '\u{f478}',
+    FmdBad = "fmd_bad" '\u{f00e}',
+    FmdGood = "fmd_good" '\u{f1db}',
+    Foggy = "foggy" '\u{e818}',
+    FoldedHands = "folded_hands" '\u{f5ed}',
+    Folder = "folder" '\u{e2c7}',
+    FolderCopy = "folder_copy" '\u{ebbd}',
+    FolderData = "folder_data" '\u{f586}',
+    FolderDelete = "folder_delete" '\u{eb34}',
+    FolderLimited = "folder_limited" '\u{f4e4}',
+    FolderManaged = "folder_managed" '\u{f775}',
+    FolderOff = "folder_off" '\u{eb83}',
+    FolderOpen = "folder_open" '\u{e2c8}',
+    FolderShared = "folder_shared" '\u{e2c9}',
+    FolderSpecial = "folder_special" '\u{e617}',
+    FolderSupervised = "folder_supervised" '\u{f774}',
+    FolderZip = "folder_zip" '\u{eb2c}',
+    FollowTheSigns = "follow_the_signs" '\u{f222}',
+    FontDownload = "font_download" '\u{e167}',
+    FontDownloadOff = "font_download_off" '\u{e4f9}',
+    FoodBank = "food_bank" '\u{f1f2}',
+    FootBones = "foot_bones" '\u{f893}',
+    Footprint = "footprint" '\u{f87d}',
+    ForYou = "for_you" '\u{e9ac}',
+    Forest = "forest" '\u{ea99}',
+    ForkLeft = "fork_left" '\u{eba0}',
+    ForkRight = "fork_right" '\u{ebac}',
+    Forklift = "forklift" '\u{f868}',
+    FormatAlignCenter = "format_align_center" '\u{e234}',
+    FormatAlignJustify = "format_align_justify" '\u{e235}',
+    FormatAlignLeft = "format_align_left" '\u{e236}',
+    FormatAlignRight = "format_align_right" '\u{e237}',
+    FormatBold = "format_bold" '\u{e238}',
+    FormatClear = "format_clear" '\u{e239}',
+    FormatColorFill = "format_color_fill" '\u{e23a}',
+    FormatColorReset = "format_color_reset" '\u{e23b}',
+    FormatColorText = "format_color_text" '\u{e23c}',
+    FormatH1 = "format_h1" '\u{f85d}',
+    FormatH2 = "format_h2" '\u{f85e}',
+    FormatH3 = "format_h3" '\u{f85f}',
+    FormatH4 = "format_h4" '\u{f860}',
+    FormatH5 = "format_h5" '\u{f861}',
+    FormatH6 = "format_h6" '\u{f862}',
+    FormatImageLeft = "format_image_left" '\u{f863}',
+    FormatImageRight = "format_image_right" '\u{f864}',
+    FormatIndentDecrease = "format_indent_decrease" '\u{e23d}',
+    FormatIndentIncrease = "format_indent_increase" '\u{e23e}',
+    FormatInkHighlighter = "format_ink_highlighter" '\u{f82b}',
+    FormatItalic = "format_italic" '\u{e23f}',
+    FormatLetterSpacing = "format_letter_spacing" '\u{f773}',
+    FormatLetterSpacing2 = "format_letter_spacing_2" '\u{f618}',
+    FormatLetterSpacingStandard = "format_letter_spacing_standard" '\u{f617}',
+    FormatLetterSpacingWide = "format_letter_spacing_wide" '\u{f616}',
+    FormatLetterSpacingWider = "format_letter_spacing_wider" '\u{f615}',
+    FormatLineSpacing = "format_line_spacing" '\u{e240}',
+    FormatListBulleted = "format_list_bulleted" '\u{e241}',
+    FormatListBulletedAdd = "format_list_bulleted_add" '\u{f849}',
+    FormatListNumbered = "format_list_numbered" '\u{e242}',
+    FormatListNumberedRtl = "format_list_numbered_rtl" '\u{e267}',
+    FormatOverline = "format_overline" '\u{eb65}',
+    FormatPaint = "format_paint" '\u{e243}',
+    FormatParagraph = "format_paragraph" '\u{f865}',
+    FormatQuote = "format_quote" '\u{e244}',
+    FormatQuoteOff = "format_quote_off" '\u{f413}',
+    FormatShapes = "format_shapes" '\u{e25e}',
+    FormatSize = "format_size" '\u{e245}',
+    FormatStrikethrough = "format_strikethrough" '\u{e246}',
+    FormatTextClip = "format_text_clip" '\u{f82a}',
+    FormatTextOverflow = "format_text_overflow" '\u{f829}',
+    FormatTextWrap = "format_text_wrap" '\u{f828}',
+    FormatTextdirectionLToR = "format_textdirection_l_to_r" '\u{e247}',
+    FormatTextdirectionRToL = "format_textdirection_r_to_l" '\u{e248}',
+    FormatTextdirectionVertical = "format_textdirection_vertical" '\u{f4b8}',
+    FormatUnderlined = "format_underlined" '\u{e249}',
+    FormatUnderlinedSquiggle = "format_underlined_squiggle" '\u{f885}',
+    FormsAddOn = "forms_add_on" '\u{f0c7}',
+    FormsAppsScript = "forms_apps_script" '\u{f0c8}',
+    Fort = "fort" '\u{eaad}',
+    Forum = "forum" '\u{e8af}',
+    Forward = "forward" '\u{f57a}',
+    Forward10 = "forward_10" '\u{e056}',
+    Forward30 = "forward_30" '\u{e057}',
+    Forward5 = "forward_5" '\u{e058}',
+    ForwardCircle = "forward_circle" '\u{f6f5}',
+    ForwardMedia = "forward_media" '\u{f6f4}',
+    ForwardToInbox = "forward_to_inbox" '\u{f187}',
+    Foundation = "foundation" '\u{f200}',
+    FrameInspect = "frame_inspect" '\u{f772}',
+    FramePerson = "frame_person" '\u{f8a6}',
+    FramePersonMic = "frame_person_mic" '\u{f4d5}',
+    FramePersonOff = "frame_person_off" '\u{f7d1}',
+    FrameReload = "frame_reload" '\u{f771}',
+    FrameSource = "frame_source" '\u{f770}',
+    FreeBreakfast = "free_breakfast" '\u{eb44}',
+    FreeCancellation = "free_cancellation" '\u{e748}',
+    FrontHand = "front_hand" '\u{e769}',
+    FrontLoader = "front_loader" '\u{f869}',
+    FullCoverage = "full_coverage" '\u{eb12}',
+    FullHd = "full_hd" '\u{f58b}',
+    FullStackedBarChart = "full_stacked_bar_chart" '\u{f212}',
+    Fullscreen = "fullscreen" '\u{e5d0}',
+    FullscreenExit = "fullscreen_exit" '\u{e5d1}',
+    FullscreenPortrait = "fullscreen_portrait" '\u{f45a}',
+    Function = "function" '\u{f866}',
+    Functions = "functions" '\u{e24a}',
+    Funicular = "funicular" '\u{f477}',
+    GMobiledata = "g_mobiledata" '\u{f010}',
+    GMobiledataBadge = "g_mobiledata_badge" '\u{f7e1}',
+    GTranslate = "g_translate" '\u{e927}',
+    GalleryThumbnail = "gallery_thumbnail" '\u{f86f}',
+    Gamepad = "gamepad" '\u{e30f}',
+    Games = "games" '\u{e30f}',
+    Garage = "garage" '\u{f011}',
+    GarageDoor = "garage_door" '\u{e714}',
+    GarageHome = "garage_home" '\u{e82d}',
+    GardenCart = "garden_cart" '\u{f8a9}',
+    GasMeter = "gas_meter" '\u{ec19}',
+    Gastroenterology = "gastroenterology" '\u{e0f1}',
+    Gate = "gate" '\u{e277}',
+    Gavel = "gavel" '\u{e90e}',
+    GeneralDevice = "general_device" '\u{e6de}',
+    GeneratingTokens = "generating_tokens" '\u{e749}',
+    Genetics = "genetics" '\u{e0f3}',
+    Genres = "genres" '\u{e6ee}',
+    Gesture = "gesture" '\u{e155}',
+    GestureSelect = "gesture_select" '\u{f657}',
+    GetApp = "get_app" '\u{f090}',
+    Gif = "gif" '\u{e908}',
+    Gif2 = "gif_2" '\u{f40e}',
+    GifBox = "gif_box" '\u{e7a3}',
+    Girl = "girl" '\u{eb68}',
+    Gite = "gite" '\u{e58b}',
+    GlassCup = "glass_cup" '\u{f6e3}',
+    Globe = "globe" '\u{e64c}',
+    GlobeAsia = "globe_asia" '\u{f799}',
+    GlobeUk = "globe_uk" '\u{f798}',
+    Glucose = "glucose" '\u{e4a0}',
+    Glyphs = "glyphs" '\u{f8a3}',
+    GoToLine = "go_to_line" '\u{f71d}',
+    GolfCourse = "golf_course" '\u{eb45}',
+    GondolaLift = "gondola_lift" '\u{f476}',
+    GoogleHomeDevices = "google_home_devices" '\u{e715}',
+    GooglePlusReshare = "google_plus_reshare" '\u{f57a}',
+    GoogleTvRemote = "google_tv_remote" '\u{f5db}',
+    GoogleWifi = "google_wifi" '\u{f579}',
+    GppBad = "gpp_bad" '\u{f012}',
+    GppGood = "gpp_good" '\u{f013}',
+    GppMaybe = "gpp_maybe" '\u{f014}',
+    GpsFixed = "gps_fixed" '\u{e55c}',
+    GpsNotFixed = "gps_not_fixed" '\u{e1b7}',
+    GpsOff = "gps_off" '\u{e1b6}',
+    Grade = "grade" '\u{e885}',
+    Gradient = "gradient" '\u{e3e9}',
+    Grading = "grading" '\u{ea4f}',
+    Grain = "grain" '\u{e3ea}',
+    GraphicEq = "graphic_eq" '\u{e1b8}',
+    Grass = "grass" '\u{f205}',
+    Grid3x3 = "grid_3x3" '\u{f015}',
+    Grid3x3Off = "grid_3x3_off" '\u{f67c}',
+    Grid4x4 = "grid_4x4" '\u{f016}',
+    GridGoldenratio = "grid_goldenratio" '\u{f017}',
+    GridGuides = "grid_guides" '\u{f76f}',
+    GridOff = "grid_off" '\u{e3eb}',
+    GridOn = "grid_on" '\u{e3ec}',
+    GridView = "grid_view" '\u{e9b0}',
+    Grocery = "grocery" '\u{ef97}',
+    Group = "group" '\u{ea21}',
+    GroupAdd = "group_add" '\u{e7f0}',
+    GroupOff = "group_off" '\u{e747}',
+    GroupRemove = "group_remove" '\u{e7ad}',
+    GroupWork = "group_work" '\u{e886}',
+    GroupedBarChart = "grouped_bar_chart" '\u{f211}',
+    Groups = "groups" '\u{f233}',
+    Groups2 = "groups_2" '\u{f8df}',
+    Groups3 = "groups_3" '\u{f8e0}',
+    Guardian = "guardian" '\u{f4c1}',
+    Gynecology = "gynecology" '\u{e0f4}',
+    HMobiledata = "h_mobiledata" '\u{f018}',
+    HMobiledataBadge = "h_mobiledata_badge" '\u{f7e0}',
+    HPlusMobiledata = "h_plus_mobiledata" '\u{f019}',
+    HPlusMobiledataBadge = "h_plus_mobiledata_badge" '\u{f7df}',
+    Hail = "hail" '\u{e9b1}',
+    Hallway = "hallway" '\u{e6f8}',
+    HandBones = "hand_bones" '\u{f894}',
+    HandGesture = "hand_gesture" '\u{ef9c}',
+    HandGestureOff = "hand_gesture_off" '\u{f3f3}',
+    HandheldController = "handheld_controller" '\u{f4c6}',
+    Handshake = "handshake" '\u{ebcb}',
+    HandwritingRecognition = "handwriting_recognition" '\u{eb02}',
+    Handyman = "handyman" '\u{f10b}',
+    HangoutVideo = "hangout_video" '\u{e0c1}',
+    HangoutVideoOff = "hangout_video_off" '\u{e0c2}',
+    HardDrive = "hard_drive" '\u{f80e}',
+    HardDrive2 = "hard_drive_2" '\u{f7a4}',
+    Hardware = "hardware" '\u{ea59}',
+    Hd = "hd" '\u{e052}',
+    HdrAuto = "hdr_auto" '\u{f01a}',
+    HdrAutoSelect = "hdr_auto_select" '\u{f01b}',
+    HdrEnhancedSelect = "hdr_enhanced_select" '\u{ef51}',
+    HdrOff = "hdr_off" '\u{e3ed}',
+    HdrOffSelect = "hdr_off_select" '\u{f01c}',
+    HdrOn = "hdr_on" '\u{e3ee}',
+    HdrOnSelect = "hdr_on_select" '\u{f01d}',
+    HdrPlus = "hdr_plus" '\u{f01e}',
+    HdrPlusOff = "hdr_plus_off" '\u{e3ef}',
+    HdrStrong = "hdr_strong" '\u{e3f1}',
+    HdrWeak = "hdr_weak" '\u{e3f2}',
+    HeadMountedDevice = "head_mounted_device" '\u{f4c5}',
+    Headphones = "headphones" '\u{f01f}',
+    HeadphonesBattery = "headphones_battery" '\u{f020}',
+    Headset = "headset" '\u{f01f}',
+    HeadsetMic = "headset_mic" '\u{e311}',
+    HeadsetOff = "headset_off" '\u{e33a}',
+    Healing = "healing" '\u{e3f3}',
+    HealthAndBeauty = "health_and_beauty" '\u{ef9d}',
+    HealthAndSafety = "health_and_safety" '\u{e1d5}',
+    HealthMetrics = "health_metrics" '\u{f6e2}',
+    HeapSnapshotLarge = "heap_snapshot_large" '\u{f76e}',
+    HeapSnapshotMultiple = "heap_snapshot_multiple" '\u{f76d}',
+    HeapSnapshotThumbnail = "heap_snapshot_thumbnail" '\u{f76c}',
+    Hearing = "hearing" '\u{e023}',
+    HearingAid = "hearing_aid" '\u{f464}',
+    HearingDisabled = "hearing_disabled" '\u{f104}',
+    HeartBroken = "heart_broken" '\u{eac2}',
+    HeartCheck = "heart_check" '\u{f60a}',
+    HeartMinus = "heart_minus" '\u{f883}',
+    HeartPlus = "heart_plus" '\u{f884}',
+    Heat = "heat" '\u{f537}',
+    HeatPump = "heat_pump" '\u{ec18}',
+    HeatPumpBalance = "heat_pump_balance" '\u{e27e}',
+    Height = "height" '\u{ea16}',
+    Helicopter = "helicopter" '\u{f60c}',
+    Help = "help" '\u{e8fd}',
+    HelpCenter = "help_center" '\u{f1c0}',
+    HelpClinic = "help_clinic" '\u{f810}',
+    HelpOutline = "help_outline" '\u{e8fd}',
+    Hematology = "hematology" '\u{e0f6}',
+    Hevc = "hevc" '\u{f021}',
+    Hexagon = "hexagon" '\u{eb39}',
+    Hide = "hide" '\u{ef9e}',
+    HideImage = "hide_image" '\u{f022}',
+    HideSource = "hide_source" '\u{f023}',
+    HighDensity = "high_density" '\u{f79c}',
+    HighQuality = "high_quality" '\u{e024}',
+    HighRes = "high_res" '\u{f54b}',
+    Highlight = "highlight" '\u{e25f}',
+    HighlightKeyboardFocus = "highlight_keyboard_focus" '\u{f510}',
+    HighlightMouseCursor = "highlight_mouse_cursor" '\u{f511}',
+    HighlightOff = "highlight_off" '\u{e888}',
+    HighlightTextCursor = "highlight_text_cursor" '\u{f512}',
+    HighlighterSize1 = "highlighter_size_1" '\u{f76b}',
+    HighlighterSize2 = "highlighter_size_2" '\u{f76a}',
+    HighlighterSize3 = "highlighter_size_3" '\u{f769}',
+    HighlighterSize4 = "highlighter_size_4" '\u{f768}',
+    HighlighterSize5 = "highlighter_size_5" '\u{f767}',
+    Hiking = "hiking" '\u{e50a}',
+    History = "history" '\u{e8b3}',
+    HistoryEdu = "history_edu" '\u{ea3e}',
+    HistoryOff = "history_off" '\u{f4da}',
+    HistoryToggleOff = "history_toggle_off" '\u{f17d}',
+    Hive = "hive" '\u{eaa6}',
+    Hls = "hls" '\u{eb8a}',
+    HlsOff = "hls_off" '\u{eb8c}',
+    HolidayVillage = "holiday_village" '\u{e58a}',
+    Home = "home" '\u{e9b2}',
+    HomeAndGarden = "home_and_garden" '\u{ef9f}',
+    HomeAppLogo = "home_app_logo" '\u{e295}',
+    HomeFilled = "home_filled" '\u{e9b2}',
+    HomeHealth = "home_health" '\u{e4b9}',
+    HomeImprovementAndTools = "home_improvement_and_tools" '\u{efa0}',
+    HomeIotDevice = "home_iot_device" '\u{e283}',
+    HomeMax = "home_max" '\u{f024}',
+    HomeMaxDots = "home_max_dots" '\u{e849}',
+    HomeMini = "home_mini" '\u{f025}',
+    HomePin = "home_pin" '\u{f14d}',
+    HomeRepairService = "home_repair_service" '\u{f100}',
+    HomeSpeaker = "home_speaker" '\u{f11c}',
+    HomeStorage = "home_storage" '\u{f86c}',
+    HomeWork = "home_work" '\u{f030}',
+    HorizontalDistribute = "horizontal_distribute" '\u{e014}',
+    HorizontalRule = "horizontal_rule" '\u{f108}',
+    HorizontalSplit = "horizontal_split" '\u{e947}',
+    HotTub = "hot_tub" '\u{eb46}',
+    Hotel = "hotel" '\u{e549}',
+    HotelClass = "hotel_class" '\u{e743}',
+    Hourglass = "hourglass" '\u{ebff}',
+    HourglassBottom = "hourglass_bottom" '\u{ea5c}',
+    HourglassDisabled = "hourglass_disabled" '\u{ef53}',
+    HourglassEmpty = "hourglass_empty" '\u{e88b}',
+    HourglassFull = "hourglass_full" '\u{e88c}',
+    HourglassTop = "hourglass_top" '\u{ea5b}',
+    House = "house" '\u{ea44}',
+    HouseSiding = "house_siding" '\u{f202}',
+    HouseWithShield = "house_with_shield" '\u{e786}',
+    Houseboat = "houseboat" '\u{e584}',
+    HouseholdSupplies = "household_supplies" '\u{efa1}',
+    Hov = "hov" '\u{f475}',
+    HowToReg = "how_to_reg" '\u{e174}',
+    HowToVote = "how_to_vote" '\u{e175}',
+    HrResting = "hr_resting" '\u{f6ba}',
+    Html = "html" '\u{eb7e}',
+    Http = "http" '\u{e902}',
+    Https = "https" '\u{e899}',
+    Hub = "hub" '\u{e9f4}',
+    Humerus = "humerus" '\u{f895}',
+    HumerusAlt = "humerus_alt" '\u{f896}',
+    HumidityHigh = "humidity_high" '\u{f163}',
+    HumidityIndoor = "humidity_indoor" '\u{f558}',
+    HumidityLow = "humidity_low" '\u{f164}',
+    HumidityMid = "humidity_mid" '\u{f165}',
+    HumidityPercentage = "humidity_percentage" '\u{f87e}',
+    Hvac = "hvac" '\u{f10e}',
+    IceSkating = "ice_skating" '\u{e50b}',
+    Icecream = "icecream" '\u{ea69}',
+    IdCard = "id_card" '\u{f4ca}',
+    Ifl = "ifl" '\u{e025}',
+    Iframe = "iframe" '\u{f71b}',
+    IframeOff = "iframe_off" '\u{f71c}',
+    Image = "image" '\u{e3f4}',
+    ImageAspectRatio = "image_aspect_ratio" '\u{e3f5}',
+    ImageNotSupported = "image_not_supported" '\u{f116}',
+    ImageSearch = "image_search" '\u{e43f}',
+    ImagesearchRoller = "imagesearch_roller" '\u{e9b4}',
+    Imagesmode = "imagesmode" '\u{efa2}',
+    Immunology = "immunology" '\u{e0fb}',
+    ImportContacts = "import_contacts" '\u{e0e0}',
+    ImportExport = "import_export" '\u{e8d5}',
+    ImportantDevices = "important_devices" '\u{e912}',
+    InHomeMode = "in_home_mode" '\u{e833}',
+    InactiveOrder = "inactive_order" '\u{e0fc}',
+    Inbox = "inbox" '\u{e156}',
+    InboxCustomize = "inbox_customize" '\u{f859}',
+    IncompleteCircle = "incomplete_circle" '\u{e79b}',
+    IndeterminateCheckBox = "indeterminate_check_box" '\u{e909}',
+    IndeterminateQuestionBox = "indeterminate_question_box" '\u{f56d}',
+    Info = "info" '\u{e88e}',
+    InfoI = "info_i" '\u{f59b}',
+    Infrared = "infrared" '\u{f87c}',
+    InkEraser = "ink_eraser" '\u{e6d0}',
+    InkEraserOff = "ink_eraser_off" '\u{e7e3}',
+    InkHighlighter = "ink_highlighter" '\u{e6d1}',
+    InkHighlighterMove = "ink_highlighter_move" '\u{f524}',
+    InkMarker = "ink_marker" '\u{e6d2}',
+    InkPen = "ink_pen" '\u{e6d3}',
+    Inpatient = "inpatient" '\u{e0fe}',
+    Input = "input" '\u{e890}',
+    InputCircle = "input_circle" '\u{f71a}',
+    InsertChart = "insert_chart" '\u{f0cc}',
+    InsertChartFilled = "insert_chart_filled" '\u{f0cc}',
+    InsertChartOutlined = "insert_chart_outlined" '\u{f0cc}',
+    InsertComment = "insert_comment" '\u{e24c}',
+    InsertDriveFile = "insert_drive_file" '\u{e66d}',
+    InsertEmoticon = "insert_emoticon" '\u{ea22}',
+    InsertInvitation = "insert_invitation" '\u{e878}',
+    InsertLink = "insert_link" '\u{e250}',
+    InsertPageBreak = "insert_page_break" '\u{eaca}',
+    InsertPhoto = "insert_photo" '\u{e3f4}',
+    InsertText = "insert_text" '\u{f827}',
+    Insights = "insights" '\u{f092}',
+    InstallDesktop = "install_desktop" '\u{eb71}',
+    InstallMobile = "install_mobile" '\u{eb72}',
+    InstantMix = "instant_mix" '\u{e026}',
+    IntegrationInstructions = "integration_instructions" '\u{ef54}',
+    InteractiveSpace = "interactive_space" '\u{f7ff}',
+    Interests = "interests" '\u{e7c8}',
+    InterpreterMode = "interpreter_mode" '\u{e83b}',
+    Inventory = "inventory" '\u{e179}',
+    Inventory2 = "inventory_2" '\u{e1a1}',
+    InvertColors = "invert_colors" '\u{e891}',
+    InvertColorsOff = "invert_colors_off" '\u{e0c4}',
+    Ios = "ios" '\u{e027}',
+    IosShare = "ios_share" '\u{e6b8}',
+    Iron = "iron" '\u{e583}',
+    Iso = "iso" '\u{e3f6}',
+    JamboardKiosk = "jamboard_kiosk" '\u{e9b5}',
+    Javascript = "javascript" '\u{eb7c}',
+    Join = "join" '\u{f84f}',
+    JoinFull = "join_full" '\u{f84f}',
+    JoinInner = "join_inner" '\u{eaf4}',
+    JoinLeft = "join_left" '\u{eaf2}',
+    JoinRight = "join_right" '\u{eaea}',
+    Joystick = "joystick" '\u{f5ee}',
+    JumpToElement = "jump_to_element" '\u{f719}',
+    Kayaking = "kayaking" '\u{e50c}',
+    KebabDining = "kebab_dining" '\u{e842}',
+    Keep = "keep" '\u{f026}',
+    KeepOff = "keep_off" '\u{e6f9}',
+    KeepPin = "keep_pin" '\u{f026}',
+    KeepPublic = "keep_public" '\u{f56f}',
+    Kettle = "kettle" '\u{e2b9}',
+    Key = "key" '\u{e73c}',
+    KeyOff = "key_off" '\u{eb84}',
+    KeyVertical = "key_vertical" '\u{f51a}',
+    KeyVisualizer = "key_visualizer" '\u{f199}',
+    Keyboard = "keyboard" '\u{e312}',
+    KeyboardAlt = "keyboard_alt" '\u{f028}',
+    KeyboardArrowDown = "keyboard_arrow_down" '\u{e313}',
+    KeyboardArrowLeft = "keyboard_arrow_left" '\u{e314}',
+    KeyboardArrowRight = "keyboard_arrow_right" '\u{e315}',
+    KeyboardArrowUp = "keyboard_arrow_up" '\u{e316}',
+    KeyboardBackspace = "keyboard_backspace" '\u{e317}',
+    KeyboardCapslock = "keyboard_capslock" '\u{e318}',
+    KeyboardCapslockBadge = "keyboard_capslock_badge" '\u{f7de}',
+    KeyboardCommandKey = "keyboard_command_key" '\u{eae7}',
+    KeyboardControlKey = "keyboard_control_key" '\u{eae6}',
+    KeyboardDoubleArrowDown = "keyboard_double_arrow_down" '\u{ead0}',
+    KeyboardDoubleArrowLeft = "keyboard_double_arrow_left" '\u{eac3}',
+    KeyboardDoubleArrowRight = "keyboard_double_arrow_right" '\u{eac9}',
+    KeyboardDoubleArrowUp = "keyboard_double_arrow_up" '\u{eacf}',
+    KeyboardExternalInput = "keyboard_external_input" '\u{f7dd}',
+    KeyboardFull = "keyboard_full" '\u{f7dc}',
+    KeyboardHide = "keyboard_hide" '\u{e31a}',
+    KeyboardKeys = "keyboard_keys" '\u{f67b}',
+    KeyboardLock = "keyboard_lock" '\u{f492}',
+    KeyboardLockOff = "keyboard_lock_off" '\u{f491}',
+    KeyboardOff = "keyboard_off" '\u{f67a}',
+    KeyboardOnscreen = "keyboard_onscreen" '\u{f7db}',
+    KeyboardOptionKey = "keyboard_option_key" '\u{eae8}',
+    KeyboardPreviousLanguage = "keyboard_previous_language" '\u{f7da}',
+    KeyboardReturn = "keyboard_return" '\u{e31b}',
+    KeyboardTab = "keyboard_tab" '\u{e31c}',
+    KeyboardTabRtl = "keyboard_tab_rtl" '\u{ec73}',
+    KeyboardVoice = "keyboard_voice" '\u{e31d}',
+    KidStar = "kid_star" '\u{f526}',
+    KingBed = "king_bed" '\u{ea45}',
+    Kitchen = "kitchen" '\u{eb47}',
+    Kitesurfing = "kitesurfing" '\u{e50d}',
+    LabPanel = "lab_panel" '\u{e103}',
+    LabProfile = "lab_profile" '\u{e104}',
+    LabResearch = "lab_research" '\u{f80b}',
+    Label = "label" '\u{e893}',
+    LabelImportant = "label_important" '\u{e948}',
+    LabelImportantOutline = "label_important_outline" '\u{e948}',
+    LabelOff = "label_off" '\u{e9b6}',
+    LabelOutline = "label_outline" '\u{e893}',
+    Labs = "labs" '\u{e105}',
+    Lan = "lan" '\u{eb2f}',
+    Landscape = "landscape" '\u{e564}',
+    Landscape2 = "landscape_2" '\u{f4c4}',
+    Landscape2Off = "landscape_2_off" '\u{f4c3}',
+    Landslide = "landslide" '\u{ebd7}',
+    Language = "language" '\u{e894}',
+    LanguageChineseArray = "language_chinese_array" '\u{f766}',
+    LanguageChineseCangjie = "language_chinese_cangjie" '\u{f765}',
+    LanguageChineseDayi = "language_chinese_dayi" '\u{f764}',
+    LanguageChinesePinyin = "language_chinese_pinyin" '\u{f763}',
+    LanguageChineseQuick = "language_chinese_quick" '\u{f762}',
+    LanguageChineseWubi = "language_chinese_wubi" '\u{f761}',
+    LanguageFrench = "language_french" '\u{f760}',
+    LanguageGbEnglish = "language_gb_english" '\u{f75f}',
+    LanguageInternational = "language_international" '\u{f75e}',
+    LanguageJapaneseKana = "language_japanese_kana" '\u{f513}',
+    LanguageKoreanLatin = "language_korean_latin" '\u{f75d}',
+    LanguagePinyin = "language_pinyin" '\u{f75c}',
+    LanguageSpanish = "language_spanish" '\u{f5e9}',
+    LanguageUs = "language_us" '\u{f759}',
+    LanguageUsColemak = "language_us_colemak" '\u{f75b}',
+    LanguageUsDvorak = "language_us_dvorak" '\u{f75a}',
+    Laps = "laps" '\u{f6b9}',
+    Laptop = "laptop" '\u{e31e}',
+    LaptopChromebook = "laptop_chromebook" '\u{e31f}',
+    LaptopMac = "laptop_mac" '\u{e320}',
+    LaptopWindows = "laptop_windows" '\u{e321}',
+    LassoSelect = "lasso_select" '\u{eb03}',
+    LastPage = "last_page" '\u{e5dd}',
+    Launch = "launch" '\u{e89e}',
+    Laundry = "laundry" '\u{e2a8}',
+    Layers = "layers" '\u{e53b}',
+    LayersClear = "layers_clear" '\u{e53c}',
+    Lda = "lda" '\u{e106}',
+    Leaderboard = "leaderboard" '\u{f20c}',
+    LeakAdd = "leak_add" '\u{e3f8}',
+    LeakRemove = "leak_remove" '\u{e3f9}',
+    LeftClick = "left_click" '\u{f718}',
+    LeftPanelClose = "left_panel_close" '\u{f717}',
+    LeftPanelOpen = "left_panel_open" '\u{f716}',
+    LegendToggle = "legend_toggle" '\u{f11b}',
+    Lens = "lens" '\u{e3fa}',
+    LensBlur = "lens_blur" '\u{f029}',
+    LetterSwitch = "letter_switch" '\u{f758}',
+    LibraryAdd = "library_add" '\u{e03c}',
+    LibraryAddCheck = "library_add_check" '\u{e9b7}',
+    LibraryBooks = "library_books" '\u{e02f}',
+    LibraryMusic = "library_music" '\u{e030}',
+    License = "license" '\u{eb04}',
+    LiftToTalk = "lift_to_talk" '\u{efa3}',
+    Light = "light" '\u{f02a}',
+    LightGroup = "light_group" '\u{e28b}',
+    LightMode = "light_mode" '\u{e518}',
+    LightOff = "light_off" '\u{e9b8}',
+    Lightbulb = "lightbulb" '\u{e90f}',
+    LightbulbCircle = "lightbulb_circle" '\u{ebfe}',
+    LightbulbOutline = "lightbulb_outline" '\u{e90f}',
+    LightningStand = "lightning_stand" '\u{efa4}',
+    LineAxis = "line_axis" '\u{ea9a}',
+    LineCurve = "line_curve" '\u{f757}',
+    LineEnd = "line_end" '\u{f826}',
+    LineEndArrow = "line_end_arrow" '\u{f81d}',
+    LineEndArrowNotch = "line_end_arrow_notch" '\u{f81c}',
+    LineEndCircle = "line_end_circle" '\u{f81b}',
+    LineEndDiamond = "line_end_diamond" '\u{f81a}',
+    LineEndSquare = "line_end_square" '\u{f819}',
+    LineStart = "line_start" '\u{f825}',
+    LineStartArrow = "line_start_arrow" '\u{f818}',
+    LineStartArrowNotch = "line_start_arrow_notch" '\u{f817}',
+    LineStartCircle = "line_start_circle" '\u{f816}',
+    LineStartDiamond = "line_start_diamond" '\u{f815}',
+    LineStartSquare = "line_start_square" '\u{f814}',
+    LineStyle = "line_style" '\u{e919}',
+    LineWeight = "line_weight" '\u{e91a}',
+    LinearScale = "linear_scale" '\u{e260}',
+    Link = "link" '\u{e250}',
+    LinkOff = "link_off" '\u{e16f}',
+    LinkedCamera = "linked_camera" '\u{e438}',
+    LinkedServices = "linked_services" '\u{f535}',
+    Liquor = "liquor" '\u{ea60}',
+    List = "list" '\u{e896}',
+    ListAlt = "list_alt" '\u{e0ee}',
+    ListAltAdd = "list_alt_add" '\u{f756}',
+    Lists = "lists" '\u{e9b9}',
+    LiveHelp = "live_help" '\u{e0c6}',
+    LiveTv = "live_tv" '\u{e63a}',
+    Living = "living" '\u{f02b}',
+    LocalActivity = "local_activity" '\u{e553}',
+    LocalAirport = "local_airport" '\u{e53d}',
+    LocalAtm = "local_atm" '\u{e53e}',
+    LocalBar = "local_bar" '\u{e540}',
+    LocalCafe = "local_cafe" '\u{eb44}',
+    LocalCarWash = "local_car_wash" '\u{e542}',
+    LocalConvenienceStore = "local_convenience_store" '\u{e543}',
+    LocalDining = "local_dining" '\u{e561}',
+    LocalDrink = "local_drink" '\u{e544}',
+    LocalFireDepartment = "local_fire_department" '\u{ef55}',
+    LocalFlorist = "local_florist" '\u{e545}',
+    LocalGasStation = "local_gas_station" '\u{e546}',
+    LocalGroceryStore = "local_grocery_store" '\u{e8cc}',
+    LocalHospital = "local_hospital" '\u{e548}',
+    LocalHotel = "local_hotel" '\u{e549}',
+    LocalLaundryService = "local_laundry_service" '\u{e54a}',
+    LocalLibrary = "local_library" '\u{e54b}',
+    LocalMall = "local_mall" '\u{e54c}',
+    LocalMovies = "local_movies" '\u{e8da}',
+    LocalOffer = "local_offer" '\u{f05b}',
+    LocalParking = "local_parking" '\u{e54f}',
+    LocalPharmacy = "local_pharmacy" '\u{e550}',
+    LocalPhone = "local_phone" '\u{f0d4}',
+    LocalPizza = "local_pizza" '\u{e552}',
+    LocalPlay = "local_play" '\u{e553}',
+    LocalPolice = "local_police" '\u{ef56}',
+    LocalPostOffice = "local_post_office" '\u{e554}',
+    LocalPrintshop = "local_printshop" '\u{e8ad}',
+    LocalSee = "local_see" '\u{e557}',
+    LocalShipping = "local_shipping" '\u{e558}',
+    LocalTaxi = "local_taxi" '\u{e559}',
+    LocationAutomation = "location_automation" '\u{f14f}',
+    LocationAway = "location_away" '\u{f150}',
+    LocationChip = "location_chip" '\u{f850}',
+    LocationCity = "location_city" '\u{e7f1}',
+    LocationDisabled = "location_disabled" '\u{e1b6}',
+    LocationHome = "location_home" '\u{f152}',
+    LocationOff = "location_off" '\u{e0c7}',
+    LocationOn = "location_on" '\u{f1db}',
+    LocationPin = "location_pin" '\u{f1db}',
+    LocationSearching = "location_searching" '\u{e1b7}',
+    LocatorTag = "locator_tag" '\u{f8c1}',
+    Lock = "lock" '\u{e899}',
+    LockClock = "lock_clock" '\u{ef57}',
+    LockOpen = "lock_open" '\u{e898}',
+    LockOpenRight = "lock_open_right" '\u{f656}',
+    LockOutline = "lock_outline" '\u{e899}',
+    LockPerson = "lock_person" '\u{f8f3}',
+    LockReset = "lock_reset" '\u{eade}',
+    Login = "login" '\u{ea77}',
+    LogoDev = "logo_dev" '\u{ead6}',
+    Logout = "logout" '\u{e9ba}',
+    Looks = "looks" '\u{e3fc}',
+    Looks3 = "looks_3" '\u{e3fb}',
+    Looks4 = "looks_4" '\u{e3fd}',
+    Looks5 = "looks_5" '\u{e3fe}',
+    Looks6 = "looks_6" '\u{e3ff}',
+    LooksOne = "looks_one" '\u{e400}',
+    LooksTwo = "looks_two" '\u{e401}',
+    Loop = "loop" '\u{e863}',
+    Loupe = "loupe" '\u{e402}',
+    LowDensity = "low_density" '\u{f79b}',
+    LowPriority = "low_priority" '\u{e16d}',
+    Lowercase = "lowercase" '\u{f48a}',
+    Loyalty = "loyalty" '\u{e89a}',
+    LteMobiledata = "lte_mobiledata" '\u{f02c}',
+    LteMobiledataBadge = "lte_mobiledata_badge" '\u{f7d9}',
+    LtePlusMobiledata = "lte_plus_mobiledata" '\u{f02d}',
+    LtePlusMobiledataBadge = "lte_plus_mobiledata_badge" '\u{f7d8}',
+    Luggage = "luggage" '\u{f235}',
+    LunchDining = "lunch_dining" '\u{ea61}',
+    Lyrics = "lyrics" '\u{ec0b}',
+    MacroAuto = "macro_auto" '\u{f6f2}',
+    MacroOff = "macro_off" '\u{f8d2}',
+    MagicButton = "magic_button" '\u{f136}',
+    MagicExchange = "magic_exchange" '\u{f7f4}',
+    MagicTether = "magic_tether" '\u{f7d7}',
+    MagnificationLarge = "magnification_large" '\u{f83d}',
+    MagnificationSmall = "magnification_small" '\u{f83c}',
+    MagnifyDocked = "magnify_docked" '\u{f7d6}',
+    MagnifyFullscreen = "magnify_fullscreen" '\u{f7d5}',
+    Mail = "mail" '\u{e159}',
+    MailLock = "mail_lock" '\u{ec0a}',
+    MailOff = "mail_off" '\u{f48b}',
+    MailOutline = "mail_outline" '\u{e159}',
+    Male = "male" '\u{e58e}',
+    Man = "man" '\u{e4eb}',
+    Man2 = "man_2" '\u{f8e1}',
+    Man3 = "man_3" '\u{f8e2}',
+    Man4 = "man_4" '\u{f8e3}',
+    ManageAccounts = "manage_accounts" '\u{f02e}',
+    ManageHistory = "manage_history" '\u{ebe7}',
+    ManageSearch = "manage_search" '\u{f02f}',
+    Manga = "manga" '\u{f5e3}',
+    Manufacturing = "manufacturing" '\u{e726}',
+    Map = "map" '\u{e55b}',
+    MapsHomeWork = "maps_home_work" '\u{f030}',
+    MapsUgc = "maps_ugc" '\u{ef58}',
+    Margin = "margin" '\u{e9bb}',
+    MarkAsUnread = "mark_as_unread" '\u{e9bc}',
+    MarkChatRead = "mark_chat_read" '\u{f18b}',
+    MarkChatUnread = "mark_chat_unread" '\u{f189}',
+    MarkEmailRead = "mark_email_read" '\u{f18c}',
+    MarkEmailUnread = "mark_email_unread" '\u{f18a}',
+    MarkUnreadChatAlt = "mark_unread_chat_alt" '\u{eb9d}',
+    Markdown = "markdown" '\u{f552}',
+    MarkdownCopy = "markdown_copy" '\u{f553}',
+    MarkdownPaste = "markdown_paste" '\u{f554}',
+    Markunread = "markunread" '\u{e159}',
+    MarkunreadMailbox = "markunread_mailbox" '\u{e89b}',
+    MaskedTransitions = "masked_transitions" '\u{e72e}',
+    MaskedTransitionsAdd = "masked_transitions_add" '\u{f42b}',
+    Masks = "masks" '\u{f218}',
+    MatchCase = "match_case" '\u{f6f1}',
+    MatchWord = "match_word" '\u{f6f0}',
+    Matter = "matter" '\u{e907}',
+    Maximize = "maximize" '\u{e930}',
+    MeasuringTape = "measuring_tape" '\u{f6af}',
+    MediaBluetoothOff = "media_bluetooth_off" '\u{f031}',
+    MediaBluetoothOn = "media_bluetooth_on" '\u{f032}',
+    MediaLink = "media_link" '\u{f83f}',
+    MediaOutput = "media_output" '\u{f4f2}',
+    MediaOutputOff = "media_output_off" '\u{f4f3}',
+    Mediation = "mediation" '\u{efa7}',
+    MedicalInformation = "medical_information" '\u{ebed}',
+    MedicalMask = "medical_mask" '\u{f80a}',
+    MedicalServices = "medical_services" '\u{f109}',
+    Medication = "medication" '\u{f033}',
+    MedicationLiquid = "medication_liquid" '\u{ea87}',
+    MeetingRoom = "meeting_room" '\u{eb4f}',
+    Memory = "memory" '\u{e322}',
+    MemoryAlt = "memory_alt" '\u{f7a3}',
+    MenstrualHealth = "menstrual_health" '\u{f6e1}',
+    Menu = "menu" '\u{e5d2}',
+    MenuBook = "menu_book" '\u{ea19}',
+    MenuOpen = "menu_open" '\u{e9bd}',
+    Merge = "merge" '\u{eb98}',
+    MergeType = "merge_type" '\u{e252}',
+    Message = "message" '\u{e0c9}',
+    Metabolism = "metabolism" '\u{e10b}',
+    Metro = "metro" '\u{f474}',
+    MfgNestYaleLock = "mfg_nest_yale_lock" '\u{f11d}',
+    Mic = "mic" '\u{e31d}',
+    MicDouble = "mic_double" '\u{f5d1}',
+    MicExternalOff = "mic_external_off" '\u{ef59}',
+    MicExternalOn = "mic_external_on" '\u{ef5a}',
+    MicNone = "mic_none" '\u{e31d}',
+    MicOff = "mic_off" '\u{e02b}',
+    Microbiology = "microbiology" '\u{e10c}',
+    Microwave = "microwave" '\u{f204}',
+    MicrowaveGen = "microwave_gen" '\u{e847}',
+    MilitaryTech = "military_tech" '\u{ea3f}',
+    Mimo = "mimo" '\u{e9be}',
+    MimoDisconnect = "mimo_disconnect" '\u{e9bf}',
+    Mindfulness = "mindfulness" '\u{f6e0}',
+    Minimize = "minimize" '\u{e931}',
+    MinorCrash = "minor_crash" '\u{ebf1}',
+    Mintmark = "mintmark" '\u{efa9}',
+    MissedVideoCall = "missed_video_call" '\u{f0ce}',
+    MissedVideoCallFilled = "missed_video_call_filled" '\u{f0ce}',
+    MissingController = "missing_controller" '\u{e701}',
+    Mist = "mist" '\u{e188}',
+    Mitre = "mitre" '\u{f547}',
+    MixtureMed = "mixture_med" '\u{e4c8}',
+    Mms = "mms" '\u{e618}',
+    MobileFriendly = "mobile_friendly" '\u{e200}',
+    MobileOff = "mobile_off" '\u{e201}',
+    MobileScreenShare = "mobile_screen_share" '\u{e0e7}',
+    MobiledataOff = "mobiledata_off" '\u{f034}',
+    Mode = "mode" '\u{f097}',
+    ModeComment = "mode_comment" '\u{e253}',
+    ModeCool = "mode_cool" '\u{f166}',
+    ModeCoolOff = "mode_cool_off" '\u{f167}',
+    ModeDual = "mode_dual" '\u{f557}',
+    ModeEdit = "mode_edit" '\u{f097}',
+    ModeEditOutline = "mode_edit_outline" '\u{f097}',
+    ModeFan = "mode_fan" '\u{f168}',
+    ModeFanOff = "mode_fan_off" '\u{ec17}',
+    ModeHeat = "mode_heat" '\u{f16a}',
+    ModeHeatCool = "mode_heat_cool" '\u{f16b}',
+    ModeHeatOff = "mode_heat_off" '\u{f16d}',
+    ModeNight = "mode_night" '\u{f036}',
+    ModeOfTravel = "mode_of_travel" '\u{e7ce}',
+    ModeOffOn = "mode_off_on" '\u{f16f}',
+    ModeStandby = "mode_standby" '\u{f037}',
+    ModelTraining = "model_training" '\u{f0cf}',
+    MonetizationOn = "monetization_on" '\u{e263}',
+    Money = "money" '\u{e57d}',
+    MoneyBag = "money_bag" '\u{f3ee}',
+    MoneyOff = "money_off" '\u{f038}',
+    MoneyOffCsred = "money_off_csred" '\u{f038}',
+    Monitor = "monitor" '\u{ef5b}',
+    MonitorHeart = "monitor_heart" '\u{eaa2}',
+    MonitorWeight = "monitor_weight" '\u{f039}',
+    MonitorWeightGain = "monitor_weight_gain" '\u{f6df}',
+    MonitorWeightLoss = "monitor_weight_loss" '\u{f6de}',
+    Monitoring = "monitoring" '\u{f190}',
+    MonochromePhotos = "monochrome_photos" '\u{e403}',
+    Monorail = "monorail" '\u{f473}',
+    Mood = "mood" '\u{ea22}',
+    MoodBad = "mood_bad" '\u{e7f3}',
+    Mop = "mop" '\u{e28d}',
+    More = "more" '\u{e619}',
+    MoreDown = "more_down" '\u{f196}',
+    MoreHoriz = "more_horiz" '\u{e5d3}',
+    MoreTime = "more_time" '\u{ea5d}',
+    MoreUp = "more_up" '\u{f197}',
+    MoreVert = "more_vert" '\u{e5d4}',
+    Mosque = "mosque" '\u{eab2}',
+    MotionBlur = "motion_blur" '\u{f0d0}',
+    MotionMode = "motion_mode" '\u{f842}',
+    MotionPhotosAuto = "motion_photos_auto" '\u{f03a}',
+    MotionPhotosOff = "motion_photos_off" '\u{e9c0}',
+    MotionPhotosOn = "motion_photos_on" '\u{e9c1}',
+    MotionPhotosPause = "motion_photos_pause" '\u{f227}',
+    MotionPhotosPaused = "motion_photos_paused" '\u{f227}',
+    MotionPlay = "motion_play" '\u{f40b}',
+    MotionSensorActive = "motion_sensor_active" '\u{e792}',
+    MotionSensorAlert = "motion_sensor_alert" '\u{e784}',
+    MotionSensorIdle = "motion_sensor_idle" '\u{e783}',
+    MotionSensorUrgent = "motion_sensor_urgent" '\u{e78e}',
+    Motorcycle = "motorcycle" '\u{e91b}',
+    MountainFlag = "mountain_flag" '\u{f5e2}',
+    Mouse = "mouse" '\u{e323}',
+    MouseLock = "mouse_lock" '\u{f490}',
+    MouseLockOff = "mouse_lock_off" '\u{f48f}',
+    Move = "move" '\u{e740}',
+    MoveDown = "move_down" '\u{eb61}',
+    MoveGroup = "move_group" '\u{f715}',
+    MoveItem = "move_item" '\u{f1ff}',
+    MoveLocation = "move_location" '\u{e741}',
+    MoveSelectionDown = "move_selection_down" '\u{f714}',
+    MoveSelectionLeft = "move_selection_left" '\u{f713}',
+    MoveSelectionRight = "move_selection_right" '\u{f712}',
+    MoveSelectionUp = "move_selection_up" '\u{f711}',
+    MoveToInbox = "move_to_inbox" '\u{e168}',
+    MoveUp = "move_up" '\u{eb64}',
+    MovedLocation = "moved_location" '\u{e594}',
+    Movie = "movie" '\u{e404}',
+    MovieCreation = "movie_creation" '\u{e404}',
+    MovieEdit = "movie_edit" '\u{f840}',
+    MovieFilter = "movie_filter" '\u{e43a}',
+    MovieInfo = "movie_info" '\u{e02d}',
+    MovieOff = "movie_off" '\u{f499}',
+    Moving = "moving" '\u{e501}',
+    MovingBeds = "moving_beds" '\u{e73d}',
+    MovingMinistry = "moving_ministry" '\u{e73e}',
+    Mp = "mp" '\u{e9c3}',
+    Multicooker = "multicooker" '\u{e293}',
+    MultilineChart = "multiline_chart" '\u{e6df}',
+    MultimodalHandEye = "multimodal_hand_eye" '\u{f41b}',
+    MultipleStop = "multiple_stop" '\u{f1b9}',
+    Museum = "museum" '\u{ea36}',
+    MusicCast = "music_cast" '\u{eb1a}',
+    MusicNote = "music_note" '\u{e405}',
+    MusicOff = "music_off" '\u{e440}',
+    MusicVideo = "music_video" '\u{e063}',
+    MyLocation = "my_location" '\u{e55c}',
+    Mystery = "mystery" '\u{f5e1}',
+    Nat = "nat" '\u{ef5c}',
+    Nature = "nature" '\u{e406}',
+    NaturePeople = "nature_people" '\u{e407}',
+    NavigateBefore = "navigate_before" '\u{e5cb}',
+    NavigateNext = "navigate_next" '\u{e5cc}',
+    Navigation = "navigation" '\u{e55d}',
+    NearMe = "near_me" '\u{e569}',
+    NearMeDisabled = "near_me_disabled" '\u{f1ef}',
+    Nearby = "nearby" '\u{e6b7}',
+    NearbyError = "nearby_error" '\u{f03b}',
+    NearbyOff = "nearby_off" '\u{f03c}',
+    Nephrology = "nephrology" '\u{e10d}',
+    NestAudio = "nest_audio" '\u{ebbf}',
+    NestCamFloodlight = "nest_cam_floodlight" '\u{f8b7}',
+    NestCamIndoor = "nest_cam_indoor" '\u{f11e}',
+    NestCamIq = "nest_cam_iq" '\u{f11f}',
+    NestCamIqOutdoor = "nest_cam_iq_outdoor" '\u{f120}',
+    NestCamMagnetMount = "nest_cam_magnet_mount" '\u{f8b8}',
+    NestCamOutdoor = "nest_cam_outdoor" '\u{f121}',
+    NestCamStand = "nest_cam_stand" '\u{f8b9}',
+    NestCamWallMount = "nest_cam_wall_mount" '\u{f8ba}',
+    NestCamWiredStand = "nest_cam_wired_stand" '\u{ec16}',
+    NestClockFarsightAnalog = "nest_clock_farsight_analog" '\u{f8bb}',
+    NestClockFarsightDigital = "nest_clock_farsight_digital" '\u{f8bc}',
+    NestConnect = "nest_connect" '\u{f122}',
+    NestDetect = "nest_detect" '\u{f123}',
+    NestDisplay = "nest_display" '\u{f124}',
+    NestDisplayMax = "nest_display_max" '\u{f125}',
+    NestDoorbellVisitor = "nest_doorbell_visitor" '\u{f8bd}',
+    NestEcoLeaf = "nest_eco_leaf" '\u{f8be}',
+    NestFarsightWeather = "nest_farsight_weather" '\u{f8bf}',
+    NestFoundSavings = "nest_found_savings" '\u{f8c0}',
+    NestGaleWifi = "nest_gale_wifi" '\u{f579}',
+    NestHeatLinkE = "nest_heat_link_e" '\u{f126}',
+    NestHeatLinkGen3 = "nest_heat_link_gen_3" '\u{f127}',
+    NestHelloDoorbell = "nest_hello_doorbell" '\u{e82c}',
+    NestLocatorTag = "nest_locator_tag" '\u{f8c1}',
+    NestMini = "nest_mini" '\u{e789}',
+    NestMultiRoom = "nest_multi_room" '\u{f8c2}',
+    NestProtect = "nest_protect" '\u{e68e}',
+    NestRemote = "nest_remote" '\u{f5db}',
+    NestRemoteComfortSensor = "nest_remote_comfort_sensor" '\u{f12a}',
+    NestSecureAlarm = "nest_secure_alarm" '\u{f12b}',
+    NestSunblock = "nest_sunblock" '\u{f8c3}',
+    NestTag = "nest_tag" '\u{f8c1}',
+    NestThermostat = "nest_thermostat" '\u{e68f}',
+    NestThermostatEEu = "nest_thermostat_e_eu" '\u{f12d}',
+    NestThermostatGen3 = "nest_thermostat_gen_3" '\u{f12e}',
+    NestThermostatSensor = "nest_thermostat_sensor" '\u{f12f}',
+    NestThermostatSensorEu = "nest_thermostat_sensor_eu" '\u{f130}',
+    NestThermostatZirconiumEu = "nest_thermostat_zirconium_eu" '\u{f131}',
+    NestTrueRadiant = "nest_true_radiant" '\u{f8c4}',
+    NestWakeOnApproach = "nest_wake_on_approach" '\u{f8c5}',
+    NestWakeOnPress = "nest_wake_on_press" '\u{f8c6}',
+    NestWifiGale = "nest_wifi_gale" '\u{f132}',
+    NestWifiMistral = "nest_wifi_mistral" '\u{f133}',
+    NestWifiPoint = "nest_wifi_point" '\u{f134}',
+    NestWifiPointVento = "nest_wifi_point_vento" '\u{f134}',
+    NestWifiPro = "nest_wifi_pro" '\u{f56b}',
+    NestWifiPro2 = "nest_wifi_pro_2" '\u{f56a}',
+    NestWifiRouter = "nest_wifi_router" '\u{f133}',
+    NetworkCell = "network_cell" '\u{e1b9}',
+    NetworkCheck = "network_check" '\u{e640}',
+    NetworkIntelligenceHistory = "network_intelligence_history" '\u{f5f6}',
+    NetworkIntelligenceUpdate = "network_intelligence_update" '\u{f5f5}',
+    NetworkLocked = "network_locked" '\u{e61a}',
+    NetworkManage = "network_manage" '\u{f7ab}',
+    NetworkNode = "network_node" '\u{f56e}',
+    NetworkPing = "network_ping" '\u{ebca}',
+    NetworkWifi = "network_wifi" '\u{e1ba}',
+    NetworkWifi1Bar = "network_wifi_1_bar" '\u{ebe4}',
+    NetworkWifi1BarLocked = "network_wifi_1_bar_locked" '\u{f58f}',
+    NetworkWifi2Bar = "network_wifi_2_bar" '\u{ebd6}',
+    NetworkWifi2BarLocked = "network_wifi_2_bar_locked" '\u{f58e}',
+    NetworkWifi3Bar = "network_wifi_3_bar" '\u{ebe1}',
+    NetworkWifi3BarLocked = "network_wifi_3_bar_locked" '\u{f58d}',
+    NetworkWifiLocked = "network_wifi_locked" '\u{f532}',
+    Neurology = "neurology" '\u{e10e}',
+    NewLabel = "new_label" '\u{e609}',
+    NewReleases = "new_releases" '\u{ef76}',
+    NewWindow = "new_window" '\u{f710}',
+    News = "news" '\u{e032}',
+    Newsmode = "newsmode" '\u{efad}',
+    Newspaper = "newspaper" '\u{eb81}',
+    Newsstand = "newsstand" '\u{e9c4}',
+    NextPlan = "next_plan" '\u{ef5d}',
+    NextWeek = "next_week" '\u{e16a}',
+    Nfc = "nfc" '\u{e1bb}',
+    NightShelter = "night_shelter" '\u{f1f1}',
+    NightSightAuto = "night_sight_auto" '\u{f1d7}',
+    NightSightAutoOff = "night_sight_auto_off" '\u{f1f9}',
+    NightSightMax = "night_sight_max" '\u{f6c3}',
+    Nightlife = "nightlife" '\u{ea62}',
+    Nightlight = "nightlight" '\u{f03d}',
+    NightlightRound = "nightlight_round" '\u{f03d}',
+    NightsStay = "nights_stay" '\u{ea46}',
+    NoAccounts = "no_accounts" '\u{f03e}',
+    NoAdultContent = "no_adult_content" '\u{f8fe}',
+    NoBackpack = "no_backpack" '\u{f237}',
+    NoCrash = "no_crash" '\u{ebf0}',
+    NoDrinks = "no_drinks" '\u{f1a5}',
+    NoEncryption = "no_encryption" '\u{f03f}',
+    NoEncryptionGmailerrorred = "no_encryption_gmailerrorred" '\u{f03f}',
+    NoFlash = "no_flash" '\u{f1a6}',
+    NoFood = "no_food" '\u{f1a7}',
+    NoLuggage = "no_luggage" '\u{f23b}',
+    NoMeals = "no_meals" '\u{f1d6}',
+    NoMeetingRoom = "no_meeting_room" '\u{eb4e}',
+    NoPhotography = "no_photography" '\u{f1a8}',
+    NoSim = "no_sim" '\u{e1ce}',
+    NoSound = "no_sound" '\u{e710}',
+    NoStroller = "no_stroller" '\u{f1af}',
+    NoTransfer = "no_transfer" '\u{f1d5}',
+    NoiseAware = "noise_aware" '\u{ebec}',
+    NoiseControlOff = "noise_control_off" '\u{ebf3}',
+    NoiseControlOn = "noise_control_on" '\u{f8a8}',
+    NordicWalking = "nordic_walking" '\u{e50e}',
+    North = "north" '\u{f1e0}',
+    NorthEast = "north_east" '\u{f1e1}',
+    NorthWest = "north_west" '\u{f1e2}',
+    NotAccessible = "not_accessible" '\u{f0fe}',
+    NotAccessibleForward = "not_accessible_forward" '\u{f54a}',
+    NotInterested = "not_interested" '\u{f08c}',
+    NotListedLocation = "not_listed_location" '\u{e575}',
+    NotStarted = "not_started" '\u{f0d1}',
+    Note = "note" '\u{e66d}',
+    NoteAdd = "note_add" '\u{e89c}',
+    NoteAlt = "note_alt" '\u{f040}',
+    NoteStack = "note_stack" '\u{f562}',
+    NoteStackAdd = "note_stack_add" '\u{f563}',
+    Notes = "notes" '\u{e26c}',
+    NotificationAdd = "notification_add" '\u{e399}',
+    NotificationImportant = "notification_important" '\u{e004}',
+    NotificationMultiple = "notification_multiple" '\u{e6c2}',
+    Notifications = "notifications" '\u{e7f5}',
+    NotificationsActive = "notifications_active" '\u{e7f7}',
+    NotificationsNone = "notifications_none" '\u{e7f5}',
+    NotificationsOff = "notifications_off" '\u{e7f6}',
+    NotificationsPaused = "notifications_paused" '\u{e7f8}',
+    NotificationsUnread = "notifications_unread" '\u{f4fe}',
+    Numbers = "numbers" '\u{eac7}',
+    Nutrition = "nutrition" '\u{e110}',
+    Ods = "ods" '\u{e6e8}',
+    Odt = "odt" '\u{e6e9}',
+    OfflineBolt = "offline_bolt" '\u{e932}',
+    OfflinePin = "offline_pin" '\u{e90a}',
+    OfflinePinOff = "offline_pin_off" '\u{f4d0}',
+    OfflineShare = "offline_share" '\u{e9c5}',
+    OilBarrel = "oil_barrel" '\u{ec15}',
+    OnDeviceTraining = "on_device_training" '\u{ebfd}',
+    OnHubDevice = "on_hub_device" '\u{e6c3}',
+    Oncology = "oncology" '\u{e114}',
+    OndemandVideo = "ondemand_video" '\u{e63a}',
+    OnlinePrediction = "online_prediction" '\u{f0eb}',
+    Onsen = "onsen" '\u{f6f8}',
+    Opacity = "opacity" '\u{e91c}',
+    OpenInBrowser = "open_in_browser" '\u{e89d}',
+    OpenInFull = "open_in_full" '\u{f1ce}',
+    OpenInNew = "open_in_new" '\u{e89e}',
+    OpenInNewDown = "open_in_new_down" '\u{f70f}',
+    OpenInNewOff = "open_in_new_off" '\u{e4f6}',
+    OpenInPhone = "open_in_phone" '\u{e702}',
+    OpenJam = "open_jam" '\u{efae}',
+    OpenRun = "open_run" '\u{f4b7}',
+    OpenWith = "open_with" '\u{e89f}',
+    Ophthalmology = "ophthalmology" '\u{e115}',
+    OralDisease = "oral_disease" '\u{e116}',
+    Orbit = "orbit" '\u{f426}',
+    OrderApprove = "order_approve" '\u{f812}',
+    OrderPlay = "order_play" '\u{f811}',
+    Orders = "orders" '\u{eb14}',
+    Orthopedics = "orthopedics" '\u{f897}',
+    OtherAdmission = "other_admission" '\u{e47b}',
+    OtherHouses = "other_houses" '\u{e58c}',
+    Outbound = "outbound" '\u{e1ca}',
+    Outbox = "outbox" '\u{ef5f}',
+    OutboxAlt = "outbox_alt" '\u{eb17}',
+    OutdoorGarden = "outdoor_garden" '\u{e205}',
+    OutdoorGrill = "outdoor_grill" '\u{ea47}',
+    OutgoingMail = "outgoing_mail" '\u{f0d2}',
+    Outlet = "outlet" '\u{f1d4}',
+    OutlinedFlag = "outlined_flag" '\u{f0c6}',
+    Outpatient = "outpatient" '\u{e118}',
+    OutpatientMed = "outpatient_med" '\u{e119}',
+    Output = "output" '\u{ebbe}',
+    OutputCircle = "output_circle" '\u{f70e}',
+    Oven = "oven" '\u{e9c7}',
+    OvenGen = "oven_gen" '\u{e843}',
+    Overview = "overview" '\u{e4a7}',
+    OverviewKey = "overview_key" '\u{f7d4}',
+    OxygenSaturation = "oxygen_saturation" '\u{e4de}',
+    P2p = "p2p" '\u{f52a}',
+    Pace = "pace" '\u{f6b8}',
+    Pacemaker = "pacemaker" '\u{e656}',
+    Package = "package" '\u{e48f}',
+    Package2 = "package_2" '\u{f569}',
+    Padding = "padding" '\u{e9c8}',
+    PageControl = "page_control" '\u{e731}',
+    PageInfo = "page_info" '\u{f614}',
+    Pageless = "pageless" '\u{f509}',
+    Pages = "pages" '\u{e7f9}',
+    Pageview = "pageview" '\u{e8a0}',
+    Paid = "paid" '\u{f041}',
+    Palette = "palette" '\u{e40a}',
+    Pallet = "pallet" '\u{f86a}',
+    PanTool = "pan_tool" '\u{e925}',
+    PanToolAlt = "pan_tool_alt" '\u{ebb9}',
+    PanZoom = "pan_zoom" '\u{f655}',
+    Panorama = "panorama" '\u{e40b}',
+    PanoramaFishEye = "panorama_fish_eye" '\u{e40c}',
+    PanoramaHorizontal = "panorama_horizontal" '\u{e40d}',
+    PanoramaPhotosphere = "panorama_photosphere" '\u{e9c9}',
+    PanoramaVertical = "panorama_vertical" '\u{e40e}',
+    PanoramaWideAngle = "panorama_wide_angle" '\u{e40f}',
+    Paragliding = "paragliding" '\u{e50f}',
+    Park = "park" '\u{ea63}',
+    PartlyCloudyDay = "partly_cloudy_day" '\u{f172}',
+    PartlyCloudyNight = "partly_cloudy_night" '\u{f174}',
+    PartnerExchange = "partner_exchange" '\u{f7f9}',
+    PartnerReports = "partner_reports" '\u{efaf}',
+    PartyMode = "party_mode" '\u{e7fa}',
+    Passkey = "passkey" '\u{f87f}',
+    Password = "password" '\u{f042}',
+    Password2 = "password_2" '\u{f4a9}',
+    Password2Off = "password_2_off" '\u{f4a8}',
+    PatientList = "patient_list" '\u{e653}',
+    Pattern = "pattern" '\u{f043}',
+    Pause = "pause" '\u{e034}',
+    PauseCircle = "pause_circle" '\u{e1a2}',
+    PauseCircleFilled = "pause_circle_filled" '\u{e1a2}',
+    PauseCircleOutline = "pause_circle_outline" '\u{e1a2}',
+    PausePresentation = "pause_presentation" '\u{e0ea}',
+    Payment = "payment" '\u{e8a1}',
+    Payments = "payments" '\u{ef63}',
+    PedalBike = "pedal_bike" '\u{eb29}',
+    Pediatrics = "pediatrics" '\u{e11d}',
+    PenSize1 = "pen_size_1" '\u{f755}',
+    PenSize2 = "pen_size_2" '\u{f754}',
+    PenSize3 = "pen_size_3" '\u{f753}',
+    PenSize4 = "pen_size_4" '\u{f752}',
+    PenSize5 = "pen_size_5" '\u{f751}',
+    Pending = "pending" '\u{ef64}',
+    PendingActions = "pending_actions" '\u{f1bb}',
+    Pentagon = "pentagon" '\u{eb50}',
+    People = "people" '\u{ea21}',
+    PeopleAlt = "people_alt" '\u{ea21}',
+    PeopleOutline = "people_outline" '\u{ea21}',
+    Percent = "percent" '\u{eb58}',
+    PerformanceMax = "performance_max" '\u{e51a}',
+    Pergola = "pergola" '\u{e203}',
+    PermCameraMic = "perm_camera_mic" '\u{e8a2}',
+    PermContactCalendar = "perm_contact_calendar" '\u{e8a3}',
+    PermDataSetting = "perm_data_setting" '\u{e8a4}',
+    PermDeviceInformation = "perm_device_information" '\u{e8a5}',
+    PermIdentity = "perm_identity" '\u{f0d3}',
+    PermMedia = "perm_media" '\u{e8a7}',
+    PermPhoneMsg = "perm_phone_msg" '\u{e8a8}',
+    PermScanWifi = "perm_scan_wifi" '\u{e8a9}',
+    Person = "person" '\u{f0d3}',
+    Person2 = "person_2" '\u{f8e4}',
+    Person3 = "person_3" '\u{f8e5}',
+    Person4 = "person_4" '\u{f8e6}',
+    PersonAdd = "person_add" '\u{ea4d}',
+    PersonAddAlt = "person_add_alt" '\u{ea4d}',
+    PersonAddDisabled = "person_add_disabled" '\u{e9cb}',
+    PersonAlert = "person_alert" '\u{f567}',
+    PersonApron = "person_apron" '\u{f5a3}',
+    PersonBook = "person_book" '\u{f5e8}',
+    PersonCancel = "person_cancel" '\u{f566}',
+    PersonCelebrate = "person_celebrate" '\u{f7fe}',
+    PersonCheck = "person_check" '\u{f565}',
+    PersonEdit = "person_edit" '\u{f4fa}',
+    PersonFilled = "person_filled" '\u{f0d3}',
+    PersonOff = "person_off" '\u{e510}',
+    PersonOutline = "person_outline" '\u{f0d3}',
+    PersonPin = "person_pin" '\u{e55a}',
+    PersonPinCircle = "person_pin_circle" '\u{e56a}',
+    PersonPlay = "person_play" '\u{f7fd}',
+    PersonRaisedHand = "person_raised_hand" '\u{f59a}',
+    PersonRemove = "person_remove" '\u{ef66}',
+    PersonSearch = "person_search" '\u{f106}',
+    PersonalBag = "personal_bag" '\u{eb0e}',
+    PersonalBagOff = "personal_bag_off" '\u{eb0f}',
+    PersonalBagQuestion = "personal_bag_question" '\u{eb10}',
+    PersonalInjury = "personal_injury" '\u{e6da}',
+    PersonalPlaces = "personal_places" '\u{e703}',
+    PersonalVideo = "personal_video" '\u{e63b}',
+    PestControl = "pest_control" '\u{f0fa}',
+    PestControlRodent = "pest_control_rodent" '\u{f0fd}',
+    PetSupplies = "pet_supplies" '\u{efb1}',
+    Pets = "pets" '\u{e91d}',
+    Phishing = "phishing" '\u{ead7}',
+    Phone = "phone" '\u{f0d4}',
+    PhoneAlt = "phone_alt" '\u{f0d4}',
+    PhoneAndroid = "phone_android" '\u{e324}',
+    PhoneBluetoothSpeaker = "phone_bluetooth_speaker" '\u{e61b}',
+    PhoneCallback = "phone_callback" '\u{e649}',
+    PhoneDisabled = "phone_disabled" '\u{e9cc}',
+    PhoneEnabled = "phone_enabled" '\u{e9cd}',
+    PhoneForwarded = "phone_forwarded" '\u{e61c}',
+    PhoneInTalk = "phone_in_talk" '\u{e61d}',
+    PhoneIphone = "phone_iphone" '\u{e325}',
+    PhoneLocked = "phone_locked" '\u{e61e}',
+    PhoneMissed = "phone_missed" '\u{e61f}',
+    PhonePaused = "phone_paused" '\u{e620}',
+    Phonelink = "phonelink" '\u{e326}',
+    PhonelinkErase = "phonelink_erase" '\u{e0db}',
+    PhonelinkLock = "phonelink_lock" '\u{e0dc}',
+    PhonelinkOff = "phonelink_off" '\u{e327}',
+    PhonelinkRing = "phonelink_ring" '\u{e0dd}',
+    PhonelinkRingOff = "phonelink_ring_off" '\u{f7aa}',
+    PhonelinkSetup = "phonelink_setup" '\u{ef41}',
+    Photo = "photo" '\u{e432}',
+    PhotoAlbum = "photo_album" '\u{e411}',
+    PhotoAutoMerge = "photo_auto_merge" '\u{f530}',
+    PhotoCamera = "photo_camera" '\u{e412}',
+    PhotoCameraBack = "photo_camera_back" '\u{ef68}',
+    PhotoCameraFront = "photo_camera_front" '\u{ef69}',
+    PhotoFilter = "photo_filter" '\u{e43b}',
+    PhotoFrame = "photo_frame" '\u{f0d9}',
+    PhotoLibrary = "photo_library" '\u{e413}',
+    PhotoPrints = "photo_prints" '\u{efb2}',
+    PhotoSizeSelectActual = "photo_size_select_actual" '\u{e432}',
+    PhotoSizeSelectLarge = "photo_size_select_large" '\u{e433}',
+    PhotoSizeSelectSmall = "photo_size_select_small" '\u{e434}',
+    Php = "php" '\u{eb8f}',
+    PhysicalTherapy = "physical_therapy" '\u{e11e}',
+    Piano = "piano" '\u{e521}',
+    PianoOff = "piano_off" '\u{e520}',
+    PictureAsPdf = "picture_as_pdf" '\u{e415}',
+    PictureInPicture = "picture_in_picture" '\u{e8aa}',
+    PictureInPictureAlt = "picture_in_picture_alt" '\u{e911}',
+    PictureInPictureCenter = "picture_in_picture_center" '\u{f550}',
+    PictureInPictureLarge = "picture_in_picture_large" '\u{f54f}',
+    PictureInPictureMedium = "picture_in_picture_medium" '\u{f54e}',
+    PictureInPictureMobile = "picture_in_picture_mobile" '\u{f517}',
+    PictureInPictureOff = "picture_in_picture_off" '\u{f52f}',
+    PictureInPictureSmall = "picture_in_picture_small" '\u{f54d}',
+    PieChart = "pie_chart" '\u{f0da}',
+    PieChartFilled = "pie_chart_filled" '\u{f0da}',
+    PieChartOutline = "pie_chart_outline" '\u{f0da}',
+    PieChartOutlined = "pie_chart_outlined" '\u{f0da}',
+    Pill = "pill" '\u{e11f}',
+    PillOff = "pill_off" '\u{f809}',
+    Pin = "pin" '\u{f045}',
+    PinDrop = "pin_drop" '\u{e55e}',
+    PinEnd = "pin_end" '\u{e767}',
+    PinInvoke = "pin_invoke" '\u{e763}',
+    Pinch = "pinch" '\u{eb38}',
+    PinchZoomIn = "pinch_zoom_in" '\u{f1fa}',
+    PinchZoomOut = "pinch_zoom_out" '\u{f1fb}',
+    Pip = "pip" '\u{f64d}',
+    PipExit = "pip_exit" '\u{f70d}',
+    PivotTableChart = "pivot_table_chart" '\u{e9ce}',
+    Pixel9ProFold = "pixel_9_pro_fold" '\u{f3f0}',
+    Place = "place" '\u{f1db}',
+    PlaceItem = "place_item" '\u{f1f0}',
+    Plagiarism = "plagiarism" '\u{ea5a}',
+    PlannerBannerAdPt = "planner_banner_ad_pt" '\u{e692}',
+    PlannerReview = "planner_review" '\u{e694}',
+    PlayArrow = "play_arrow" '\u{e037}',
+    PlayCircle = "play_circle" '\u{e1c4}',
+    PlayDisabled = "play_disabled" '\u{ef6a}',
+    PlayForWork = "play_for_work" '\u{e906}',
+    PlayLesson = "play_lesson" '\u{f047}',
+    PlayMusic = "play_music" '\u{e6ee}',
+    PlayPause = "play_pause" '\u{f137}',
+    PlayShapes = "play_shapes" '\u{f7fc}',
+    PlayingCards = "playing_cards" '\u{f5dc}',
+    PlaylistAdd = "playlist_add" '\u{e03b}',
+    PlaylistAddCheck = "playlist_add_check" '\u{e065}',
+    PlaylistAddCheckCircle = "playlist_add_check_circle" '\u{e7e6}',
+    PlaylistAddCircle = "playlist_add_circle" '\u{e7e5}',
+    PlaylistPlay = "playlist_play" '\u{e05f}',
+    PlaylistRemove = "playlist_remove" '\u{eb80}',
+    Plumbing = "plumbing" '\u{f107}',
+    PlusOne = "plus_one" '\u{e800}',
+    Podcasts = "podcasts" '\u{f048}',
+    Podiatry = "podiatry" '\u{e120}',
+    Podium = "podium" '\u{f7fb}',
+    PointOfSale = "point_of_sale" '\u{f17e}',
+    PointScan = "point_scan" '\u{f70c}',
+    PokerChip = "poker_chip" '\u{f49b}',
+    Policy = "policy" '\u{ea17}',
+    PolicyAlert = "policy_alert" '\u{f407}',
+    Poll = "poll" '\u{f0cc}',
+    Polyline = "polyline" '\u{ebbb}',
+    Polymer = "polymer" '\u{e8ab}',
+    Pool = "pool" '\u{eb48}',
+    PortableWifiOff = "portable_wifi_off" '\u{f087}',
+    Portrait = "portrait" '\u{e851}',
+    PositionBottomLeft = "position_bottom_left" '\u{f70b}',
+    PositionBottomRight = "position_bottom_right" '\u{f70a}',
+    PositionTopRight = "position_top_right" '\u{f709}',
+    Post = "post" '\u{e705}',
+    PostAdd = "post_add" '\u{ea20}',
+    PottedPlant = "potted_plant" '\u{f8aa}',
+    Power = "power" '\u{e63c}',
+    PowerInput = "power_input" '\u{e336}',
+    PowerOff = "power_off" '\u{e646}',
+    PowerRounded = "power_rounded" '\u{f8c7}',
+    PowerSettingsCircle = "power_settings_circle" '\u{f418}',
+    PowerSettingsNew = "power_settings_new" '\u{f8c7}',
+    PrayerTimes = "prayer_times" '\u{f838}',
+    PrecisionManufacturing = "precision_manufacturing" '\u{f049}',
+    Pregnancy = "pregnancy" '\u{f5f1}',
+    PregnantWoman = "pregnant_woman" '\u{f5f1}',
+    Preliminary = "preliminary" '\u{e7d8}',
+    Prescriptions = "prescriptions" '\u{e121}',
+    PresentToAll = "present_to_all" '\u{e0df}',
+    Preview = "preview" '\u{f1c5}',
+    PreviewOff = "preview_off" '\u{f7af}',
+    PriceChange = "price_change" '\u{f04a}',
+    PriceCheck = "price_check" '\u{f04b}',
+    Print = "print" '\u{e8ad}',
+    PrintAdd = "print_add" '\u{f7a2}',
+    PrintConnect = "print_connect" '\u{f7a1}',
+    PrintDisabled = "print_disabled" '\u{e9cf}',
+    PrintError = "print_error" '\u{f7a0}',
+    PrintLock = "print_lock" '\u{f651}',
+    Priority = "priority" '\u{e19f}',
+    PriorityHigh = "priority_high" '\u{e645}',
+    Privacy = "privacy" '\u{f148}',
+    PrivacyTip = "privacy_tip" '\u{f0dc}',
+    PrivateConnectivity = "private_connectivity" '\u{e744}',
+    Problem = "problem" '\u{e122}',
+    Procedure = "procedure" '\u{e651}',
+    ProcessChart = "process_chart" '\u{f855}',
+    ProductionQuantityLimits = "production_quantity_limits" '\u{e1d1}',
+    Productivity = "productivity" '\u{e296}',
+    ProgressActivity = "progress_activity" '\u{e9d0}',
+    PromptSuggestion = "prompt_suggestion" '\u{f4f6}',
+    Propane = "propane" '\u{ec14}',
+    PropaneTank = "propane_tank" '\u{ec13}',
+    Psychiatry = "psychiatry" '\u{e123}',
+    Psychology = "psychology" '\u{ea4a}',
+    PsychologyAlt = "psychology_alt" '\u{f8ea}',
+    Public = "public" '\u{e80b}',
+    PublicOff = "public_off" '\u{f1ca}',
+    Publish = "publish" '\u{e255}',
+    PublishedWithChanges = "published_with_changes" '\u{f232}',
+    Pulmonology = "pulmonology" '\u{e124}',
+    PulseAlert = "pulse_alert" '\u{f501}',
+    PunchClock = "punch_clock" '\u{eaa8}',
+    PushPin = "push_pin" '\u{f10d}',
+    QrCode = "qr_code" '\u{ef6b}',
+    QrCode2 = "qr_code_2" '\u{e00a}',
+    QrCode2Add = "qr_code_2_add" '\u{f658}',
+    QrCodeScanner = "qr_code_scanner" '\u{f206}',
+    QueryBuilder = "query_builder" '\u{efd6}',
+    QueryStats = "query_stats" '\u{e4fc}',
+    QuestionAnswer = "question_answer" '\u{e8af}',
+    QuestionExchange = "question_exchange" '\u{f7f3}',
+    QuestionMark = "question_mark" '\u{eb8b}',
+    Queue = "queue" '\u{e03c}',
+    QueueMusic = "queue_music" '\u{e03d}',
+    QueuePlayNext = "queue_play_next" '\u{e066}',
+    QuickPhrases = "quick_phrases" '\u{e7d1}',
+    QuickReference = "quick_reference" '\u{e46e}',
+    QuickReferenceAll = "quick_reference_all" '\u{f801}',
+    QuickReorder = "quick_reorder" '\u{eb15}',
+    Quickreply = "quickreply" '\u{ef6c}',
+    QuietTime = "quiet_time" '\u{e1f9}',
+    QuietTimeActive = "quiet_time_active" '\u{e291}',
+    Quiz = "quiz" '\u{f04c}',
+    RMobiledata = "r_mobiledata" '\u{f04d}',
+    Radar = "radar" '\u{f04e}',
+    Radio = "radio" '\u{e03e}',
+    RadioButtonChecked = "radio_button_checked" '\u{e837}',
+    RadioButtonPartial = "radio_button_partial" '\u{f560}',
+    RadioButtonUnchecked = "radio_button_unchecked" '\u{e836}',
+    Radiology = "radiology" '\u{e125}',
+    RailwayAlert = "railway_alert" '\u{e9d1}',
+    RailwayAlert2 = "railway_alert_2" '\u{f461}',
+    Rainy = "rainy" '\u{f176}',
+    RainyHeavy = "rainy_heavy" '\u{f61f}',
+    RainyLight = "rainy_light" '\u{f61e}',
+    RainySnow = "rainy_snow" '\u{f61d}',
+    RamenDining = "ramen_dining" '\u{ea64}',
+    RampLeft = "ramp_left" '\u{eb9c}',
+    RampRight = "ramp_right" '\u{eb96}',
+    RangeHood = "range_hood" '\u{e1ea}',
+    RateReview = "rate_review" '\u{e560}',
+    Raven = "raven" '\u{f555}',
+    RawOff = "raw_off" '\u{f04f}',
+    RawOn = "raw_on" '\u{f050}',
+    ReadMore = "read_more" '\u{ef6d}',
+    ReadinessScore = "readiness_score" '\u{f6dd}',
+    RealEstateAgent = "real_estate_agent" '\u{e73a}',
+    RearCamera = "rear_camera" '\u{f6c2}',
+    Rebase = "rebase" '\u{f845}',
+    RebaseEdit = "rebase_edit" '\u{f846}',
+    Receipt = "receipt" '\u{e8b0}',
+    ReceiptLong = "receipt_long" '\u{ef6e}',
+    ReceiptLongOff = "receipt_long_off" '\u{f40a}',
+    RecentActors = "recent_actors" '\u{e03f}',
+    RecentPatient = "recent_patient" '\u{f808}',
+    Recenter = "recenter" '\u{f4c0}',
+    Recommend = "recommend" '\u{e9d2}',
+    RecordVoiceOver = "record_voice_over" '\u{e91f}',
+    Rectangle = "rectangle" '\u{eb54}',
+    Recycling = "recycling" '\u{e760}',
+    Redeem = "redeem" '\u{e8f6}',
+    Redo = "redo" '\u{e15a}',
+    ReduceCapacity = "reduce_capacity" '\u{f21c}',
+    Refresh = "refresh" '\u{e5d5}',
+    RegLogoIft = "reg_logo_ift" '\u{f3ef}',
+    RegularExpression = "regular_expression" '\u{f750}',
+    Relax = "relax" '\u{f6dc}',
+    ReleaseAlert = "release_alert" '\u{f654}',
+    RememberMe = "remember_me" '\u{f051}',
+    Reminder = "reminder" '\u{e6c6}',
+    RemindersAlt = "reminders_alt" '\u{e6c6}',
+    RemoteGen = "remote_gen" '\u{e83e}',
+    Remove = "remove" '\u{e15b}',
+    RemoveCircle = "remove_circle" '\u{f08f}',
+    RemoveCircleOutline = "remove_circle_outline" '\u{f08f}',
+    RemoveDone = "remove_done" '\u{e9d3}',
+    RemoveFromQueue = "remove_from_queue" '\u{e067}',
+    RemoveModerator = "remove_moderator" '\u{e9d4}',
+    RemoveRedEye = "remove_red_eye" '\u{e8f4}',
+    RemoveRoad = "remove_road" '\u{ebfc}',
+    RemoveSelection = "remove_selection" '\u{e9d5}',
+    RemoveShoppingCart = "remove_shopping_cart" '\u{e928}',
+    ReopenWindow = "reopen_window" '\u{f708}',
+    Reorder = "reorder" '\u{e8fe}',
+    Repartition = "repartition" '\u{f8e8}',
+    Repeat = "repeat" '\u{e040}',
+    RepeatOn = "repeat_on" '\u{e9d6}',
+    RepeatOne = "repeat_one" '\u{e041}',
+    RepeatOneOn = "repeat_one_on" '\u{e9d7}',
+    ReplaceAudio = "replace_audio" '\u{f451}',
+    ReplaceImage = "replace_image" '\u{f450}',
+    ReplaceVideo = "replace_video" '\u{f44f}',
+    Replay = "replay" '\u{e042}',
+    Replay10 = "replay_10" '\u{e059}',
+    Replay30 = "replay_30" '\u{e05a}',
+    Replay5 = "replay_5" '\u{e05b}',
+    ReplayCircleFilled = "replay_circle_filled" '\u{e9d8}',
+    Reply = "reply" '\u{e15e}',
+    ReplyAll = "reply_all" '\u{e15f}',
+    Report = "report" '\u{f052}',
+    ReportGmailerrorred = "report_gmailerrorred" '\u{f052}',
+    ReportOff = "report_off" '\u{e170}',
+    ReportProblem = "report_problem" '\u{f083}',
+    RequestPage = "request_page" '\u{f22c}',
+    RequestQuote = "request_quote" '\u{f1b6}',
+    ResetBrightness = "reset_brightness" '\u{f482}',
+    ResetFocus = "reset_focus" '\u{f481}',
+    ResetImage = "reset_image" '\u{f824}',
+    ResetIso = "reset_iso" '\u{f480}',
+    ResetSettings = "reset_settings" '\u{f47f}',
+    ResetShadow = "reset_shadow" '\u{f47e}',
+    ResetShutterSpeed = "reset_shutter_speed" '\u{f47d}',
+    ResetTv = "reset_tv" '\u{e9d9}',
+    ResetWhiteBalance = "reset_white_balance" '\u{f47c}',
+    ResetWrench = "reset_wrench" '\u{f56c}',
+    Resize = "resize" '\u{f707}',
+    RespiratoryRate = "respiratory_rate" '\u{e127}',
+    ResponsiveLayout = "responsive_layout" '\u{e9da}',
+    RestartAlt = "restart_alt" '\u{f053}',
+    Restaurant = "restaurant" '\u{e56c}',
+    RestaurantMenu = "restaurant_menu" '\u{e561}',
+    Restore = "restore" '\u{e8b3}',
+    RestoreFromTrash = "restore_from_trash" '\u{e938}',
+    RestorePage = "restore_page" '\u{e929}',
+    Resume = "resume" '\u{f7d0}',
+    Reviews = "reviews" '\u{f07c}',
+    RewardedAds = "rewarded_ads" '\u{efb6}',
+    Rheumatology = "rheumatology" '\u{e128}',
+    RibCage = "rib_cage" '\u{f898}',
+    RiceBowl = "rice_bowl" '\u{f1f5}',
+    RightClick = "right_click" '\u{f706}',
+    RightPanelClose = "right_panel_close" '\u{f705}',
+    RightPanelOpen = "right_panel_open" '\u{f704}',
+    RingVolume = "ring_volume" '\u{f0dd}',
+    RingVolumeFilled = "ring_volume_filled" '\u{f0dd}',
+    Ripples = "ripples" '\u{e9db}',
+    Road = "road" '\u{f472}',
+    Robot = "robot" '\u{f882}',
+    Robot2 = "robot_2" '\u{f5d0}',
+    Rocket = "rocket" '\u{eba5}',
+    RocketLaunch = "rocket_launch" '\u{eb9b}',
+    RollerShades = "roller_shades" '\u{ec12}',
+    RollerShadesClosed = "roller_shades_closed" '\u{ec11}',
+    RollerSkating = "roller_skating" '\u{ebcd}',
+    Roofing = "roofing" '\u{f201}',
+    Room = "room" '\u{f1db}',
+    RoomPreferences = "room_preferences" '\u{f1b8}',
+    RoomService = "room_service" '\u{eb49}',
+    Rotate90DegreesCcw = "rotate_90_degrees_ccw" '\u{e418}',
+    Rotate90DegreesCw = "rotate_90_degrees_cw" '\u{eaab}',
+    RotateAuto = "rotate_auto" '\u{f417}',
+    RotateLeft = "rotate_left" '\u{e419}',
+    RotateRight = "rotate_right" '\u{e41a}',
+    RoundaboutLeft = "roundabout_left" '\u{eb99}',
+    RoundaboutRight = "roundabout_right" '\u{eba3}',
+    RoundedCorner = "rounded_corner" '\u{e920}',
+    Route = "route" '\u{eacd}',
+    Router = "router" '\u{e328}',
+    Routine = "routine" '\u{e20c}',
+    Rowing = "rowing" '\u{e921}',
+    RssFeed = "rss_feed" '\u{e0e5}',
+    Rsvp = "rsvp" '\u{f055}',
+    Rtt = "rtt" '\u{e9ad}',
+    Rubric = "rubric" '\u{eb27}',
+    Rule = "rule" '\u{f1c2}',
+    RuleFolder = "rule_folder" '\u{f1c9}',
+    RuleSettings = "rule_settings" '\u{f64c}',
+    RunCircle = "run_circle" '\u{ef6f}',
+    RunningWithErrors = "running_with_errors" '\u{e51d}',
+    RvHookup = "rv_hookup" '\u{e642}',
+    SafetyCheck = "safety_check" '\u{ebef}',
+    SafetyCheckOff = "safety_check_off" '\u{f59d}',
+    SafetyDivider = "safety_divider" '\u{e1cc}',
+    Sailing = "sailing" '\u{e502}',
+    Salinity = "salinity" '\u{f876}',
+    Sanitizer = "sanitizer" '\u{f21d}',
+    Satellite = "satellite" '\u{e562}',
+    SatelliteAlt = "satellite_alt" '\u{eb3a}',
+    Sauna = "sauna" '\u{f6f7}',
+    Save = "save" '\u{e161}',
+    SaveAlt = "save_alt" '\u{f090}',
+    SaveAs = "save_as" '\u{eb60}',
+    SavedSearch = "saved_search" '\u{ea11}',
+    Savings = "savings" '\u{e2eb}',
+    Scale = "scale" '\u{eb5f}',
+    Scan = "scan" '\u{f74e}',
+    ScanDelete = "scan_delete" '\u{f74f}',
+    Scanner = "scanner" '\u{e329}',
+    ScatterPlot = "scatter_plot" '\u{e268}',
+    Scene = "scene" '\u{e2a7}',
+    Schedule = "schedule" '\u{efd6}',
+    ScheduleSend = "schedule_send" '\u{ea0a}',
+    Schema = "schema" '\u{e4fd}',
+    School = "school" '\u{e80c}',
+    Science = "science" '\u{ea4b}',
+    ScienceOff = "science_off" '\u{f542}',
+    Scooter = "scooter" '\u{f471}',
+    Score = "score" '\u{e269}',
+    Scoreboard = "scoreboard" '\u{ebd0}',
+    ScreenLockLandscape = "screen_lock_landscape" '\u{e1be}',
+    ScreenLockPortrait = "screen_lock_portrait" '\u{e1bf}',
+    ScreenLockRotation = "screen_lock_rotation" '\u{e1c0}',
+    ScreenRecord = "screen_record" '\u{f679}',
+    ScreenRotation = "screen_rotation" '\u{e1c1}',
+    ScreenRotationAlt = "screen_rotation_alt" '\u{ebee}',
+    ScreenRotationUp = "screen_rotation_up" '\u{f678}',
+    ScreenSearchDesktop = "screen_search_desktop" '\u{ef70}',
+    ScreenShare = "screen_share" '\u{e0e2}',
+    Screenshot = "screenshot" '\u{f056}',
+    ScreenshotFrame = "screenshot_frame" '\u{f677}',
+    ScreenshotKeyboard = "screenshot_keyboard" '\u{f7d3}',
+    ScreenshotMonitor = "screenshot_monitor" '\u{ec08}',
+    ScreenshotRegion = "screenshot_region" '\u{f7d2}',
+    ScreenshotTablet = "screenshot_tablet" '\u{f697}',
+    Script = "script" '\u{f45f}',
+    ScrollableHeader = "scrollable_header" '\u{e9dc}',
+    ScubaDiving = "scuba_diving" '\u{ebce}',
+    Sd = "sd" '\u{e9dd}',
+    SdCard = "sd_card" '\u{e623}',
+    SdCardAlert = "sd_card_alert" '\u{f057}',
+    SdStorage = "sd_storage" '\u{e623}',
+    Sdk = "sdk" '\u{e720}',
+    Search = "search" '\u{e8b6}',
+    SearchCheck = "search_check" '\u{f800}',
+    SearchCheck2 = "search_check_2" '\u{f469}',
+    SearchHandsFree = "search_hands_free" '\u{e696}',
+    SearchInsights = "search_insights" '\u{f4bc}',
+    SearchOff = "search_off" '\u{ea76}',
+    Security = "security" '\u{e32a}',
+    SecurityKey = "security_key" '\u{f503}',
+    SecurityUpdate = "security_update" '\u{f072}',
+    SecurityUpdateGood = "security_update_good" '\u{f073}',
+    SecurityUpdateWarning = "security_update_warning" '\u{f074}',
+    Segment = "segment" '\u{e94b}',
+    Select = "select" '\u{f74d}',
+    SelectAll = "select_all" '\u{e162}',
+    SelectCheckBox = "select_check_box" '\u{f1fe}',
+    SelectToSpeak = "select_to_speak" '\u{f7cf}',
+    SelectWindow = "select_window" '\u{e6fa}',
+    SelectWindow2 = "select_window_2" '\u{f4c8}',
+    SelectWindowOff = "select_window_off" '\u{e506}',
+    SelfCare = "self_care" '\u{f86d}',
+    SelfImprovement = "self_improvement" '\u{ea78}',
+    Sell = "sell" '\u{f05b}',
+    Send = "send" '\u{e163}',
+    SendAndArchive = "send_and_archive" '\u{ea0c}',
+    SendMoney = "send_money" '\u{e8b7}',
+    SendTimeExtension = "send_time_extension" '\u{eadb}',
+    SendToMobile = "send_to_mobile" '\u{f05c}',
+    SensorDoor = "sensor_door" '\u{f1b5}',
+    SensorOccupied = "sensor_occupied" '\u{ec10}',
+    SensorWindow = "sensor_window" '\u{f1b4}',
+    Sensors = "sensors" '\u{e51e}',
+    SensorsKrx = "sensors_krx" '\u{f556}',
+    SensorsKrxOff = "sensors_krx_off" '\u{f515}',
+    SensorsOff = "sensors_off" '\u{e51f}',
+    SentimentCalm = "sentiment_calm" '\u{f6a7}',
+    SentimentContent = "sentiment_content" '\u{f6a6}',
+    SentimentDissatisfied = "sentiment_dissatisfied" '\u{e811}',
+    SentimentExcited = "sentiment_excited" '\u{f6a5}',
+    SentimentExtremelyDissatisfied = "sentiment_extremely_dissatisfied" '\u{f194}',
+    SentimentFrustrated = "sentiment_frustrated" '\u{f6a4}',
+    SentimentNeutral = "sentiment_neutral" '\u{e812}',
+    SentimentSad = "sentiment_sad" '\u{f6a3}',
+    SentimentSatisfied = "sentiment_satisfied" '\u{e813}',
+    SentimentSatisfiedAlt = "sentiment_satisfied_alt" '\u{e813}',
+    SentimentStressed = "sentiment_stressed" '\u{f6a2}',
+    SentimentVeryDissatisfied = "sentiment_very_dissatisfied" '\u{e814}',
+    SentimentVerySatisfied = "sentiment_very_satisfied" '\u{e815}',
+    SentimentWorried = "sentiment_worried" '\u{f6a1}',
+    Serif = "serif" '\u{f4ac}',
+    ServiceToolbox = "service_toolbox" '\u{e717}',
+    SetMeal = "set_meal" '\u{f1ea}',
+    Settings = "settings" '\u{e8b8}',
+    SettingsAccessibility = "settings_accessibility" '\u{f05d}',
+    SettingsAccountBox = "settings_account_box" '\u{f835}',
+    SettingsAlert = "settings_alert" '\u{f143}',
+    SettingsApplications = "settings_applications" '\u{e8b9}',
+    SettingsBRoll = "settings_b_roll" '\u{f625}',
+    SettingsBackupRestore = "settings_backup_restore" '\u{e8ba}',
+    SettingsBluetooth = "settings_bluetooth" '\u{e8bb}',
+    SettingsBrightness = "settings_brightness" '\u{e8bd}',
+    SettingsCell = "settings_cell" '\u{e8bc}',
+    SettingsCinematicBlur = "settings_cinematic_blur" '\u{f624}',
+    SettingsEthernet = "settings_ethernet" '\u{e8be}',
+    SettingsHeart = "settings_heart" '\u{f522}',
+    SettingsInputAntenna = "settings_input_antenna" '\u{e8bf}',
+    SettingsInputComponent = "settings_input_component" '\u{e8c1}',
+    SettingsInputComposite = "settings_input_composite" '\u{e8c1}',
+    SettingsInputHdmi = "settings_input_hdmi" '\u{e8c2}',
+    SettingsInputSvideo = "settings_input_svideo" '\u{e8c3}',
+    SettingsMotionMode = "settings_motion_mode" '\u{f833}',
+    SettingsNightSight = "settings_night_sight" '\u{f832}',
+    SettingsOverscan = "settings_overscan" '\u{e8c4}',
+    SettingsPanorama = "settings_panorama" '\u{f831}',
+    SettingsPhone = "settings_phone" '\u{e8c5}',
+    SettingsPhotoCamera = "settings_photo_camera" '\u{f834}',
+    SettingsPower = "settings_power" '\u{e8c6}',
+    SettingsRemote = "settings_remote" '\u{e8c7}',
+    SettingsSlowMotion = "settings_slow_motion" '\u{f623}',
+    SettingsSuggest = "settings_suggest" '\u{f05e}',
+    SettingsSystemDaydream = "settings_system_daydream" '\u{e1c3}',
+    SettingsTimelapse = "settings_timelapse" '\u{f622}',
+    SettingsVideoCamera = "settings_video_camera" '\u{f621}',
+    SettingsVoice = "settings_voice" '\u{e8c8}',
+    SettopComponent = "settop_component" '\u{e2ac}',
+    SevereCold = "severe_cold" '\u{ebd3}',
+    Shadow = "shadow" '\u{e9df}',
+    ShadowAdd = "shadow_add" '\u{f584}',
+    ShadowMinus = "shadow_minus" '\u{f583}',
+    ShapeLine = "shape_line" '\u{f8d3}',
+    ShapeRecognition = "shape_recognition" '\u{eb01}',
+    Shapes = "shapes" '\u{e602}',
+    Share = "share" '\u{e80d}',
+    ShareLocation = "share_location" '\u{f05f}',
+    ShareOff = "share_off" '\u{f6cb}',
+    ShareReviews = "share_reviews" '\u{f8a4}',
+    ShareWindows = "share_windows" '\u{f613}',
+    SheetsRtl = "sheets_rtl" '\u{f823}',
+    ShelfAutoHide = "shelf_auto_hide" '\u{f703}',
+    ShelfPosition = "shelf_position" '\u{f702}',
+    Shelves = "shelves" '\u{f86e}',
+    Shield = "shield" '\u{e9e0}',
+    ShieldLock = "shield_lock" '\u{f686}',
+    ShieldLocked = "shield_locked" '\u{f592}',
+    ShieldMoon = "shield_moon" '\u{eaa9}',
+    ShieldPerson = "shield_person" '\u{f650}',
+    ShieldQuestion = "shield_question" '\u{f529}',
+    ShieldWithHeart = "shield_with_heart" '\u{e78f}',
+    ShieldWithHouse = "shield_with_house" '\u{e78d}',
+    Shift = "shift" '\u{e5f2}',
+    ShiftLock = "shift_lock" '\u{f7ae}',
+    ShiftLockOff = "shift_lock_off" '\u{f483}',
+    Shop = "shop" '\u{e8c9}',
+    Shop2 = "shop_2" '\u{e8ca}',
+    ShopTwo = "shop_two" '\u{e8ca}',
+    ShoppingBag = "shopping_bag" '\u{f1cc}',
+    ShoppingBasket = "shopping_basket" '\u{e8cb}',
+    ShoppingCart = "shopping_cart" '\u{e8cc}',
+    ShoppingCartCheckout = "shopping_cart_checkout" '\u{eb88}',
+    ShoppingCartOff = "shopping_cart_off" '\u{f4f7}',
+    Shoppingmode = "shoppingmode" '\u{efb7}',
+    ShortStay = "short_stay" '\u{e4d0}',
+    ShortText = "short_text" '\u{e261}',
+    Shortcut = "shortcut" '\u{f57a}',
+    ShowChart = "show_chart" '\u{e6e1}',
+    Shower = "shower" '\u{f061}',
+    Shuffle = "shuffle" '\u{e043}',
+    ShuffleOn = "shuffle_on" '\u{e9e1}',
+    ShutterSpeed = "shutter_speed" '\u{e43d}',
+    ShutterSpeedAdd = "shutter_speed_add" '\u{f57e}',
+    ShutterSpeedMinus = "shutter_speed_minus" '\u{f57d}',
+    Sick = "sick" '\u{f220}',
+    SideNavigation = "side_navigation" '\u{e9e2}',
+    SignLanguage = "sign_language" '\u{ebe5}',
+    SignalCellular0Bar = "signal_cellular_0_bar" '\u{f0a8}',
+    SignalCellular1Bar = "signal_cellular_1_bar" '\u{f0a9}',
+    SignalCellular2Bar = "signal_cellular_2_bar" '\u{f0aa}',
+    SignalCellular3Bar = "signal_cellular_3_bar" '\u{f0ab}',
+    SignalCellular4Bar = "signal_cellular_4_bar" '\u{e1c8}',
+    SignalCellularAdd = "signal_cellular_add" '\u{f7a9}',
+    SignalCellularAlt = "signal_cellular_alt" '\u{e202}',
+    SignalCellularAlt1Bar = "signal_cellular_alt_1_bar" '\u{ebdf}',
+    SignalCellularAlt2Bar = "signal_cellular_alt_2_bar" '\u{ebe3}',
+    SignalCellularConnectedNoInternet0Bar = "signal_cellular_connected_no_internet_0_bar" '\u{f0ac}',
+    SignalCellularConnectedNoInternet4Bar = "signal_cellular_connected_no_internet_4_bar" '\u{e1cd}',
+    SignalCellularNoSim = "signal_cellular_no_sim" '\u{e1ce}',
+    SignalCellularNodata = "signal_cellular_nodata" '\u{f062}',
+    SignalCellularNull = "signal_cellular_null" '\u{e1cf}',
+    SignalCellularOff = "signal_cellular_off" '\u{e1d0}',
+    SignalCellularPause = "signal_cellular_pause" '\u{f5a7}',
+    SignalDisconnected = "signal_disconnected" '\u{f239}',
+    SignalWifi0Bar = "signal_wifi_0_bar" '\u{f0b0}',
+    SignalWifi4Bar = "signal_wifi_4_bar" '\u{f065}',
+    SignalWifi4BarLock = "signal_wifi_4_bar_lock" '\u{e1e1}',
+    SignalWifiBad = "signal_wifi_bad" '\u{f064}',
+    SignalWifiConnectedNoInternet4 = "signal_wifi_connected_no_internet_4" '\u{f064}',
+    SignalWifiOff = "signal_wifi_off" '\u{e1da}',
+    SignalWifiStatusbar4Bar = "signal_wifi_statusbar_4_bar" '\u{f065}',
+    SignalWifiStatusbarNotConnected = "signal_wifi_statusbar_not_connected" '\u{f0ef}',
+    SignalWifiStatusbarNull = "signal_wifi_statusbar_null" '\u{f067}',
+    Signature = "signature" '\u{f74c}',
+    Signpost = "signpost" '\u{eb91}',
+    SimCard = "sim_card" '\u{e32b}',
+    SimCardAlert = "sim_card_alert" '\u{f057}',
+    SimCardDownload = "sim_card_download" '\u{f068}',
+    SingleBed = "single_bed" '\u{ea48}',
+    Sip = "sip" '\u{f069}',
+    Skateboarding = "skateboarding" '\u{e511}',
+    Skeleton = "skeleton" '\u{f899}',
+    Skillet = "skillet" '\u{f543}',
+    SkilletCooktop = "skillet_cooktop" '\u{f544}',
+    SkipNext = "skip_next" '\u{e044}',
+    SkipPrevious = "skip_previous" '\u{e045}',
+    Skull = "skull" '\u{f89a}',
+    SlabSerif = "slab_serif" '\u{f4ab}',
+    Sledding = "sledding" '\u{e512}',
+    Sleep = "sleep" '\u{e213}',
+    SleepScore = "sleep_score" '\u{f6b7}',
+    SlideLibrary = "slide_library" '\u{f822}',
+    Sliders = "sliders" '\u{e9e3}',
+    Slideshow = "slideshow" '\u{e41b}',
+    SlowMotionVideo = "slow_motion_video" '\u{e068}',
+    SmartButton = "smart_button" '\u{f1c1}',
+    SmartCardReader = "smart_card_reader" '\u{f4a5}',
+    SmartCardReaderOff = "smart_card_reader_off" '\u{f4a6}',
+    SmartDisplay = "smart_display" '\u{f06a}',
+    SmartOutlet = "smart_outlet" '\u{e844}',
+    SmartScreen = "smart_screen" '\u{f06b}',
+    SmartToy = "smart_toy" '\u{f06c}',
+    Smartphone = "smartphone" '\u{e32c}',
+    SmartphoneCamera = "smartphone_camera" '\u{f44e}',
+    SmbShare = "smb_share" '\u{f74b}',
+    SmokeFree = "smoke_free" '\u{eb4a}',
+    SmokingRooms = "smoking_rooms" '\u{eb4b}',
+    Sms = "sms" '\u{e625}',
+    SmsFailed = "sms_failed" '\u{e87f}',
+    SnippetFolder = "snippet_folder" '\u{f1c7}',
+    Snooze = "snooze" '\u{e046}',
+    Snowboarding = "snowboarding" '\u{e513}',
+    Snowing = "snowing" '\u{e80f}',
+    SnowingHeavy = "snowing_heavy" '\u{f61c}',
+    Snowmobile = "snowmobile" '\u{e503}',
+    Snowshoeing = "snowshoeing" '\u{e514}',
+    Soap = "soap" '\u{f1b2}',
+    SocialDistance = "social_distance" '\u{e1cb}',
+    SocialLeaderboard = "social_leaderboard" '\u{f6a0}',
+    SolarPower = "solar_power" '\u{ec0f}',
+    Sort = "sort" '\u{e164}',
+    SortByAlpha = "sort_by_alpha" '\u{e053}',
+    Sos = "sos" '\u{ebf7}',
+    SoundDetectionDogBarking = "sound_detection_dog_barking" '\u{f149}',
+    SoundDetectionGlassBreak = "sound_detection_glass_break" '\u{f14a}',
+    SoundDetectionLoudSound = "sound_detection_loud_sound" '\u{f14b}',
+    SoundSampler = "sound_sampler" '\u{f6b4}',
+    SoupKitchen = "soup_kitchen" '\u{e7d3}',
+    Source = "source" '\u{f1c8}',
+    SourceEnvironment = "source_environment" '\u{e527}',
+    SourceNotes = "source_notes" '\u{e12d}',
+    South = "south" '\u{f1e3}',
+    SouthAmerica = "south_america" '\u{e7e4}',
+    SouthEast = "south_east" '\u{f1e4}',
+    SouthWest = "south_west" '\u{f1e5}',
+    Spa = "spa" '\u{eb4c}',
+    SpaceBar = "space_bar" '\u{e256}',
+    SpaceDashboard = "space_dashboard" '\u{e66b}',
+    SpatialAudio = "spatial_audio" '\u{ebeb}',
+    SpatialAudioOff = "spatial_audio_off" '\u{ebe8}',
+    SpatialSpeaker = "spatial_speaker" '\u{f4cf}',
+    SpatialTracking = "spatial_tracking" '\u{ebea}',
+    Speaker = "speaker" '\u{e32d}',
+    SpeakerGroup = "speaker_group" '\u{e32e}',
+    SpeakerNotes = "speaker_notes" '\u{e8cd}',
+    SpeakerNotesOff = "speaker_notes_off" '\u{e92a}',
+    SpeakerPhone = "speaker_phone" '\u{e0d2}',
+    SpecialCharacter = "special_character" '\u{f74a}',
+    SpecificGravity = "specific_gravity" '\u{f872}',
+    SpeechToText = "speech_to_text" '\u{f8a7}',
+    Speed = "speed" '\u{e9e4}',
+    Speed025 = "speed_0_25" '\u{f4d4}',
+    Speed02x = "speed_0_2x" '\u{f498}',
+    Speed05 = "speed_0_5" '\u{f4e2}',
+    Speed05x = "speed_0_5x" '\u{f497}',
+    Speed075 = "speed_0_75" '\u{f4d3}',
+    Speed07x = "speed_0_7x" '\u{f496}',
+    Speed12 = "speed_1_2" '\u{f4e1}',
+    Speed125 = "speed_1_25" '\u{f4d2}',
+    Speed12x = "speed_1_2x" '\u{f495}',
+    Speed15 = "speed_1_5" '\u{f4e0}',
+    Speed15x = "speed_1_5x" '\u{f494}',
+    Speed175 = "speed_1_75" '\u{f4d1}',
+    Speed17x = "speed_1_7x" '\u{f493}',
+    Speed2x = "speed_2x" '\u{f4eb}',
+    SpeedCamera = "speed_camera" '\u{f470}',
+    Spellcheck = "spellcheck" '\u{e8ce}',
+    Splitscreen = "splitscreen" '\u{f06d}',
+    SplitscreenAdd = "splitscreen_add" '\u{f4fd}',
+    SplitscreenBottom = "splitscreen_bottom" '\u{f676}',
+    SplitscreenLandscape = "splitscreen_landscape" '\u{f459}',
+    SplitscreenLeft = "splitscreen_left" '\u{f675}',
+    SplitscreenPortrait = "splitscreen_portrait" '\u{f458}',
+    SplitscreenRight = "splitscreen_right" '\u{f674}',
+    SplitscreenTop = "splitscreen_top" '\u{f673}',
+    SplitscreenVerticalAdd = "splitscreen_vertical_add" '\u{f4fc}',
+    Spo2 = "spo2" '\u{f6db}',
+    Spoke = "spoke" '\u{e9a7}',
+    Sports = "sports" '\u{ea30}',
+    SportsAndOutdoors = "sports_and_outdoors" '\u{efb8}',
+    SportsBar = "sports_bar" '\u{f1f3}',
+    SportsBaseball = "sports_baseball" '\u{ea51}',
+    SportsBasketball = "sports_basketball" '\u{ea26}',
+    SportsCricket = "sports_cricket" '\u{ea27}',
+    SportsEsports = "sports_esports" '\u{ea28}',
+    SportsFootball = "sports_football" '\u{ea29}',
+    SportsGolf = "sports_golf" '\u{ea2a}',
+    SportsGymnastics = "sports_gymnastics" '\u{ebc4}',
+    SportsHandball = "sports_handball" '\u{ea33}',
+    SportsHockey = "sports_hockey" '\u{ea2b}',
+    SportsKabaddi = "sports_kabaddi" '\u{ea34}',
+    SportsMartialArts = "sports_martial_arts" '\u{eae9}',
+    SportsMma = "sports_mma" '\u{ea2c}',
+    SportsMotorsports = "sports_motorsports" '\u{ea2d}',
+    SportsRugby = "sports_rugby" '\u{ea2e}',
+    SportsScore = "sports_score" '\u{f06e}',
+    SportsSoccer = "sports_soccer" '\u{ea2f}',
+    SportsTennis = "sports_tennis" '\u{ea32}',
+    SportsVolleyball = "sports_volleyball" '\u{ea31}',
+    Sprinkler = "sprinkler" '\u{e29a}',
+    Sprint = "sprint" '\u{f81f}',
+    Square = "square" '\u{eb36}',
+    SquareFoot = "square_foot" '\u{ea49}',
+    SsidChart = "ssid_chart" '\u{eb66}',
+    Stack = "stack" '\u{f609}',
+    StackHexagon = "stack_hexagon" '\u{f41c}',
+    StackOff = "stack_off" '\u{f608}',
+    StackStar = "stack_star" '\u{f607}',
+    StackedBarChart = "stacked_bar_chart" '\u{e9e6}',
+    StackedEmail = "stacked_email" '\u{e6c7}',
+    StackedInbox = "stacked_inbox" '\u{e6c9}',
+    StackedLineChart = "stacked_line_chart" '\u{f22b}',
+    Stacks = "stacks" '\u{f500}',
+    StadiaController = "stadia_controller" '\u{f135}',
+    Stadium = "stadium" '\u{eb90}',
+    Stairs = "stairs" '\u{f1a9}',
+    Stairs2 = "stairs_2" '\u{f46c}',
+    Star = "star" '\u{f09a}',
+    StarBorder = "star_border" '\u{f09a}',
+    StarBorderPurple500 = "star_border_purple500" '\u{f09a}',
+    StarHalf = "star_half" '\u{e839}',
+    StarOutline = "star_outline" '\u{f09a}',
+    StarPurple500 = "star_purple500" '\u{f09a}',
+    StarRate = "star_rate" '\u{f0ec}',
+    StarRateHalf = "star_rate_half" '\u{ec45}',
+    Stars = "stars" '\u{e8d0}',
+    Start = "start" '\u{e089}',
+    Stat0 = "stat_0" '\u{e697}',
+    Stat1 = "stat_1" '\u{e698}',
+    Stat2 = "stat_2" '\u{e699}',
+    Stat3 = "stat_3" '\u{e69a}',
+    StatMinus1 = "stat_minus_1" '\u{e69b}',
+    StatMinus2 = "stat_minus_2" '\u{e69c}',
+    StatMinus3 = "stat_minus_3" '\u{e69d}',
+    StayCurrentLandscape = "stay_current_landscape" '\u{e0d3}',
+    StayCurrentPortrait = "stay_current_portrait" '\u{e0d4}',
+    StayPrimaryLandscape = "stay_primary_landscape" '\u{e0d5}',
+    StayPrimaryPortrait = "stay_primary_portrait" '\u{e0d6}',
+    Step = "step" '\u{f6fe}',
+    StepInto = "step_into" '\u{f701}',
+    StepOut = "step_out" '\u{f700}',
+    StepOver = "step_over" '\u{f6ff}',
+    Steppers = "steppers" '\u{e9e7}',
+    Steps = "steps" '\u{f6da}',
+    Stethoscope = "stethoscope" '\u{f805}',
+    StethoscopeArrow = "stethoscope_arrow" '\u{f807}',
+    StethoscopeCheck = "stethoscope_check" '\u{f806}',
+    StickyNote = "sticky_note" '\u{e9e8}',
+    StickyNote2 = "sticky_note_2" '\u{f1fc}',
+    StockMedia = "stock_media" '\u{f570}',
+    Stockpot = "stockpot" '\u{f545}',
+    Stop = "stop" '\u{e047}',
+    StopCircle = "stop_circle" '\u{ef71}',
+    StopScreenShare = "stop_screen_share" '\u{e0e3}',
+    Storage = "storage" '\u{e1db}',
+    Store = "store" '\u{e8d1}',
+    StoreMallDirectory = "store_mall_directory" '\u{e8d1}',
+    Storefront = "storefront" '\u{ea12}',
+    Storm = "storm" '\u{f070}',
+    Straight = "straight" '\u{eb95}',
+    Straighten = "straighten" '\u{e41c}',
+    Strategy = "strategy" '\u{f5df}',
+    Stream = "stream" '\u{e9e9}',
+    StreamApps = "stream_apps" '\u{f79f}',
+    Streetview = "streetview" '\u{e56e}',
+    StressManagement = "stress_management" '\u{f6d9}',
+    StrikethroughS = "strikethrough_s" '\u{e257}',
+    StrokeFull = "stroke_full" '\u{f749}',
+    StrokePartial = "stroke_partial" '\u{f748}',
+    Stroller = "stroller" '\u{f1ae}',
+    Style = "style" '\u{e41d}',
+    Styler = "styler" '\u{e273}',
+    Stylus = "stylus" '\u{f604}',
+    StylusLaserPointer = "stylus_laser_pointer" '\u{f747}',
+    StylusNote = "stylus_note" '\u{f603}',
+    SubdirectoryArrowLeft = "subdirectory_arrow_left" '\u{e5d9}',
+    SubdirectoryArrowRight = "subdirectory_arrow_right" '\u{e5da}',
+    Subheader = "subheader" '\u{e9ea}',
+    Subject = "subject" '\u{e8d2}',
+    Subscript = "subscript" '\u{f111}',
+    Subscriptions = "subscriptions" '\u{e064}',
+    Subtitles = "subtitles" '\u{e048}',
+    SubtitlesOff = "subtitles_off" '\u{ef72}',
+    Subway = "subway" '\u{e56f}',
+    Summarize = "summarize" '\u{f071}',
+    Sunny = "sunny" '\u{e81a}',
+    SunnySnowing = "sunny_snowing" '\u{e819}',
+    Superscript = "superscript" '\u{f112}',
+    SupervisedUserCircle = "supervised_user_circle" '\u{e939}',
+    SupervisedUserCircleOff = "supervised_user_circle_off" '\u{f60e}',
+    SupervisorAccount = "supervisor_account" '\u{e8d3}',
+    Support = "support" '\u{ef73}',
+    SupportAgent = "support_agent" '\u{f0e2}',
+    Surfing = "surfing" '\u{e515}',
+    Surgical = "surgical" '\u{e131}',
+    SurroundSound = "surround_sound" '\u{e049}',
+    SwapCalls = "swap_calls" '\u{e0d7}',
+    SwapDrivingApps = "swap_driving_apps" '\u{e69e}',
+    SwapDrivingAppsWheel = "swap_driving_apps_wheel" '\u{e69f}',
+    SwapHoriz = "swap_horiz" '\u{e8d4}',
+    SwapHorizontalCircle = "swap_horizontal_circle" '\u{e933}',
+    SwapVert = "swap_vert" '\u{e8d5}',
+    SwapVerticalCircle = "swap_vertical_circle" '\u{e8d6}',
+    Sweep = "sweep" '\u{e6ac}',
+    Swipe = "swipe" '\u{e9ec}',
+    SwipeDown = "swipe_down" '\u{eb53}',
+    SwipeDownAlt = "swipe_down_alt" '\u{eb30}',
+    SwipeLeft = "swipe_left" '\u{eb59}',
+    SwipeLeftAlt = "swipe_left_alt" '\u{eb33}',
+    SwipeRight = "swipe_right" '\u{eb52}',
+    SwipeRightAlt = "swipe_right_alt" '\u{eb56}',
+    SwipeUp = "swipe_up" '\u{eb2e}',
+    SwipeUpAlt = "swipe_up_alt" '\u{eb35}',
+    SwipeVertical = "swipe_vertical" '\u{eb51}',
+    Switch = "switch" '\u{e1f4}',
+    SwitchAccess = "switch_access" '\u{f6fd}',
+    SwitchAccess2 = "switch_access_2" '\u{f506}',
+    SwitchAccessShortcut = "switch_access_shortcut" '\u{e7e1}',
+    SwitchAccessShortcutAdd = "switch_access_shortcut_add" '\u{e7e2}',
+    SwitchAccount = "switch_account" '\u{e9ed}',
+    SwitchCamera = "switch_camera" '\u{e41e}',
+    SwitchLeft = "switch_left" '\u{f1d1}',
+    SwitchRight = "switch_right" '\u{f1d2}',
+    SwitchVideo = "switch_video" '\u{e41f}',
+    Switches = "switches" '\u{e733}',
+    SwordRose = "sword_rose" '\u{f5de}',
+    Swords = "swords" '\u{f889}',
+    Symptoms = "symptoms" '\u{e132}',
+    Synagogue = "synagogue" '\u{eab0}',
+    Sync = "sync" '\u{e627}',
+    SyncAlt = "sync_alt" '\u{ea18}',
+    SyncDesktop = "sync_desktop" '\u{f41a}',
+    SyncDisabled = "sync_disabled" '\u{e628}',
+    SyncLock = "sync_lock" '\u{eaee}',
+    SyncProblem = "sync_problem" '\u{e629}',
+    SyncSavedLocally = "sync_saved_locally" '\u{f820}',
+    Syringe = "syringe" '\u{e133}',
+    SystemSecurityUpdate = "system_security_update" '\u{f072}',
+    SystemSecurityUpdateGood = "system_security_update_good" '\u{f073}',
+    SystemSecurityUpdateWarning = "system_security_update_warning" '\u{f074}',
+    SystemUpdate = "system_update" '\u{f072}',
+    SystemUpdateAlt = "system_update_alt" '\u{e8d7}',
+    Tab = "tab" '\u{e8d8}',
+    TabClose = "tab_close" '\u{f745}',
+    TabCloseRight = "tab_close_right" '\u{f746}',
+    TabDuplicate = "tab_duplicate" '\u{f744}',
+    TabGroup = "tab_group" '\u{f743}',
+    TabInactive = "tab_inactive" '\u{f43b}',
+    TabMove = "tab_move" '\u{f742}',
+    TabNewRight = "tab_new_right" '\u{f741}',
+    TabRecent = "tab_recent" '\u{f740}',
+    TabUnselected = "tab_unselected" '\u{e8d9}',
+    Table = "table" '\u{f191}',
+    TableBar = "table_bar" '\u{ead2}',
+    TableChart = "table_chart" '\u{e265}',
+    TableChartView = "table_chart_view" '\u{f6ef}',
+    TableEye = "table_eye" '\u{f466}',
+    TableLamp = "table_lamp" '\u{e1f2}',
+    TableRestaurant = "table_restaurant" '\u{eac6}',
+    TableRows = "table_rows" '\u{f101}',
+    TableRowsNarrow = "table_rows_narrow" '\u{f73f}',
+    TableView = "table_view" '\u{f1be}',
+    Tablet = "tablet" '\u{e32f}',
+    TabletAndroid = "tablet_android" '\u{e330}',
+    TabletCamera = "tablet_camera" '\u{f44d}',
+    TabletMac = "tablet_mac" '\u{e331}',
+    Tabs = "tabs" '\u{e9ee}',
+    Tactic = "tactic" '\u{f564}',
+    Tag = "tag" '\u{e9ef}',
+    TagFaces = "tag_faces" '\u{ea22}',
+    TakeoutDining = "takeout_dining" '\u{ea74}',
+    TamperDetectionOff = "tamper_detection_off" '\u{e82e}',
+    TamperDetectionOn = "tamper_detection_on" '\u{f8c8}',
+    TapAndPlay = "tap_and_play" '\u{e62b}',
+    Tapas = "tapas" '\u{f1e9}',
+    Target = "target" '\u{e719}',
+    Task = "task" '\u{f075}',
+    TaskAlt = "task_alt" '\u{e2e6}',
+    Taunt = "taunt" '\u{f69f}',
+    TaxiAlert = "taxi_alert" '\u{ef74}',
+    TeamDashboard = "team_dashboard" '\u{e013}',
+    TempPreferencesCustom = "temp_preferences_custom" '\u{f8c9}',
+    TempPreferencesEco = "temp_preferences_eco" '\u{f8ca}',
+    TempleBuddhist = "temple_buddhist" '\u{eab3}',
+    TempleHindu = "temple_hindu" '\u{eaaf}',
+    Tenancy = "tenancy" '\u{f0e3}',
+    Terminal = "terminal" '\u{eb8e}',
+    Terrain = "terrain" '\u{e564}',
+    TextAd = "text_ad" '\u{e728}',
+    TextDecrease = "text_decrease" '\u{eadd}',
+    TextFields = "text_fields" '\u{e262}',
+    TextFieldsAlt = "text_fields_alt" '\u{e9f1}',
+    TextFormat = "text_format" '\u{e165}',
+    TextIncrease = "text_increase" '\u{eae2}',
+    TextRotateUp = "text_rotate_up" '\u{e93a}',
+    TextRotateVertical = "text_rotate_vertical" '\u{e93b}',
+    TextRotationAngledown = "text_rotation_angledown" '\u{e93c}',
+    TextRotationAngleup = "text_rotation_angleup" '\u{e93d}',
+    TextRotationDown = "text_rotation_down" '\u{e93e}',
+    TextRotationNone = "text_rotation_none" '\u{e93f}',
+    TextSelectEnd = "text_select_end" '\u{f73e}',
+    TextSelectJumpToBeginning = "text_select_jump_to_beginning" '\u{f73d}',
+    TextSelectJumpToEnd = "text_select_jump_to_end" '\u{f73c}',
+    TextSelectMoveBackCharacter = "text_select_move_back_character" '\u{f73b}',
+    TextSelectMoveBackWord = "text_select_move_back_word" '\u{f73a}',
+    TextSelectMoveDown = "text_select_move_down" '\u{f739}',
+    TextSelectMoveForwardCharacter = "text_select_move_forward_character" '\u{f738}',
+    TextSelectMoveForwardWord = "text_select_move_forward_word" '\u{f737}',
+    TextSelectMoveUp = "text_select_move_up" '\u{f736}',
+    TextSelectStart = "text_select_start" '\u{f735}',
+    TextSnippet = "text_snippet" '\u{f1c6}',
+    TextToSpeech = "text_to_speech" '\u{f1bc}',
+    TextUp = "text_up" '\u{f49e}',
+    Textsms = "textsms" '\u{e625}',
+    Texture = "texture" '\u{e421}',
+    TextureAdd = "texture_add" '\u{f57c}',
+    TextureMinus = "texture_minus" '\u{f57b}',
+    TheaterComedy = "theater_comedy" '\u{ea66}',
+    Theaters = "theaters" '\u{e8da}',
+    Thermometer = "thermometer" '\u{e846}',
+    ThermometerAdd = "thermometer_add" '\u{f582}',
+    ThermometerGain = "thermometer_gain" '\u{f6d8}',
+    ThermometerLoss = "thermometer_loss" '\u{f6d7}',
+    ThermometerMinus = "thermometer_minus" '\u{f581}',
+    Thermostat = "thermostat" '\u{f076}',
+    ThermostatAuto = "thermostat_auto" '\u{f077}',
+    ThermostatCarbon = "thermostat_carbon" '\u{f178}',
+    ThingsToDo = "things_to_do" '\u{eb2a}',
+    ThreadUnread = "thread_unread" '\u{f4f9}',
+    ThumbDown = "thumb_down" '\u{f578}',
+    ThumbDownAlt = "thumb_down_alt" '\u{f578}',
+    ThumbDownFilled = "thumb_down_filled" '\u{f578}',
+    ThumbDownOff = "thumb_down_off" '\u{f578}',
+    ThumbDownOffAlt = "thumb_down_off_alt" '\u{f578}',
+    ThumbUp = "thumb_up" '\u{f577}',
+    ThumbUpAlt = "thumb_up_alt" '\u{f577}',
+    ThumbUpFilled = "thumb_up_filled" '\u{f577}',
+    ThumbUpOff = "thumb_up_off" '\u{f577}',
+    ThumbUpOffAlt = "thumb_up_off_alt" '\u{f577}',
+    ThumbnailBar = "thumbnail_bar" '\u{f734}',
+    ThumbsUpDown = "thumbs_up_down" '\u{e8dd}',
+    Thunderstorm = "thunderstorm" '\u{ebdb}',
+    Tibia = "tibia" '\u{f89b}',
+    TibiaAlt = "tibia_alt" '\u{f89c}',
+    TimeAuto = "time_auto" '\u{f0e4}',
+    TimeToLeave = "time_to_leave" '\u{eff7}',
+    Timelapse = "timelapse" '\u{e422}',
+    Timeline = "timeline" '\u{e922}',
+    Timer = "timer" '\u{e425}',
+    Timer10 = "timer_10" '\u{e423}',
+    Timer10Alt1 = "timer_10_alt_1" '\u{efbf}',
+    Timer10Select = "timer_10_select" '\u{f07a}',
+    Timer3 = "timer_3" '\u{e424}',
+    Timer3Alt1 = "timer_3_alt_1" '\u{efc0}',
+    Timer3Select = "timer_3_select" '\u{f07b}',
+    Timer5 = "timer_5" '\u{f4b1}',
+    Timer5Shutter = "timer_5_shutter" '\u{f4b2}',
+    TimerOff = "timer_off" '\u{e426}',
+    TimerPause = "timer_pause" '\u{f4bb}',
+    TimerPlay = "timer_play" '\u{f4ba}',
+    TipsAndUpdates = "tips_and_updates" '\u{e79a}',
+    TireRepair = "tire_repair" '\u{ebc8}',
+    Title = "title" '\u{e264}',
+    Titlecase = "titlecase" '\u{f489}',
+    Toast = "toast" '\u{efc1}',
+    Toc = "toc" '\u{e8de}',
+    Today = "today" '\u{e8df}',
+    ToggleOff = "toggle_off" '\u{e9f5}',
+    ToggleOn = "toggle_on" '\u{e9f6}',
+    Token = "token" '\u{ea25}',
+    Toll = "toll" '\u{e8e0}',
+    Tonality = "tonality" '\u{e427}',
+    Toolbar = "toolbar" '\u{e9f7}',
+    ToolsFlatHead = "tools_flat_head" '\u{f8cb}',
+    ToolsInstallationKit = "tools_installation_kit" '\u{e2ab}',
+    ToolsLadder = "tools_ladder" '\u{e2cb}',
+    ToolsLevel = "tools_level" '\u{e77b}',
+    ToolsPhillips = "tools_phillips" '\u{f8cc}',
+    ToolsPliersWireStripper = "tools_pliers_wire_stripper" '\u{e2aa}',
+    ToolsPowerDrill = "tools_power_drill" '\u{e1e9}',
+    ToolsWrench = "tools_wrench" '\u{f8cd}',
+    Tooltip = "tooltip" '\u{e9f8}',
+    Tooltip2 = "tooltip_2" '\u{f3ed}',
+    TopPanelClose = "top_panel_close" '\u{f733}',
+    TopPanelOpen = "top_panel_open" '\u{f732}',
+    Topic = "topic" '\u{f1c8}',
+    Tornado = "tornado" '\u{e199}',
+    TotalDissolvedSolids = "total_dissolved_solids" '\u{f877}',
+    TouchApp = "touch_app" '\u{e913}',
+    TouchpadMouse = "touchpad_mouse" '\u{f687}',
+    TouchpadMouseOff = "touchpad_mouse_off" '\u{f4e6}',
+    Tour = "tour" '\u{ef75}',
+    Toys = "toys" '\u{e332}',
+    ToysAndGames = "toys_and_games" '\u{efc2}',
+    ToysFan = "toys_fan" '\u{f887}',
+    TrackChanges = "track_changes" '\u{e8e1}',
+    TrackpadInput = "trackpad_input" '\u{f4c7}',
+    TrackpadInput2 = "trackpad_input_2" '\u{f409}',
+    TrackpadInput3 = "trackpad_input_3" '\u{f408}',
+    Traffic = "traffic" '\u{e565}',
+    TrafficJam = "traffic_jam" '\u{f46f}',
+    TrailLength = "trail_length" '\u{eb5e}',
+    TrailLengthMedium = "trail_length_medium" '\u{eb63}',
+    TrailLengthShort = "trail_length_short" '\u{eb6d}',
+    Train = "train" '\u{e570}',
+    Tram = "tram" '\u{e571}',
+    Transcribe = "transcribe" '\u{f8ec}',
+    TransferWithinAStation = "transfer_within_a_station" '\u{e572}',
+    Transform = "transform" '\u{e428}',
+    Transgender = "transgender" '\u{e58d}',
+    TransitEnterexit = "transit_enterexit" '\u{e579}',
+    TransitTicket = "transit_ticket" '\u{f3f1}',
+    TransitionChop = "transition_chop" '\u{f50e}',
+    TransitionDissolve = "transition_dissolve" '\u{f50d}',
+    TransitionFade = "transition_fade" '\u{f50c}',
+    TransitionPush = "transition_push" '\u{f50b}',
+    TransitionSlide = "transition_slide" '\u{f50a}',
+    Translate = "translate" '\u{e8e2}',
+    Transportation = "transportation" '\u{e21d}',
+    Travel = "travel" '\u{ef93}',
+    TravelExplore = "travel_explore" '\u{e2db}',
+    TravelLuggageAndBags = "travel_luggage_and_bags" '\u{efc3}',
+    TrendingDown = "trending_down" '\u{e8e3}',
+    TrendingFlat = "trending_flat" '\u{e8e4}',
+    TrendingUp = "trending_up" '\u{e8e5}',
+    Trip = "trip" '\u{e6fb}',
+    TripOrigin = "trip_origin" '\u{e57b}',
+    Trolley = "trolley" '\u{f86b}',
+    TrolleyCableCar = "trolley_cable_car" '\u{f46e}',
+    Trophy = "trophy" '\u{ea23}',
+    Troubleshoot = "troubleshoot" '\u{e1d2}',
+    Try = "try" '\u{f07c}',
+    Tsunami = "tsunami" '\u{ebd8}',
+    Tsv = "tsv" '\u{e6d6}',
+    Tty = "tty" '\u{f1aa}',
+    Tune = "tune" '\u{e429}',
+    Tungsten = "tungsten" '\u{f07d}',
+    TurnLeft = "turn_left" '\u{eba6}',
+    TurnRight = "turn_right" '\u{ebab}',
+    TurnSharpLeft = "turn_sharp_left" '\u{eba7}',
+    TurnSharpRight = "turn_sharp_right" '\u{ebaa}',
+    TurnSlightLeft = "turn_slight_left" '\u{eba4}',
+    TurnSlightRight = "turn_slight_right" '\u{eb9a}',
+    TurnedIn = "turned_in" '\u{e8e7}',
+    TurnedInNot = "turned_in_not" '\u{e8e7}',
+    Tv = "tv" '\u{e63b}',
+    TvDisplays = "tv_displays" '\u{f3ec}',
+    TvGen = "tv_gen" '\u{e830}',
+    TvGuide = "tv_guide" '\u{e1dc}',
+    TvNext = "tv_next" '\u{f3eb}',
+    TvOff = "tv_off" '\u{e647}',
+    TvOptionsEditChannels = "tv_options_edit_channels" '\u{e1dd}',
+    TvOptionsInputSettings = "tv_options_input_settings" '\u{e1de}',
+    TvRemote = "tv_remote" '\u{f5d9}',
+    TvSignin = "tv_signin" '\u{e71b}',
+    TvWithAssistant = "tv_with_assistant" '\u{e785}',
+    TwoPager = "two_pager" '\u{f51f}',
+    TwoWheeler = "two_wheeler" '\u{e9f9}',
+    TypeSpecimen = "type_specimen" '\u{f8f0}',
+    UTurnLeft = "u_turn_left" '\u{eba1}',
+    UTurnRight = "u_turn_right" '\u{eba2}',
+    UlnaRadius = "ulna_radius" '\u{f89d}',
+    UlnaRadiusAlt = "ulna_radius_alt" '\u{f89e}',
+    Umbrella = "umbrella" '\u{f1ad}',
+    Unarchive = "unarchive" '\u{e169}',
+    Undo = "undo" '\u{e166}',
+    UnfoldLess = "unfold_less" '\u{e5d6}',
+    UnfoldLessDouble = "unfold_less_double" '\u{f8cf}',
+    UnfoldMore = "unfold_more" '\u{e5d7}',
+    UnfoldMoreDouble = "unfold_more_double" '\u{f8d0}',
+    Ungroup = "ungroup" '\u{f731}',
+    UniversalCurrency = "universal_currency" '\u{e9fa}',
+    UniversalCurrencyAlt = "universal_currency_alt" '\u{e734}',
+    UniversalLocal = "universal_local" '\u{e9fb}',
+    Unknown2 = "unknown_2" '\u{f49f}',
+    Unknown5 = "unknown_5" '\u{e6a5}',
+    Unknown7 = "unknown_7" '\u{f49e}',
+    UnknownDocument = "unknown_document" '\u{f804}',
+    UnknownMed = "unknown_med" '\u{eabd}',
+    Unlicense = "unlicense" '\u{eb05}',
+    UnpavedRoad = "unpaved_road" '\u{f46d}',
+    Unpin = "unpin" '\u{e6f9}',
+    Unpublished = "unpublished" '\u{f236}',
+    Unsubscribe = "unsubscribe" '\u{e0eb}',
+    Upcoming = "upcoming" '\u{f07e}',
+    Update = "update" '\u{e923}',
+    UpdateDisabled = "update_disabled" '\u{e075}',
+    Upgrade = "upgrade" '\u{f0fb}',
+    Upload = "upload" '\u{f09b}',
+    Upload2 = "upload_2" '\u{f521}',
+    UploadFile = "upload_file" '\u{e9fc}',
+    Uppercase = "uppercase" '\u{f488}',
+    Urology = "urology" '\u{e137}',
+    Usb = "usb" '\u{e1e0}',
+    UsbOff = "usb_off" '\u{e4fa}',
+    UserAttributes = "user_attributes" '\u{e708}',
+    Vaccines = "vaccines" '\u{e138}',
+    Vacuum = "vacuum" '\u{efc5}',
+    Valve = "valve" '\u{e224}',
+    VapeFree = "vape_free" '\u{ebc6}',
+    VapingRooms = "vaping_rooms" '\u{ebcf}',
+    VariableAdd = "variable_add" '\u{f51e}',
+    VariableInsert = "variable_insert" '\u{f51d}',
+    VariableRemove = "variable_remove" '\u{f51c}',
+    Variables = "variables" '\u{f851}',
+    Ventilator = "ventilator" '\u{e139}',
+    Verified = "verified" '\u{ef76}',
+    VerifiedUser = "verified_user" '\u{f013}',
+    VerticalAlignBottom = "vertical_align_bottom" '\u{e258}',
+    VerticalAlignCenter = "vertical_align_center" '\u{e259}',
+    VerticalAlignTop = "vertical_align_top" '\u{e25a}',
+    VerticalDistribute = "vertical_distribute" '\u{e076}',
+    VerticalShades = "vertical_shades" '\u{ec0e}',
+    VerticalShadesClosed = "vertical_shades_closed" '\u{ec0d}',
+    VerticalSplit = "vertical_split" '\u{e949}',
+    Vibration = "vibration" '\u{e62d}',
+    VideoCall = "video_call" '\u{e070}',
+    VideoCameraBack = "video_camera_back" '\u{f07f}',
+    VideoCameraBackAdd = "video_camera_back_add" '\u{f40c}',
+    VideoCameraFront = "video_camera_front" '\u{f080}',
+    VideoCameraFrontOff = "video_camera_front_off" '\u{f83b}',
+    VideoChat = "video_chat" '\u{f8a0}',
+    VideoFile = "video_file" '\u{eb87}',
+    VideoLabel = "video_label" '\u{e071}',
+    VideoLibrary = "video_library" '\u{e04a}',
+    VideoSearch = "video_search" '\u{efc6}',
+    VideoSettings = "video_settings" '\u{ea75}',
+    VideoStable = "video_stable" '\u{f081}',
+    Videocam = "videocam" '\u{e04b}',
+    VideocamOff = "videocam_off" '\u{e04c}',
+    VideogameAsset = "videogame_asset" '\u{e338}',
+    VideogameAssetOff = "videogame_asset_off" '\u{e500}',
+    ViewAgenda = "view_agenda" '\u{e8e9}',
+    ViewArray = "view_array" '\u{e8ea}',
+    ViewCarousel = "view_carousel" '\u{e8eb}',
+    ViewColumn = "view_column" '\u{e8ec}',
+    ViewColumn2 = "view_column_2" '\u{f847}',
+    ViewComfy = "view_comfy" '\u{e42a}',
+    ViewComfyAlt = "view_comfy_alt" '\u{eb73}',
+    ViewCompact = "view_compact" '\u{e42b}',
+    ViewCompactAlt = "view_compact_alt" '\u{eb74}',
+    ViewCozy = "view_cozy" '\u{eb75}',
+    ViewDay = "view_day" '\u{e8ed}',
+    ViewHeadline = "view_headline" '\u{e8ee}',
+    ViewInAr = "view_in_ar" '\u{efc9}',
+    ViewInArNew = "view_in_ar_new" '\u{efc9}',
+    ViewInArOff = "view_in_ar_off" '\u{f61b}',
+    ViewKanban = "view_kanban" '\u{eb7f}',
+    ViewList = "view_list" '\u{e8ef}',
+    ViewModule = "view_module" '\u{e8f0}',
+    ViewObjectTrack = "view_object_track" '\u{f432}',
+    ViewQuilt = "view_quilt" '\u{e8f1}',
+    ViewRealSize = "view_real_size" '\u{f4c2}',
+    ViewSidebar = "view_sidebar" '\u{f114}',
+    ViewStream = "view_stream" '\u{e8f2}',
+    ViewTimeline = "view_timeline" '\u{eb85}',
+    ViewWeek = "view_week" '\u{e8f3}',
+    Vignette = "vignette" '\u{e435}',
+    Villa = "villa" '\u{e586}',
+    Visibility = "visibility" '\u{e8f4}',
+    VisibilityLock = "visibility_lock" '\u{f653}',
+    VisibilityOff = "visibility_off" '\u{e8f5}',
+    VitalSigns = "vital_signs" '\u{e650}',
+    Vitals = "vitals" '\u{e13b}',
+    Vo2Max = "vo2_max" '\u{f4aa}',
+    VoiceChat = "voice_chat" '\u{e62e}',
+    VoiceOverOff = "voice_over_off" '\u{e94a}',
+    VoiceSelection = "voice_selection" '\u{f58a}',
+    VoiceSelectionOff = "voice_selection_off" '\u{f42c}',
+    Voicemail = "voicemail" '\u{e0d9}',
+    Volcano = "volcano" '\u{ebda}',
+    VolumeDown = "volume_down" '\u{e04d}',
+    VolumeDownAlt = "volume_down_alt" '\u{e79c}',
+    VolumeMute = "volume_mute" '\u{e04e}',
+    VolumeOff = "volume_off" '\u{e04f}',
+    VolumeUp = "volume_up" '\u{e050}',
+    VolunteerActivism = "volunteer_activism" '\u{ea70}',
+    VotingChip = "voting_chip" '\u{f852}',
+    VpnKey = "vpn_key" '\u{e0da}',
+    VpnKeyAlert = "vpn_key_alert" '\u{f6cc}',
+    VpnKeyOff = "vpn_key_off" '\u{eb7a}',
+    VpnLock = "vpn_lock" '\u{e62f}',
+    Vr180Create2d = "vr180_create2d" '\u{efca}',
+    Vr180Create2dOff = "vr180_create2d_off" '\u{f571}',
+    Vrpano = "vrpano" '\u{f082}',
+    WallArt = "wall_art" '\u{efcb}',
+    WallLamp = "wall_lamp" '\u{e2b4}',
+    Wallet = "wallet" '\u{f8ff}',
+    Wallpaper = "wallpaper" '\u{e1bc}',
+    WallpaperSlideshow = "wallpaper_slideshow" '\u{f672}',
+    Ward = "ward" '\u{e13c}',
+    Warehouse = "warehouse" '\u{ebb8}',
+    Warning = "warning" '\u{f083}',
+    WarningAmber = "warning_amber" '\u{f083}',
+    WarningOff = "warning_off" '\u{f7ad}',
+    Wash = "wash" '\u{f1b1}',
+    Watch = "watch" '\u{e334}',
+    WatchButtonPress = "watch_button_press" '\u{f6aa}',
+    WatchCheck = "watch_check" '\u{f468}',
+    WatchLater = "watch_later" '\u{efd6}',
+    WatchOff = "watch_off" '\u{eae3}',
+    WatchScreentime = "watch_screentime" '\u{f6ae}',
+    WatchVibration = "watch_vibration" '\u{f467}',
+    WatchWake = "watch_wake" '\u{f6a9}',
+    Water = "water" '\u{f084}',
+    WaterBottle = "water_bottle" '\u{f69d}',
+    WaterBottleLarge = "water_bottle_large" '\u{f69e}',
+    WaterDamage = "water_damage" '\u{f203}',
+    WaterDo = "water_do" '\u{f870}',
+    WaterDrop = "water_drop" '\u{e798}',
+    WaterEc = "water_ec" '\u{f875}',
+    WaterFull = "water_full" '\u{f6d6}',
+    WaterHeater = "water_heater" '\u{e284}',
+    WaterLock = "water_lock" '\u{f6ad}',
+    WaterLoss = "water_loss" '\u{f6d5}',
+    WaterLux = "water_lux" '\u{f874}',
+    WaterMedium = "water_medium" '\u{f6d4}',
+    WaterOrp = "water_orp" '\u{f878}',
+    WaterPh = "water_ph" '\u{f87a}',
+    WaterPump = "water_pump" '\u{f5d8}',
+    WaterVoc = "water_voc" '\u{f87b}',
+    WaterfallChart = "waterfall_chart" '\u{ea00}',
+    Waves = "waves" '\u{e176}',
+    WavingHand = "waving_hand" '\u{e766}',
+    WbAuto = "wb_auto" '\u{e42c}',
+    WbCloudy = "wb_cloudy" '\u{f15c}',
+    WbIncandescent = "wb_incandescent" '\u{e42e}',
+    WbIridescent = "wb_iridescent" '\u{f07d}',
+    WbShade = "wb_shade" '\u{ea01}',
+    WbSunny = "wb_sunny" '\u{e430}',
+    WbTwilight = "wb_twilight" '\u{e1c6}',
+    Wc = "wc" '\u{e63d}',
+    WeatherHail = "weather_hail" '\u{f67f}',
+    WeatherMix = "weather_mix" '\u{f60b}',
+    WeatherSnowy = "weather_snowy" '\u{e2cd}',
+    Web = "web" '\u{e051}',
+    WebAsset = "web_asset" '\u{e069}',
+    WebAssetOff = "web_asset_off" '\u{ef47}',
+    WebStories = "web_stories" '\u{e595}',
+    WebTraffic = "web_traffic" '\u{ea03}',
+    Webhook = "webhook" '\u{eb92}',
+    Weekend = "weekend" '\u{e16b}',
+    Weight = "weight" '\u{e13d}',
+    West = "west" '\u{f1e6}',
+    Whatshot = "whatshot" '\u{e80e}',
+    WheelchairPickup = "wheelchair_pickup" '\u{f1ab}',
+    WhereToVote = "where_to_vote" '\u{e177}',
+    Widgets = "widgets" '\u{e1bd}',
+    Width = "width" '\u{f730}',
+    WidthFull = "width_full" '\u{f8f5}',
+    WidthNormal = "width_normal" '\u{f8f6}',
+    WidthWide = "width_wide" '\u{f8f7}',
+    Wifi = "wifi" '\u{e63e}',
+    Wifi1Bar = "wifi_1_bar" '\u{e4ca}',
+    Wifi2Bar = "wifi_2_bar" '\u{e4d9}',
+    WifiAdd = "wifi_add" '\u{f7a8}',
+    WifiCalling = "wifi_calling" '\u{ef77}',
+    WifiCalling1 = "wifi_calling_1" '\u{f0e7}',
+    WifiCalling2 = "wifi_calling_2" '\u{f0f6}',
+    WifiCalling3 = "wifi_calling_3" '\u{f0e7}',
+    WifiCallingBar1 = "wifi_calling_bar_1" '\u{f44c}',
+    WifiCallingBar2 = "wifi_calling_bar_2" '\u{f44b}',
+    WifiCallingBar3 = "wifi_calling_bar_3" '\u{f44a}',
+    WifiChannel = "wifi_channel" '\u{eb6a}',
+    WifiFind = "wifi_find" '\u{eb31}',
+    WifiHome = "wifi_home" '\u{f671}',
+    WifiLock = "wifi_lock" '\u{e1e1}',
+    WifiNotification = "wifi_notification" '\u{f670}',
+    WifiOff = "wifi_off" '\u{e648}',
+    WifiPassword = "wifi_password" '\u{eb6b}',
+    WifiProtectedSetup = "wifi_protected_setup" '\u{f0fc}',
+    WifiProxy = "wifi_proxy" '\u{f7a7}',
+    WifiTethering = "wifi_tethering" '\u{e1e2}',
+    WifiTetheringError = "wifi_tethering_error" '\u{ead9}',
+    WifiTetheringOff = "wifi_tethering_off" '\u{f087}',
+    WindPower = "wind_power" '\u{ec0c}',
+    Window = "window" '\u{f088}',
+    WindowClosed = "window_closed" '\u{e77e}',
+    WindowOpen = "window_open" '\u{e78c}',
+    WindowSensor = "window_sensor" '\u{e2bb}',
+    WineBar = "wine_bar" '\u{f1e8}',
+    Woman = "woman" '\u{e13e}',
+    Woman2 = "woman_2" '\u{f8e7}',
+    Work = "work" '\u{e943}',
+    WorkAlert = "work_alert" '\u{f5f7}',
+    WorkHistory = "work_history" '\u{ec09}',
+    WorkOff = "work_off" '\u{e942}',
+    WorkOutline = "work_outline" '\u{e943}',
+    WorkUpdate = "work_update" '\u{f5f8}',
+    Workflow = "workflow" '\u{ea04}',
+    WorkspacePremium = "workspace_premium" '\u{e7af}',
+    Workspaces = "workspaces" '\u{ea0f}',
+    WorkspacesOutline = "workspaces_outline" '\u{ea0f}',
+    WoundsInjuries = "wounds_injuries" '\u{e13f}',
+    WrapText = "wrap_text" '\u{e25b}',
+    Wrist = "wrist" '\u{f69c}',
+    WrongLocation = "wrong_location" '\u{ef78}',
+    Wysiwyg = "wysiwyg" '\u{f1c3}',
+    Yard = "yard" '\u{f089}',
+    YourTrips = "your_trips" '\u{eb2b}',
+    YoutubeActivity = "youtube_activity" '\u{f85a}',
+    YoutubeSearchedFor = "youtube_searched_for" '\u{e8fa}',
+    ZonePersonAlert = "zone_person_alert" '\u{e781}',
+    ZonePersonIdle = "zone_person_idle" '\u{e77a}',
+    ZonePersonUrgent = "zone_person_urgent" '\u{e788}',
+    ZoomIn = "zoom_in" '\u{e8ff}',
+    ZoomInMap = "zoom_in_map" '\u{eb2d}',
+    ZoomOut = "zoom_out" '\u{e900}',
+    ZoomOutMap = "zoom_out_map" '\u{e56b}',
+}
@@ -0,0 +1,140 @@
+//! Icons painted from paths
+
+use egui::{Color32, Painter, Pos2, Rect, Stroke, pos2};
+
+/// Width of the strokes, in the 24 unit icon grid
+const STROKE_WIDTH: f32 = 2.0;
+
+/// A simple icon painted as strokes, crisp at any size
+///
+/// Unlike font glyphs they can be drawn in part, e.g. a checkmark drawing itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VectorIcon {
+    Check,
+    /// A horizontal dash, e.g. the mark of an indeterminate checkbox
+    Remove,
+    Add,
+    Close,
+    ChevronLeft,
+    ChevronRight,
+    ExpandMore,
+    ExpandLess,
+    ArrowBack,
+    ArrowForward,
+}
+
+impl VectorIcon {
+    /// Name of the Material Symbols icon this one draws, e.g. `chevron_left`
+    pub fn name(&self) -> &'static str {
+        match self {
+            VectorIcon::Check => "check",
+            VectorIcon::Remove => "remove",
+            VectorIcon::Add => "add",
+            VectorIcon::Close => "close",
+            VectorIcon::ChevronLeft => "chevron_left",
+            VectorIcon::ChevronRight => "chevron_right",
+            VectorIcon::ExpandMore => "expand_more",
+            VectorIcon::ExpandLess => "expand_less",
+            VectorIcon::ArrowBack => "arrow_back",
+            VectorIcon::ArrowForward => "arrow_forward",
+        }
+    }
+
+    /// Polylines of the icon in a 24 by 24 grid, in drawing order
+    fn paths(&self) -> &'static [&'static [(f32, f32)]] {
+        match self {
+            VectorIcon::Check => &[&[(5.0, 12.5), (9.5, 17.0), (19.0, 7.5)]],
+            // 与对勾点数相同，便于两者之间变形
+            VectorIcon::Remove => &[&[(5.0, 12.0), (12.0, 12.0), (19.0, 12.0)]],
+            VectorIcon::Add => &[&[(12.0, 5.0), (12.0, 19.0)], &[(5.0, 12.0), (19.0, 12.0)]],
+            VectorIcon::Close => &[&[(6.0, 6.0), (18.0, 18.0)], &[(18.0, 6.0), (6.0, 18.0)]],
+            VectorIcon::ChevronLeft => &[&[(14.5, 6.0), (8.5, 12.0), (14.5, 18.0)]],
+            VectorIcon::ChevronRight => &[&[(9.5, 6.0), (15.5, 12.0), (9.5, 18.0)]],
+            VectorIcon::ExpandMore => &[&[(6.0, 9.5), (12.0, 15.5), (18.0, 9.5)]],
+            VectorIcon::ExpandLess => &[&[(6.0, 14.5), (12.0, 8.5), (18.0, 14.5)]],
+            VectorIcon::ArrowBack => &[
+                &[(19.0, 12.0), (5.0, 12.0)],
+                &[(11.0, 6.0), (5.0, 12.0), (11.0, 18.0)],
+            ],
+            VectorIcon::ArrowForward => &[
+                &[(5.0, 12.0), (19.0, 12.0)],
+                &[(13.0, 6.0), (19.0, 12.0), (13.0, 18.0)],
+            ],
+        }
+    }
+
+    /// Paint the icon scaled into a square rect
+    pub fn paint(&self, painter: &Painter, rect: Rect, color: Color32) {
+        self.paint_progress(painter, rect, color, 1.0);
+    }
+
+    /// Paint the first `progress` (0 to 1) of the icon's strokes
+    pub fn paint_progress(&self, painter: &Painter, rect: Rect, color: Color32, progress: f32) {
+        self.paint_morph(*self, painter, rect, color, 0.0, progress);
+    }
+
+    /// Paint the icon morphing into `other` by `t` (0 to 1), drawn up to `progress`
+    ///
+    /// Icons with different path layouts switch halfway instead of morphing.
+    pub fn paint_morph(
+        &self,
+        other: VectorIcon,
+        painter: &Painter,
+        rect: Rect,
+        color: Color32,
+        t: f32,
+        progress: f32,
+    ) {
+        if progress <= 0.0 {
+            return;
+        }
+        let t = t.clamp(0.0, 1.0);
+        let scale = rect.size().min_elem() / 24.0;
+        let origin = rect.center() - egui::vec2(12.0, 12.0) * scale;
+        let stroke = Stroke::new(STROKE_WIDTH * scale, color);
+        let to_screen = |(x, y): (f32, f32)| origin + pos2(x, y).to_vec2() * scale;
+
+        let (from, to) = (self.paths(), other.paths());
+        let same_layout =
+            from.len() == to.len() && from.iter().zip(to).all(|(a, b)| a.len() == b.len());
+        let paths: Vec<Vec<Pos2>> = if same_layout {
+            from.iter()
+                .zip(to)
+                .map(|(a, b)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .map(|(&a, &b)| to_screen(a).lerp(to_screen(b), t))
+                        .collect()
+                })
+                .collect()
+        } else {
+            let paths = if t < 0.5 { from } else { to };
+            paths
+                .iter()
+                .map(|path| path.iter().copied().map(to_screen).collect())
+                .collect()
+        };
+
+        // 按总长度截取已绘制的部分
+        let length = |path: &[Pos2]| path.windows(2).map(|w| w[0].distance(w[1])).sum::<f32>();
+        let mut remaining = paths.iter().map(|path| length(path)).sum::<f32>() * progress.min(1.0);
+        for path in paths {
+            if remaining <= 0.0 {
+                break;
+            }
+            let mut points = vec![path[0]];
+            for segment in path.windows(2) {
+                let distance = segment[0].distance(segment[1]);
+                if distance >= remaining {
+                    points.push(segment[0] + (segment[1] - segment[0]) * (remaining / distance));
+                    remaining = 0.0;
+                    break;
+                }
+                points.push(segment[1]);
+                remaining -= distance;
+            }
+            painter.line(points, stroke);
+        }
+    }
+}
@@ -5,5 +5,11 @@
 
 pub mod color;
 pub mod components;
+pub mod elevation;
+pub mod icon;
+pub mod motion;
 pub mod prelude;
+pub mod shape;
+pub mod tokens;
+pub mod typography;
 pub mod utils;
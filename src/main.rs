@@ -1,6 +1,228 @@
+//! Generate Material design tokens from a seed color or an image
+//!
+//! ```text
+//! egui_material "#6750a4" --variant vibrant --mode system --format css
+//! egui_material wallpaper.png --contrast high --format rust -o tokens.rs
+//! ```
+
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+
+use egui::Color32;
 use egui_material::color::prelude::*;
 
-fn main() {
-    let theme = MaterialTheme::from_argb(0xffaae5a4, ColorMode::Light);
-    println!("{:#?}", theme.get());
+const USAGE: &str = "\
+Usage: egui_material <SEED | IMAGE> [OPTIONS]
+
+Arguments:
+  <SEED | IMAGE>          seed color as hex (#6750a4) or path to an image
+
+Options:
+  -v, --variant <NAME>    tonal_spot (default), monochrome, neutral, vibrant,
+                          expressive, fidelity, content, rainbow, fruit_salad
+  -c, --contrast <LEVEL>  reduced, standard (default), medium, high or -1.0..=1.0
+  -m, --mode <MODE>       light (default), dark or system (both schemes)
+  -f, --format <FORMAT>   json (default), css, rust or ron (egui visuals)
+  -o, --output <FILE>     write to a file instead of stdout
+  -h, --help              print this help";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Json,
+    Css,
+    Rust,
+    Ron,
+}
+
+struct Args {
+    input: String,
+    variant: Variant,
+    contrast: ContrastLevel,
+    mode: ColorMode,
+    format: Format,
+    output: Option<String>,
+}
+
+fn parse_contrast(value: &str) -> Result<ContrastLevel, String> {
+    let level = match value.to_ascii_lowercase().as_str() {
+        "reduced" => ContrastLevel::Reduced,
+        "standard" => ContrastLevel::Standard,
+        "medium" => ContrastLevel::Medium,
+        "high" => ContrastLevel::High,
+        level => {
+            let level: f64 = level
+                .parse()
+                .map_err(|_| format!("invalid contrast level `{value}`"))?;
+            // 拒绝 nan、无穷大与 -1 到 1 之外的值
+            if !(-1.0..=1.0).contains(&level) {
+                return Err(format!(
+                    "contrast level `{value}` is out of range, expected -1.0 to 1.0"
+                ));
+            }
+            ContrastLevel::Custom(level)
+        }
+    };
+    Ok(level)
+}
+
+fn parse_mode(value: &str) -> Option<ColorMode> {
+    let mode = match value.to_ascii_lowercase().as_str() {
+        "light" => ColorMode::Light,
+        "dark" => ColorMode::Dark,
+        "system" => ColorMode::System,
+        _ => return None,
+    };
+    Some(mode)
+}
+
+fn parse_format(value: &str) -> Option<Format> {
+    let format = match value.to_ascii_lowercase().as_str() {
+        "json" => Format::Json,
+        "css" => Format::Css,
+        "rust" | "rs" => Format::Rust,
+        "ron" => Format::Ron,
+        _ => return None,
+    };
+    Some(format)
+}
+
+/// `Ok(None)` when help was requested
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut input = None;
+    let mut variant = Variant::TonalSpot;
+    let mut contrast = ContrastLevel::Standard;
+    let mut mode = ColorMode::Light;
+    let mut format = Format::Json;
+    let mut output = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-v" | "--variant" => {
+                let value = value()?;
+                variant = parse_variant(&value).ok_or(format!("unknown variant `{value}`"))?;
+            }
+            "-c" | "--contrast" => {
+                let value = value()?;
+                contrast = parse_contrast(&value)?;
+            }
+            "-m" | "--mode" => {
+                let value = value()?;
+                mode = parse_mode(&value).ok_or(format!("unknown color mode `{value}`"))?;
+            }
+            "-f" | "--format" => {
+                let value = value()?;
+                format = parse_format(&value).ok_or(format!("unknown format `{value}`"))?;
+            }
+            "-o" | "--output" => output = Some(value()?),
+            flag if flag.starts_with('-') => {
+                return Err(format!("unknown option `{flag}`"));
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    let input = input.ok_or("missing seed color or image")?;
+    Ok(Some(Args {
+        input,
+        variant,
+        contrast,
+        mode,
+        format,
+        output,
+    }))
+}
+
+/// Seed color from a hex code, or the best seed of an image
+fn load_seed(input: &str) -> Result<Argb, String> {
+    if let Ok(seed) = input.parse::<Argb>() {
+        return Ok(seed);
+    }
+
+    let image = image::open(input)
+        .map_err(|err| format!("`{input}` is neither a hex color nor a readable image: {err}"))?
+        .into_rgba8();
+    let pixels: Vec<Color32> = image
+        .pixels()
+        .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+        .collect();
+    MaterialTheme::seeds_from_image(&pixels, 1)
+        .first()
+        .copied()
+        .ok_or_else(|| format!("no seed color found in `{input}`"))
+}
+
+fn visuals_ron(theme: &MaterialTheme) -> Result<String, String> {
+    let config = ron::ser::PrettyConfig::default();
+    let result = match theme.get_color_mode() {
+        ColorMode::Light | ColorMode::Dark => ron::ser::to_string_pretty(&theme.visuals(), config),
+        ColorMode::System => {
+            let visuals = BTreeMap::from([
+                (
+                    "light",
+                    MaterialTheme::scheme_visuals(theme.get_light_scheme(), false),
+                ),
+                (
+                    "dark",
+                    MaterialTheme::scheme_visuals(theme.get_dark_scheme(), true),
+                ),
+            ]);
+            ron::ser::to_string_pretty(&visuals, config)
+        }
+    };
+    result.map_err(|err| err.to_string())
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let seed = load_seed(&args.input)?;
+    let theme = MaterialThemeBuilder::from_source(seed)
+        .with_variant(args.variant)
+        .with_contrast(args.contrast)
+        .with_color_mode(args.mode)
+        .build();
+
+    let output = match args.format {
+        Format::Json => theme.to_tokens_json(),
+        Format::Css => theme.to_css(),
+        Format::Rust => theme.to_rust_module(),
+        Format::Ron => visuals_ron(&theme)?,
+    };
+
+    match args.output {
+        Some(path) => {
+            std::fs::write(&path, output).map_err(|err| format!("failed to write `{path}`: {err}"))
+        }
+        None => {
+            print!("{output}");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
 }
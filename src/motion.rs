@@ -0,0 +1,267 @@
+//! Material Design motion
+//!
+//! See: [material design document](https://m3.material.io/styles/motion/easing-and-duration/tokens-specs)
+
+use egui::{Context, Id, Vec2, vec2};
+
+use crate::tokens::MaterialTokens;
+
+/// Duration tokens of the motion spec
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MotionDuration {
+    /// 50ms
+    Short1,
+    /// 100ms
+    Short2,
+    /// 150ms, state layers and small state changes
+    #[default]
+    Short3,
+    /// 200ms
+    Short4,
+    /// 250ms
+    Medium1,
+    /// 300ms, selection controls and ripples
+    Medium2,
+    /// 350ms
+    Medium3,
+    /// 400ms
+    Medium4,
+    /// 450ms
+    Long1,
+    /// 500ms
+    Long2,
+    /// 550ms
+    Long3,
+    /// 600ms
+    Long4,
+    /// 700ms
+    ExtraLong1,
+    /// 800ms
+    ExtraLong2,
+    /// 900ms
+    ExtraLong3,
+    /// 1000ms
+    ExtraLong4,
+}
+
+impl MotionDuration {
+    /// Duration in seconds
+    pub fn seconds(&self) -> f32 {
+        let millis = match self {
+            MotionDuration::Short1 => 50,
+            MotionDuration::Short2 => 100,
+            MotionDuration::Short3 => 150,
+            MotionDuration::Short4 => 200,
+            MotionDuration::Medium1 => 250,
+            MotionDuration::Medium2 => 300,
+            MotionDuration::Medium3 => 350,
+            MotionDuration::Medium4 => 400,
+            MotionDuration::Long1 => 450,
+            MotionDuration::Long2 => 500,
+            MotionDuration::Long3 => 550,
+            MotionDuration::Long4 => 600,
+            MotionDuration::ExtraLong1 => 700,
+            MotionDuration::ExtraLong2 => 800,
+            MotionDuration::ExtraLong3 => 900,
+            MotionDuration::ExtraLong4 => 1000,
+        };
+        millis as f32 / 1000.0
+    }
+}
+
+/// Easing curves of the motion spec
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MotionEasing {
+    Linear,
+    /// For utility transitions that begin and end on screen
+    #[default]
+    Standard,
+    /// For elements entering the screen
+    StandardDecelerate,
+    /// For elements leaving the screen
+    StandardAccelerate,
+    /// For expressive transitions that begin and end on screen
+    Emphasized,
+    EmphasizedDecelerate,
+    EmphasizedAccelerate,
+}
+
+impl MotionEasing {
+    /// Eased progress of a linear progress from 0 to 1
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            MotionEasing::Linear => t,
+            MotionEasing::Standard => cubic_bezier(t, vec2(0.2, 0.0), vec2(0.0, 1.0)),
+            MotionEasing::StandardDecelerate => cubic_bezier(t, vec2(0.0, 0.0), vec2(0.0, 1.0)),
+            MotionEasing::StandardAccelerate => cubic_bezier(t, vec2(0.3, 0.0), vec2(1.0, 1.0)),
+            MotionEasing::Emphasized => emphasized(t),
+            MotionEasing::EmphasizedDecelerate => cubic_bezier(t, vec2(0.05, 0.7), vec2(0.1, 1.0)),
+            MotionEasing::EmphasizedAccelerate => cubic_bezier(t, vec2(0.3, 0.0), vec2(0.8, 0.15)),
+        }
+    }
+}
+
+/// A duration and easing pair, e.g. for one state transition of a component
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MotionSpec {
+    pub duration: MotionDuration,
+    pub easing: MotionEasing,
+}
+
+impl MotionSpec {
+    pub const fn new(duration: MotionDuration, easing: MotionEasing) -> Self {
+        Self { duration, easing }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct EasedValue {
+    from: f32,
+    to: f32,
+    /// Linear progress value when the transition to `to` started
+    base: f32,
+}
+
+/// Material Design motion settings
+///
+/// Material widgets animate their state changes through this, install it
+/// with `reduced_motion` to make every transition instant.
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # egui::__run_test_ui(|ui| {
+/// // respect a reduced motion preference of the user
+/// MaterialMotion { reduced_motion: true }.install(ui.ctx());
+///
+/// let tokens = MaterialTokens::load(ui.ctx());
+/// let (rect, response) = ui.allocate_exact_size(egui::vec2(48.0, 48.0), egui::Sense::hover());
+/// let spec = MotionSpec::new(MotionDuration::Short3, MotionEasing::Standard);
+/// let t = tokens.motion.animate_bool(ui.ctx(), response.id, response.hovered(), spec);
+/// assert_eq!(t, 0.0);
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialMotion {
+    /// Skip every animation, e.g. for users that prefer reduced motion
+    pub reduced_motion: bool,
+}
+
+impl MaterialMotion {
+    /// Make these the motion settings Material widgets use
+    pub fn install(&self, ctx: &Context) {
+        let motion = self.clone();
+        MaterialTokens::update(ctx, |tokens| tokens.motion = motion);
+    }
+
+    /// Seconds a duration token takes, zero with reduced motion
+    pub fn seconds(&self, duration: MotionDuration) -> f32 {
+        match self.reduced_motion {
+            true => 0.0,
+            false => duration.seconds(),
+        }
+    }
+
+    /// Animate a value toward `target` with the duration and easing of `spec`
+    ///
+    /// The first call for an `id` returns `target` directly, a new target
+    /// starts from the value shown in this pass.
+    pub fn animate_value(&self, ctx: &Context, id: Id, target: f32, spec: MotionSpec) -> f32 {
+        let duration = self.seconds(spec.duration);
+        let state_id = id.with("motion");
+        let progress_id = id.with("motion_progress");
+
+        let Some(mut state) = ctx.data(|d| d.get_temp::<EasedValue>(state_id)) else {
+            ctx.animate_value_with_time(progress_id, 1.0, duration);
+            let state = EasedValue {
+                from: target,
+                to: target,
+                base: 0.0,
+            };
+            ctx.data_mut(|d| d.insert_temp(state_id, state));
+            return target;
+        };
+
+        // 线性进度由 egui 驱动，每次过渡前进 1
+        let progress = ctx.animate_value_with_time(progress_id, state.base + 1.0, duration);
+        let t = (progress - state.base).clamp(0.0, 1.0);
+        let value = state.from + (state.to - state.from) * spec.easing.apply(t);
+
+        if target != state.to {
+            // 从当前显示的值开始新的过渡
+            state = EasedValue {
+                from: value,
+                to: target,
+                base: progress,
+            };
+            ctx.animate_value_with_time(progress_id, progress + 1.0, duration);
+            ctx.data_mut(|d| d.insert_temp(state_id, state));
+            if duration <= 0.0 {
+                return target;
+            }
+        }
+        value
+    }
+
+    /// Show `value` right away, e.g. while dragging, the next target animates from it
+    pub fn set_value(&self, ctx: &Context, id: Id, value: f32) {
+        ctx.animate_value_with_time(id.with("motion_progress"), 0.0, 0.0);
+        let state = EasedValue {
+            from: value,
+            to: value,
+            base: 0.0,
+        };
+        ctx.data_mut(|d| d.insert_temp(id.with("motion"), state));
+    }
+
+    /// Animate from 0 to 1 when `value` turns true and back when it turns false
+    pub fn animate_bool(&self, ctx: &Context, id: Id, value: bool, spec: MotionSpec) -> f32 {
+        self.animate_value(ctx, id, if value { 1.0 } else { 0.0 }, spec)
+    }
+}
+
+/// CSS style cubic bezier from (0, 0) to (1, 1)
+fn cubic_bezier(x: f32, p1: Vec2, p2: Vec2) -> f32 {
+    bezier_segment(x, Vec2::ZERO, p1, p2, Vec2::splat(1.0))
+}
+
+/// The emphasized easing, a path of two cubic segments
+fn emphasized(x: f32) -> f32 {
+    let split = vec2(0.166666, 0.4);
+    if x < split.x {
+        bezier_segment(x, Vec2::ZERO, vec2(0.05, 0.0), vec2(0.133333, 0.06), split)
+    } else {
+        bezier_segment(
+            x,
+            split,
+            vec2(0.208333, 0.82),
+            vec2(0.25, 1.0),
+            Vec2::splat(1.0),
+        )
+    }
+}
+
+/// Y of a cubic bezier segment at `x`, the segment must be monotonic in x
+fn bezier_segment(x: f32, p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2) -> f32 {
+    let point = |t: f32| {
+        let u = 1.0 - t;
+        p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+    };
+
+    // 二分查找 x 对应的参数 t
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..24 {
+        let mid = (low + high) / 2.0;
+        if point(mid).x < x {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    point((low + high) / 2.0).y
+}
@@ -1,3 +1,9 @@
 pub use crate::color::prelude::*;
 pub use crate::components::prelude::*;
+pub use crate::elevation::*;
+pub use crate::icon::prelude::*;
+pub use crate::motion::*;
+pub use crate::shape::*;
+pub use crate::tokens::*;
+pub use crate::typography::prelude::*;
 pub use crate::utils::*;
@@ -0,0 +1,189 @@
+//! Material Design shapes
+//!
+//! See: [material design document](https://m3.material.io/styles/shape/corner-radius-scale)
+
+use egui::{Context, CornerRadius, Vec2, Visuals};
+
+use crate::tokens::MaterialTokens;
+
+/// A step of the corner radius scale
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CornerSize {
+    /// Square corner
+    #[default]
+    None,
+    ExtraSmall,
+    Small,
+    Medium,
+    Large,
+    ExtraLarge,
+    /// Half of the shorter side, a pill or circle
+    Full,
+    /// A radius outside the scale, e.g. the 2dp of checkboxes
+    Fixed(f32),
+}
+
+/// Corner of a component shape, each one can use a different size
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// // a filled text field only rounds its top corners
+/// let shape = MaterialShape::top(CornerSize::ExtraSmall);
+/// let corner_radius = shape.corner_radius(&MaterialShapes::default(), egui::vec2(200.0, 56.0));
+/// assert_eq!(corner_radius.sw, 0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialShape {
+    pub nw: CornerSize,
+    pub ne: CornerSize,
+    pub sw: CornerSize,
+    pub se: CornerSize,
+}
+
+impl MaterialShape {
+    /// Every corner the same size
+    pub const fn all(size: CornerSize) -> Self {
+        Self {
+            nw: size,
+            ne: size,
+            sw: size,
+            se: size,
+        }
+    }
+
+    /// Only the top corners rounded, e.g. filled text fields and bottom sheets
+    pub const fn top(size: CornerSize) -> Self {
+        Self {
+            nw: size,
+            ne: size,
+            sw: CornerSize::None,
+            se: CornerSize::None,
+        }
+    }
+
+    /// Only the bottom corners rounded
+    pub const fn bottom(size: CornerSize) -> Self {
+        Self {
+            nw: CornerSize::None,
+            ne: CornerSize::None,
+            sw: size,
+            se: size,
+        }
+    }
+
+    /// Only the left corners rounded, e.g. the first segment of a segmented button
+    pub const fn left(size: CornerSize) -> Self {
+        Self {
+            nw: size,
+            ne: CornerSize::None,
+            sw: size,
+            se: CornerSize::None,
+        }
+    }
+
+    /// Only the right corners rounded
+    pub const fn right(size: CornerSize) -> Self {
+        Self {
+            nw: CornerSize::None,
+            ne: size,
+            sw: CornerSize::None,
+            se: size,
+        }
+    }
+
+    /// Corner radius of a component of the given size
+    pub fn corner_radius(&self, shapes: &MaterialShapes, size: Vec2) -> CornerRadius {
+        let radius = |corner| shapes.radius(corner, size).round().clamp(0.0, 255.0) as u8;
+        CornerRadius {
+            nw: radius(self.nw),
+            ne: radius(self.ne),
+            sw: radius(self.sw),
+            se: radius(self.se),
+        }
+    }
+}
+
+impl From<CornerSize> for MaterialShape {
+    fn from(size: CornerSize) -> Self {
+        Self::all(size)
+    }
+}
+
+/// Material Design corner radius scale
+///
+/// Install a changed scale to reshape every Material widget and the egui
+/// windows, menus and widgets.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialShapes {
+    pub extra_small: f32,
+    pub small: f32,
+    pub medium: f32,
+    pub large: f32,
+    pub extra_large: f32,
+}
+
+impl Default for MaterialShapes {
+    fn default() -> Self {
+        Self {
+            extra_small: 4.0,
+            small: 8.0,
+            medium: 12.0,
+            large: 16.0,
+            extra_large: 28.0,
+        }
+    }
+}
+
+impl MaterialShapes {
+    /// Radius of a corner size for a component of the given size
+    pub fn radius(&self, corner: CornerSize, size: Vec2) -> f32 {
+        match corner {
+            CornerSize::None => 0.0,
+            CornerSize::ExtraSmall => self.extra_small,
+            CornerSize::Small => self.small,
+            CornerSize::Medium => self.medium,
+            CornerSize::Large => self.large,
+            CornerSize::ExtraLarge => self.extra_large,
+            CornerSize::Full => size.min_elem() / 2.0,
+            CornerSize::Fixed(radius) => radius,
+        }
+    }
+
+    /// Make this the corner radius scale Material widgets use, and round the
+    /// egui windows (extra large), menus (extra small) and widgets (small) with it
+    pub fn install(&self, ctx: &Context) {
+        ctx.all_styles_mut(|style| self.apply_to(&mut style.visuals));
+
+        let shapes = self.clone();
+        MaterialTokens::update(ctx, |tokens| tokens.shapes = shapes);
+    }
+
+    /// Round egui windows, menus and widgets with this scale
+    pub fn apply_to(&self, visuals: &mut Visuals) {
+        let radius = |radius: f32| CornerRadius::same(radius.round().clamp(0.0, 255.0) as u8);
+        let widget = radius(self.small);
+
+        visuals.window_corner_radius = radius(self.extra_large);
+        visuals.menu_corner_radius = radius(self.extra_small);
+        visuals.widgets.noninteractive.corner_radius = widget;
+        visuals.widgets.inactive.corner_radius = widget;
+        visuals.widgets.hovered.corner_radius = widget;
+        visuals.widgets.active.corner_radius = widget;
+        visuals.widgets.open.corner_radius = widget;
+    }
+}
+
+/// Corner radius part way between two shapes
+pub fn lerp_corner_radius(from: CornerRadius, to: CornerRadius, t: f32) -> CornerRadius {
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    CornerRadius {
+        nw: lerp(from.nw, to.nw),
+        ne: lerp(from.ne, to.ne),
+        sw: lerp(from.sw, to.sw),
+        se: lerp(from.se, to.se),
+    }
+}
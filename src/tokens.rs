@@ -0,0 +1,119 @@
+//! Material Design tokens shared through the egui context
+
+use std::sync::Arc;
+
+use egui::{Context, Id};
+use material_colors::scheme::Scheme;
+
+use crate::color::prelude::*;
+use crate::elevation::MaterialElevation;
+use crate::motion::MaterialMotion;
+use crate::shape::MaterialShapes;
+use crate::typography::prelude::*;
+use crate::utils::clone_scheme;
+
+/// The design tokens Material widgets are styled with
+///
+/// [`MaterialTheme::apply`] and [`MaterialTypography::install`] store them in
+/// egui memory, widgets load them every frame so switching the theme restyles
+/// every widget on the next frame.
+#[derive(Clone)]
+pub struct MaterialTokens {
+    pub scheme: Arc<Scheme>,
+    pub dark_mode: bool,
+    /// Light and dark schemes to pick from when following the system theme
+    pub system_schemes: Option<[Arc<Scheme>; 2]>,
+    pub custom_colors: Arc<Vec<CustomColorRoles>>,
+    pub typography: MaterialTypography,
+    pub elevation: MaterialElevation,
+    pub shapes: MaterialShapes,
+    pub motion: MaterialMotion,
+}
+
+impl Default for MaterialTokens {
+    fn default() -> Self {
+        let theme = MaterialTheme::default();
+        Self {
+            scheme: Arc::new(clone_scheme(theme.get())),
+            dark_mode: theme.get_dark_mode(),
+            system_schemes: None,
+            custom_colors: Arc::default(),
+            typography: MaterialTypography::default(),
+            elevation: MaterialElevation::default(),
+            shapes: MaterialShapes::default(),
+            motion: MaterialMotion::default(),
+        }
+    }
+}
+
+impl MaterialTokens {
+    fn id() -> Id {
+        Id::new("egui_material::tokens")
+    }
+
+    /// Load the active tokens, or the default ones if nothing was installed
+    ///
+    /// While a [`ThemeTransition`] runs the scheme and custom colors are the
+    /// blended ones.
+    pub fn load(ctx: &Context) -> Self {
+        let tokens = Self::load_target(ctx);
+        match ThemeTransition::current(ctx) {
+            Some((scheme, custom_colors)) => Self {
+                custom_colors,
+                ..tokens.with_scheme(scheme)
+            },
+            None => tokens,
+        }
+    }
+
+    /// Load the active tokens, ignoring a running transition
+    pub(crate) fn load_target(ctx: &Context) -> Self {
+        let mut tokens: Self = ctx.data(|d| d.get_temp(Self::id())).unwrap_or_default();
+        if let Some([light, dark]) = &tokens.system_schemes {
+            tokens.dark_mode = ctx.theme() == egui::Theme::Dark;
+            tokens.scheme = match tokens.dark_mode {
+                true => dark.clone(),
+                false => light.clone(),
+            };
+        }
+        tokens
+    }
+
+    /// Load the active tokens, replacing the scheme if one is given
+    pub fn load_with(ctx: &Context, scheme: Option<Arc<Scheme>>) -> Self {
+        let tokens = Self::load(ctx);
+        match scheme {
+            Some(scheme) => tokens.with_scheme(scheme),
+            None => tokens,
+        }
+    }
+
+    /// Make these tokens the active ones
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(), self));
+    }
+
+    /// Modify the active tokens in place
+    pub fn update(ctx: &Context, f: impl FnOnce(&mut Self)) {
+        let mut tokens = Self::load_target(ctx);
+        f(&mut tokens);
+        tokens.store(ctx);
+    }
+
+    /// Resolve a color role against the scheme and custom colors
+    ///
+    /// Unknown custom colors fall back to primary.
+    pub fn roles(&self, role: &ColorRole) -> ColorRoles {
+        if let ColorRole::Custom(name) = role
+            && let Some(custom) = self.custom_colors.iter().find(|c| &c.name == name)
+        {
+            return custom.get(self.dark_mode);
+        }
+        role.scheme_roles(&self.scheme)
+    }
+
+    /// Use another scheme, e.g. for a single widget
+    pub fn with_scheme(self, scheme: Arc<Scheme>) -> Self {
+        Self { scheme, ..self }
+    }
+}
@@ -0,0 +1,121 @@
+//! Bundled Roboto fonts
+
+use std::sync::Arc;
+
+use egui::{FontData, FontDefinitions, FontFamily};
+
+use crate::icon::prelude::add_icon_fonts;
+
+/// Font weights shipped with the bundled Roboto fonts
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontWeight {
+    Thin,
+    Light,
+    Regular,
+    Medium,
+    Bold,
+    Black,
+}
+
+macro_rules! bundled_fonts {
+    ($($name:literal),* $(,)?) => {
+        &[$(($name, include_bytes!(concat!("../../assets/fonts/", $name, ".ttf")))),*]
+    };
+}
+
+/// Every font under `assets/fonts`, keyed by its file stem
+const BUNDLED_FONTS: &[(&str, &[u8])] = bundled_fonts![
+    "Roboto-Thin",
+    "Roboto-ThinItalic",
+    "Roboto-Light",
+    "Roboto-LightItalic",
+    "Roboto-Regular",
+    "Roboto-Italic",
+    "Roboto-Medium",
+    "Roboto-MediumItalic",
+    "Roboto-Bold",
+    "Roboto-BoldItalic",
+    "Roboto-Black",
+    "Roboto-BlackItalic",
+    "RobotoCondensed-Light",
+    "RobotoCondensed-LightItalic",
+    "RobotoCondensed-Regular",
+    "RobotoCondensed-Italic",
+    "RobotoCondensed-Medium",
+    "RobotoCondensed-MediumItalic",
+    "RobotoCondensed-Bold",
+    "RobotoCondensed-BoldItalic",
+];
+
+fn font_name(family: &str, weight: &str, italic: bool) -> String {
+    match (weight, italic) {
+        ("Regular", true) => format!("{family}-Italic"),
+        (weight, true) => format!("{family}-{weight}Italic"),
+        (weight, false) => format!("{family}-{weight}"),
+    }
+}
+
+/// Font family of the bundled Roboto font in the given weight
+///
+/// Only available after the fonts are installed, see [`font_definitions`].
+pub fn roboto(weight: FontWeight, italic: bool) -> FontFamily {
+    let weight = match weight {
+        FontWeight::Thin => "Thin",
+        FontWeight::Light => "Light",
+        FontWeight::Regular => "Regular",
+        FontWeight::Medium => "Medium",
+        FontWeight::Bold => "Bold",
+        FontWeight::Black => "Black",
+    };
+    FontFamily::Name(font_name("Roboto", weight, italic).into())
+}
+
+/// Font family of the bundled Roboto Condensed font in the given weight
+///
+/// Roboto Condensed has no thin and black cuts, the closest weight is used instead.
+pub fn roboto_condensed(weight: FontWeight, italic: bool) -> FontFamily {
+    let weight = match weight {
+        FontWeight::Thin | FontWeight::Light => "Light",
+        FontWeight::Regular => "Regular",
+        FontWeight::Medium => "Medium",
+        FontWeight::Bold | FontWeight::Black => "Bold",
+    };
+    FontFamily::Name(font_name("RobotoCondensed", weight, italic).into())
+}
+
+/// egui font definitions with every bundled font registered
+///
+/// Each font gets its own named family (e.g. `FontFamily::Name("Roboto-Medium")`)
+/// falling back to the egui default fonts for missing glyphs, and Roboto Regular
+/// becomes the default proportional font. The Material Symbols icon fonts are
+/// registered too, see [`icon_family`](crate::icon::prelude::icon_family).
+pub fn font_definitions() -> FontDefinitions {
+    let mut fonts = FontDefinitions::default();
+    let fallback = fonts
+        .families
+        .get(&FontFamily::Proportional)
+        .cloned()
+        .unwrap_or_default();
+
+    for (name, data) in BUNDLED_FONTS {
+        fonts
+            .font_data
+            .insert((*name).to_owned(), Arc::new(FontData::from_static(data)));
+
+        let mut family = vec![(*name).to_owned()];
+        family.extend(fallback.iter().cloned());
+        fonts
+            .families
+            .insert(FontFamily::Name((*name).into()), family);
+    }
+
+    fonts
+        .families
+        .entry(FontFamily::Proportional)
+        .or_default()
+        .insert(0, "Roboto-Regular".to_owned());
+
+    add_icon_fonts(&mut fonts);
+    fonts
+}
@@ -0,0 +1,5 @@
+//! Material Design Typography
+
+mod fonts;
+pub mod prelude;
+mod scale;
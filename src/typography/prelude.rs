@@ -0,0 +1,2 @@
+pub use super::fonts::*;
+pub use super::scale::*;
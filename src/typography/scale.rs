@@ -0,0 +1,173 @@
+//! Material Design type scale
+
+use egui::{Color32, Context, FontFamily, FontId, Id, TextFormat, TextStyle};
+use std::sync::Arc;
+
+use crate::tokens::MaterialTokens;
+
+use super::fonts::{FontWeight, font_definitions, roboto};
+
+/// A single role of the type scale
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TypeStyle {
+    pub family: FontFamily,
+    pub size: f32,
+    pub line_height: f32,
+    pub tracking: f32,
+}
+
+impl TypeStyle {
+    pub fn new(weight: FontWeight, size: f32, line_height: f32, tracking: f32) -> Self {
+        Self {
+            family: roboto(weight, false),
+            size,
+            line_height,
+            tracking,
+        }
+    }
+
+    /// Font id of this style
+    ///
+    /// Named families only exist after [`MaterialTypography::install`], use
+    /// [`TypeStyle::resolve`] when the fonts may not be installed yet.
+    pub fn font_id(&self) -> FontId {
+        FontId::new(self.size, self.family.clone())
+    }
+
+    /// Font id of this style, falling back to the proportional font
+    /// if the family is not registered in the context
+    pub fn resolve(&self, ctx: &Context) -> FontId {
+        let installed = ctx.fonts(|f| f.families().contains(&self.family));
+        if installed {
+            self.font_id()
+        } else {
+            FontId::proportional(self.size)
+        }
+    }
+
+    /// Text format for layout jobs, including line height and tracking
+    pub fn text_format(&self, ctx: &Context, color: Color32) -> TextFormat {
+        TextFormat {
+            font_id: self.resolve(ctx),
+            line_height: Some(self.line_height),
+            extra_letter_spacing: self.tracking,
+            color,
+            ..Default::default()
+        }
+    }
+}
+
+/// Material Design type scale
+///
+/// See: [material design document](https://m3.material.io/styles/typography/type-scale-tokens)
+///
+/// # Example
+/// ```rust
+/// # use egui_material::prelude::*;
+/// # let ctx = egui::Context::default();
+/// let typography = MaterialTypography::default();
+/// // register the bundled Roboto fonts, egui text styles follow from the next pass
+/// typography.install(&ctx);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaterialTypography {
+    pub display_large: TypeStyle,
+    pub display_medium: TypeStyle,
+    pub display_small: TypeStyle,
+    pub headline_large: TypeStyle,
+    pub headline_medium: TypeStyle,
+    pub headline_small: TypeStyle,
+    pub title_large: TypeStyle,
+    pub title_medium: TypeStyle,
+    pub title_small: TypeStyle,
+    pub body_large: TypeStyle,
+    pub body_medium: TypeStyle,
+    pub body_small: TypeStyle,
+    pub label_large: TypeStyle,
+    pub label_medium: TypeStyle,
+    pub label_small: TypeStyle,
+}
+
+impl Default for MaterialTypography {
+    fn default() -> Self {
+        use FontWeight::{Medium, Regular};
+
+        Self {
+            display_large: TypeStyle::new(Regular, 57.0, 64.0, -0.25),
+            display_medium: TypeStyle::new(Regular, 45.0, 52.0, 0.0),
+            display_small: TypeStyle::new(Regular, 36.0, 44.0, 0.0),
+            headline_large: TypeStyle::new(Regular, 32.0, 40.0, 0.0),
+            headline_medium: TypeStyle::new(Regular, 28.0, 36.0, 0.0),
+            headline_small: TypeStyle::new(Regular, 24.0, 32.0, 0.0),
+            title_large: TypeStyle::new(Regular, 22.0, 28.0, 0.0),
+            title_medium: TypeStyle::new(Medium, 16.0, 24.0, 0.15),
+            title_small: TypeStyle::new(Medium, 14.0, 20.0, 0.1),
+            body_large: TypeStyle::new(Regular, 16.0, 24.0, 0.5),
+            body_medium: TypeStyle::new(Regular, 14.0, 20.0, 0.25),
+            body_small: TypeStyle::new(Regular, 12.0, 16.0, 0.4),
+            label_large: TypeStyle::new(Medium, 14.0, 20.0, 0.1),
+            label_medium: TypeStyle::new(Medium, 12.0, 16.0, 0.5),
+            label_small: TypeStyle::new(Medium, 11.0, 16.0, 0.5),
+        }
+    }
+}
+
+impl MaterialTypography {
+    /// Install the bundled fonts, map egui text styles onto the type scale
+    /// and make it the type scale Material widgets use
+    ///
+    /// The fonts become active at the start of the next pass, the egui text
+    /// styles switch to them then, so this can also be called during a pass.
+    pub fn install(&self, ctx: &egui::Context) {
+        ctx.set_fonts(font_definitions());
+
+        let typography = self.clone();
+        MaterialTokens::update(ctx, |tokens| tokens.typography = typography);
+
+        // 命名字体族在下一帧开始时才绑定，提前切换文本样式会使本帧的排版失败
+        let hook = Id::new("egui_material::typography_hook");
+        if !ctx.data(|d| d.get_temp::<bool>(hook).unwrap_or(false)) {
+            ctx.on_begin_pass("egui_material::typography", Arc::new(Self::apply_pending));
+            ctx.data_mut(|d| d.insert_temp(hook, true));
+        }
+        ctx.data_mut(|d| d.insert_temp(Self::pending_id(), self.clone()));
+        ctx.request_repaint();
+    }
+
+    fn pending_id() -> Id {
+        Id::new("egui_material::typography")
+    }
+
+    /// Map the egui text styles onto the type scale once its fonts are bound
+    fn apply_pending(ctx: &Context) {
+        let Some(typography) = ctx.data(|d| d.get_temp::<Self>(Self::pending_id())) else {
+            return;
+        };
+        let styles = [
+            (TextStyle::Small, &typography.body_small),
+            (TextStyle::Body, &typography.body_medium),
+            (TextStyle::Button, &typography.label_large),
+            (TextStyle::Heading, &typography.title_large),
+        ];
+        let bound = ctx.fonts(|f| {
+            let families = f.families();
+            styles
+                .iter()
+                .all(|(_, style)| families.contains(&style.family))
+        });
+        if !bound {
+            return;
+        }
+
+        ctx.data_mut(|d| d.remove::<Self>(Self::pending_id()));
+        ctx.all_styles_mut(|style| {
+            for (text_style, type_style) in &styles {
+                style
+                    .text_styles
+                    .insert(text_style.clone(), type_style.font_id());
+            }
+        });
+    }
+}
@@ -1,7 +1,149 @@
-use egui::Color32;
-use material_colors::color::Argb;
+use egui::{Color32, EventFilter, Id, Key, Response, Ui};
+use material_colors::{color::Argb, scheme::Scheme};
 
 /// Convert Argb(from material-color) to Color32(from egui)
 pub fn argb_to_color32(argb: Argb) -> Color32 {
     Color32::from_rgba_unmultiplied(argb.red, argb.green, argb.blue, argb.alpha)
 }
+
+/// Convert Argb(from material-color) to a `0xAARRGGBB` code
+pub fn argb_to_u32(argb: Argb) -> u32 {
+    u32::from_be_bytes([argb.alpha, argb.red, argb.green, argb.blue])
+}
+
+/// Invoke `$callback!` with the names of every color role of a [`Scheme`]
+///
+/// [`Scheme`]: material_colors::scheme::Scheme
+macro_rules! with_scheme_roles {
+    ($callback:ident) => {
+        $callback! {
+            primary,
+            on_primary,
+            primary_container,
+            on_primary_container,
+            inverse_primary,
+            primary_fixed,
+            primary_fixed_dim,
+            on_primary_fixed,
+            on_primary_fixed_variant,
+            secondary,
+            on_secondary,
+            secondary_container,
+            on_secondary_container,
+            secondary_fixed,
+            secondary_fixed_dim,
+            on_secondary_fixed,
+            on_secondary_fixed_variant,
+            tertiary,
+            on_tertiary,
+            tertiary_container,
+            on_tertiary_container,
+            tertiary_fixed,
+            tertiary_fixed_dim,
+            on_tertiary_fixed,
+            on_tertiary_fixed_variant,
+            error,
+            on_error,
+            error_container,
+            on_error_container,
+            surface_dim,
+            surface,
+            surface_tint,
+            surface_bright,
+            surface_container_lowest,
+            surface_container_low,
+            surface_container,
+            surface_container_high,
+            surface_container_highest,
+            on_surface,
+            on_surface_variant,
+            outline,
+            outline_variant,
+            inverse_surface,
+            inverse_on_surface,
+            surface_variant,
+            background,
+            on_background,
+            shadow,
+            scrim
+        }
+    };
+}
+pub(crate) use with_scheme_roles;
+
+/// Copy a [`Scheme`], which does not implement `Clone`
+pub fn clone_scheme(scheme: &Scheme) -> Scheme {
+    macro_rules! copy_roles {
+        ($($role:ident),*) => {
+            Scheme {
+                $($role: scheme.$role),*
+            }
+        };
+    }
+    with_scheme_roles!(copy_roles)
+}
+
+/// Mutable access to a color role of a [`Scheme`] by its snake_case name
+pub fn scheme_role_mut<'a>(scheme: &'a mut Scheme, name: &str) -> Option<&'a mut Argb> {
+    macro_rules! match_roles {
+        ($($role:ident),*) => {
+            match name {
+                $(stringify!($role) => Some(&mut scheme.$role),)*
+                _ => None,
+            }
+        };
+    }
+    with_scheme_roles!(match_roles)
+}
+
+/// Move keyboard focus between the widgets of a group with the arrow, Home and End keys
+///
+/// Returns the index of the widget that gets the focus.
+pub(crate) fn arrow_key_focus(ui: &Ui, responses: &[Response], wrap: bool) -> Option<usize> {
+    let focused = responses.iter().position(|response| response.has_focus())?;
+    let id = responses[focused].id;
+
+    // 组内自行处理方向键，避免 egui 按空间位置把焦点移出组外。
+    // 锁定从获得焦点后的下一帧才生效，在此之前方向键仍由 egui 处理
+    let lock_id = responses[0].id.with("arrow_key_focus");
+    let locked = ui.data(|d| d.get_temp::<Id>(lock_id)) == Some(id);
+    let lock_next = ui.memory_mut(|m| {
+        m.set_focus_lock_filter(
+            id,
+            EventFilter {
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            },
+        );
+        m.had_focus_last_frame(id)
+    });
+    ui.data_mut(|d| d.insert_temp(lock_id, if lock_next { id } else { Id::NULL }));
+    if !lock_next {
+        // 刚获得焦点，再绘制一帧使锁定在下次按键前生效
+        ui.ctx().request_repaint();
+    }
+
+    let last = responses.len() - 1;
+    let target = ui.input(|i| {
+        if locked && (i.key_pressed(Key::ArrowDown) || i.key_pressed(Key::ArrowRight)) {
+            match focused < last {
+                true => Some(focused + 1),
+                false => wrap.then_some(0),
+            }
+        } else if locked && (i.key_pressed(Key::ArrowUp) || i.key_pressed(Key::ArrowLeft)) {
+            match focused > 0 {
+                true => Some(focused - 1),
+                false => wrap.then_some(last),
+            }
+        } else if i.key_pressed(Key::Home) {
+            Some(0)
+        } else if i.key_pressed(Key::End) {
+            Some(last)
+        } else {
+            None
+        }
+    })?;
+    responses[target].request_focus();
+    Some(target)
+}